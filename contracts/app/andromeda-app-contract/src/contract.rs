@@ -9,9 +9,8 @@ use andromeda_std::amp::AndrAddr;
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::os::vfs::{convert_component_name, ExecuteMsg as VFSExecuteMsg};
 use andromeda_std::{
-    ado_base::InstantiateMsg as BaseInstantiateMsg,
-    common::encode_binary,
-    error::{from_semver, ContractError},
+    ado_base::InstantiateMsg as BaseInstantiateMsg, andr_migrate, common::encode_binary,
+    error::ContractError,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -19,10 +18,9 @@ use cosmwasm_std::{
     ensure, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply,
     Response, StdError, SubMsg, WasmMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 
 use crate::{execute, query};
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-app-contract";
@@ -56,6 +54,7 @@ pub fn instantiate(
                 operators: None,
                 kernel_address: msg.kernel_address.clone(),
                 owner: msg.owner.clone(),
+                metadata: msg.metadata,
             },
         )?
         .add_attribute("owner", &msg.owner.clone().unwrap_or(sender.clone()))
@@ -172,7 +171,7 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
@@ -190,42 +189,22 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
         ExecuteMsg::ProxyMessage { msg, name } => execute::message(ctx, name, msg),
         ExecuteMsg::UpdateAddress { name, addr } => execute::update_address(ctx, name, addr),
         ExecuteMsg::AssignAppToComponents {} => execute::assign_app_to_components(ctx),
+        ExecuteMsg::PauseComponents { actions } => {
+            execute::set_components_paused(ctx, actions, true)
+        }
+        ExecuteMsg::UnpauseComponents { actions } => {
+            execute::set_components_paused(ctx, actions, false)
+        }
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
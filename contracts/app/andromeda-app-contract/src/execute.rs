@@ -1,13 +1,16 @@
 use crate::state::{
     add_app_component, generate_assign_app_message, generate_ownership_message,
-    load_component_addresses, ADO_ADDRESSES,
+    generate_pause_message, load_component_addresses, ADO_ADDRESSES,
 };
 use andromeda_app::app::{AppComponent, ComponentType};
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::error::ContractError;
 use andromeda_std::os::aos_querier::AOSQuerier;
 use andromeda_std::os::vfs::ExecuteMsg as VFSExecuteMsg;
-use andromeda_std::{ado_contract::ADOContract, amp::AndrAddr};
+use andromeda_std::{
+    ado_contract::{pausing::PAUSER_ROLE, ADOContract},
+    amp::AndrAddr,
+};
 
 use crate::reply::ReplyId;
 use cosmwasm_std::{
@@ -97,8 +100,9 @@ pub fn claim_ownership(
 
         for address in addresses {
             let curr_owner = AOSQuerier::ado_owner_getter(&ctx.deps.querier, &address)?;
+            // Components whose ownership has been renounced cannot be claimed
             // Get the AndrAddr's raw address if available, else get the message sender's address.
-            if curr_owner == ctx.env.contract.address {
+            if curr_owner.as_ref() == Some(&ctx.env.contract.address) {
                 let new_owner = if let Some(new_owner) = new_owner.clone() {
                     new_owner.get_raw_address(&ctx.deps.as_ref())?
                 } else {
@@ -228,3 +232,30 @@ pub fn assign_app_to_components(ctx: ExecuteContext) -> Result<Response, Contrac
 
     Ok(resp)
 }
+
+/// Pauses or unpauses the given actions on every component, requires the app to be assigned to each component
+pub fn set_components_paused(
+    ctx: ExecuteContext,
+    actions: Option<Vec<String>>,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+    ensure!(
+        ADOContract::default().is_owner_or_role(deps.storage, PAUSER_ROLE, info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let mut resp = Response::default();
+    let addresses = load_component_addresses(deps.storage, None)?;
+    for address in addresses {
+        let pause_msg = generate_pause_message(&address, actions.clone(), paused)?;
+        resp = resp.add_message(pause_msg);
+    }
+
+    let method = if paused {
+        "pause_components"
+    } else {
+        "unpause_components"
+    };
+    Ok(resp.add_attribute("method", method))
+}
@@ -1,6 +1,7 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 use crate::contract::{execute, instantiate, query, reply};
-use andromeda_app::app::{AppComponent, ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_app::app::{AppComponent, ComponentAddress, ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_std::ado_base::AndromedaMsg;
 use andromeda_testing::{
     mock_ado,
     mock_contract::{AnyResult, MockADO, MockContract},
@@ -44,10 +45,24 @@ impl MockApp {
         self.execute(app, &mock_claim_ownership_msg(component_name), sender, &[])
     }
 
+    /// Claims ownership of all components and accepts each resulting ownership proposal
+    pub fn execute_claim_and_accept_ownership(&self, app: &mut App, sender: Addr) -> AnyResult<()> {
+        self.execute_claim_ownership(app, sender.clone(), None)?;
+        for component in self.query_components(app) {
+            let addr = self.query_component_addr(app, component.name);
+            app.execute_contract(sender.clone(), addr, &AndromedaMsg::AcceptOwnership {}, &[])?;
+        }
+        Ok(())
+    }
+
     pub fn query_components(&self, app: &App) -> Vec<AppComponent> {
         self.query::<Vec<AppComponent>>(app, mock_get_components_msg())
     }
 
+    pub fn query_addresses_with_names(&self, app: &App) -> Vec<ComponentAddress> {
+        self.query::<Vec<ComponentAddress>>(app, QueryMsg::GetAddressesWithNames {})
+    }
+
     pub fn query_component_addr(&self, app: &App, name: impl Into<String>) -> Addr {
         self.query::<Addr>(app, mock_get_address_msg(name.into()))
     }
@@ -77,6 +92,7 @@ pub fn mock_app_instantiate_msg(
         name: name.into(),
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
         chain_info: None,
     }
 }
@@ -2,7 +2,12 @@ use crate::state::{
     load_component_addresses_with_name, load_component_descriptors, ADO_ADDRESSES, APP_NAME,
 };
 use andromeda_app::app::{AppComponent, ComponentAddress, ConfigResponse};
-use andromeda_std::ado_contract::ADOContract;
+use andromeda_std::{
+    ado_base::{metadata::AdoMetadata, AndromedaQuery},
+    ado_contract::ADOContract,
+    amp::AndrAddr,
+    os::aos_querier::{AOSQuerier, ResolvedAddresses},
+};
 
 use andromeda_std::error::ContractError;
 
@@ -23,10 +28,32 @@ pub fn component_exists(deps: Deps, name: String) -> bool {
 }
 
 pub fn component_addresses_with_name(deps: Deps) -> Result<Vec<ComponentAddress>, ContractError> {
-    let value = load_component_addresses_with_name(deps.storage)?;
+    let mut resolved = ResolvedAddresses::default();
+    let value = load_component_addresses_with_name(deps.storage)?
+        .into_iter()
+        .map(|component| ComponentAddress {
+            metadata: component_metadata(&deps, &mut resolved, &component.address),
+            ..component
+        })
+        .collect();
     Ok(value)
 }
 
+/// Queries a component for its metadata, components that do not support the query have none
+fn component_metadata(
+    deps: &Deps,
+    resolved: &mut ResolvedAddresses,
+    address: &str,
+) -> Option<AdoMetadata> {
+    AOSQuerier::query_ado(
+        deps,
+        resolved,
+        &AndrAddr::from_string(address),
+        &AndromedaQuery::AdoMetadata {},
+    )
+    .ok()
+}
+
 pub fn config(deps: Deps) -> Result<ConfigResponse, ContractError> {
     let name = APP_NAME.load(deps.storage)?;
     let owner = ADOContract::default().query_contract_owner(deps)?.owner;
@@ -57,6 +57,7 @@ pub fn load_component_addresses_with_name(
         .map(|(name, addr)| ComponentAddress {
             name,
             address: addr.to_string(),
+            metadata: None,
         })
         .collect();
 
@@ -77,8 +78,9 @@ pub fn load_component_descriptors(
 }
 
 pub fn generate_ownership_message(addr: Addr, owner: &str) -> Result<SubMsg, ContractError> {
-    let msg = to_json_binary(&AndromedaMsg::UpdateOwner {
-        address: owner.to_string(),
+    let msg = to_json_binary(&AndromedaMsg::ProposeOwner {
+        address: AndrAddr::from_string(owner),
+        expiration: None,
     })?;
     Ok(SubMsg {
         id: ReplyId::ClaimOwnership.repr(),
@@ -108,6 +110,23 @@ pub fn generate_assign_app_message(addr: &Addr, app_addr: &str) -> Result<SubMsg
     })
 }
 
+pub fn generate_pause_message(
+    addr: &Addr,
+    actions: Option<Vec<String>>,
+    paused: bool,
+) -> Result<CosmosMsg, ContractError> {
+    let msg = if paused {
+        AndromedaMsg::Pause { actions }
+    } else {
+        AndromedaMsg::Unpause { actions }
+    };
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: addr.to_string(),
+        msg: to_json_binary(&msg)?,
+        funds: vec![],
+    }))
+}
+
 pub fn get_chain_info(chain_name: String, chain_info: Option<Vec<ChainInfo>>) -> Option<ChainInfo> {
     match chain_info {
         Some(chain_info) => {
@@ -171,6 +190,7 @@ pub fn create_cross_chain_message(
     }
     let msg = InstantiateMsg {
         owner: Some(chain_info.owner.clone()),
+        metadata: None,
         app_components: new_components,
         name: app_name,
         chain_info: None,
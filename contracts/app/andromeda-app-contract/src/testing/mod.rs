@@ -23,6 +23,7 @@ fn test_empty_instantiation() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
     let info = mock_info("creator", &[]);
@@ -45,6 +46,7 @@ fn test_instantiation() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
     let info = mock_info("creator", &[]);
@@ -127,6 +129,7 @@ fn test_instantiation_duplicate_components() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
     let info = mock_info("creator", &[]);
@@ -145,6 +148,7 @@ fn test_add_app_component_unauthorized() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
 
@@ -177,6 +181,7 @@ fn test_add_app_component_duplicate_name() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
 
@@ -211,6 +216,7 @@ fn test_add_app_component() {
         name: String::from("Some App"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         chain_info: None,
     };
 
@@ -263,6 +269,7 @@ fn test_claim_ownership_unauth() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -288,6 +295,7 @@ fn test_claim_ownership_not_found() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -312,6 +320,7 @@ fn test_claim_ownership_empty() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -375,6 +384,7 @@ fn test_claim_ownership() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -407,8 +417,9 @@ fn test_claim_ownership() {
         id: 101,
         msg: CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: "tokenaddress".to_string(),
-            msg: to_json_binary(&AndromedaMsg::UpdateOwner {
-                address: "creator".to_string(),
+            msg: to_json_binary(&AndromedaMsg::ProposeOwner {
+                address: AndrAddr::from_string("creator"),
+                expiration: None,
             })
             .unwrap(),
             funds: vec![],
@@ -423,6 +434,90 @@ fn test_claim_ownership() {
     assert_eq!(expected, res)
 }
 
+#[test]
+fn test_pause_components() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let inst_msg = InstantiateMsg {
+        app_components: vec![],
+        name: String::from("Some App"),
+        owner: None,
+        metadata: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        chain_info: None,
+    };
+
+    instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "token",
+            &Addr::unchecked("tokenaddress".to_string()),
+        )
+        .unwrap();
+    ADO_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            "anchor",
+            &Addr::unchecked("anchoraddress".to_string()),
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::PauseComponents { actions: None };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let pause_msg = |contract_addr: &str| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(&AndromedaMsg::Pause { actions: None }).unwrap(),
+            funds: vec![],
+        })
+    };
+    let expected = Response::new()
+        .add_message(pause_msg("anchoraddress"))
+        .add_message(pause_msg("tokenaddress"))
+        .add_attribute("method", "pause_components");
+    assert_eq!(expected, res);
+
+    // A paused app can still recover its components
+    let msg = ExecuteMsg::Pause { actions: None };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::ProxyMessage {
+        name: "token".to_string(),
+        msg: to_json_binary(&true).unwrap(),
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ActionPaused {
+            action: "ProxyMessage".to_string()
+        }
+    );
+
+    let actions = Some(vec!["Mint".to_string()]);
+    let msg = ExecuteMsg::UnpauseComponents {
+        actions: actions.clone(),
+    };
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "anchoraddress".to_string(),
+            msg: to_json_binary(&AndromedaMsg::Unpause { actions }).unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
 #[test]
 fn test_proxy_message_unauth() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -432,6 +527,7 @@ fn test_proxy_message_unauth() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -457,6 +553,7 @@ fn test_proxy_message_not_found() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -482,6 +579,7 @@ fn test_proxy_message() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -530,6 +628,7 @@ fn test_update_address_unauth() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -562,6 +661,7 @@ fn test_update_address_not_found() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
@@ -589,6 +689,7 @@ fn test_update_address() {
         app_components: vec![],
         name: String::from("Some App"),
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         chain_info: None,
     };
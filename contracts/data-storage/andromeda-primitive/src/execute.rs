@@ -15,6 +15,9 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
         ExecuteMsg::UpdateRestriction { restriction } => update_restriction(ctx, restriction),
         ExecuteMsg::SetValue { key, value } => set_value(ctx, key, value),
         ExecuteMsg::DeleteValue { key } => delete_value(ctx, key),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -20,6 +20,7 @@ pub fn mock_primitive_instantiate_msg(
     InstantiateMsg {
         kernel_address,
         owner,
+        metadata: None,
         restriction,
     }
 }
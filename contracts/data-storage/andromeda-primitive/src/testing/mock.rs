@@ -21,6 +21,7 @@ pub fn proper_initialization(restriction: PrimitiveRestriction) -> (MockDeps, Me
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         restriction,
     };
     let env = mock_env();
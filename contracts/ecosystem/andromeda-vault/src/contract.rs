@@ -13,7 +13,8 @@ use andromeda_std::{
         operators::IsOperatorResponse, AndromedaMsg, AndromedaQuery,
         InstantiateMsg as BaseInstantiateMsg,
     },
-    error::{from_semver, ContractError},
+    andr_migrate,
+    error::ContractError,
 };
 
 use cosmwasm_std::{
@@ -21,9 +22,8 @@ use cosmwasm_std::{
     ContractResult, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, QueryRequest, Reply,
     ReplyOn, Response, StdError, SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::nonpayable;
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-vault";
@@ -48,6 +48,7 @@ pub fn instantiate(
             ado_version: CONTRACT_VERSION.to_string(),
             operators: None,
             owner: msg.owner,
+            metadata: msg.metadata,
             kernel_address: msg.kernel_address,
         },
     )
@@ -77,7 +78,7 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
@@ -95,6 +96,9 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
         ExecuteMsg::Withdraw { .. } => Err(ContractError::NotImplemented {
             msg: Some("Please use WithdrawVault".to_string()),
         }),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -382,37 +386,8 @@ fn execute_update_strategy(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
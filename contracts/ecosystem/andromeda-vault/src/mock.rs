@@ -18,6 +18,7 @@ pub fn mock_vault_instantiate_msg(
     InstantiateMsg {
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
 
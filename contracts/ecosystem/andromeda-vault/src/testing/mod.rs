@@ -27,6 +27,7 @@ fn test_instantiate() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let env = mock_env();
     let info = mock_info("minter", &[]);
@@ -80,6 +81,7 @@ fn test_execute_update_strategy() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let info = mock_info(&depositor, &[]);
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -113,6 +115,7 @@ fn test_execute_update_strategy_not_operator() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let info = mock_info(&depositor, &[]);
     instantiate(deps.as_mut(), env.clone(), info.clone(), inst_msg).unwrap();
@@ -182,6 +185,7 @@ fn test_deposit_strategy() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let mut env = mock_env();
     let info = mock_info("minter", &[]);
@@ -242,6 +246,7 @@ fn test_deposit_strategy_partial_amount() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let mut env = mock_env();
     let info = mock_info("minter", &[]);
@@ -337,6 +342,7 @@ fn test_deposit_strategy_insufficient_partial_amount() {
     let inst_msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let env = mock_env();
     let info = mock_info("minter", &[]);
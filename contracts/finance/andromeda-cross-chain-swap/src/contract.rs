@@ -8,17 +8,17 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         AndrAddr,
     },
-    error::{from_semver, ContractError},
+    andr_migrate,
+    error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Reply,
-    Response, StdError, SubMsg,
+    attr, entry_point, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdError, SubMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 
 use cw_utils::one_coin;
-use semver::Version;
 
 use crate::{
     dex::{execute_swap_osmo, parse_swap_reply, MSG_FORWARD_ID, MSG_SWAP_ID},
@@ -49,6 +49,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
 
@@ -135,7 +136,7 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
@@ -158,6 +159,9 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
             slippage_percentage,
             window_seconds,
         ),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => Err(ContractError::UnsupportedOperation {}),
     }
 }
@@ -217,37 +221,8 @@ fn execute_swap_and_forward(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
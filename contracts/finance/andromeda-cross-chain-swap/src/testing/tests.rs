@@ -24,6 +24,7 @@ use cosmwasm_std::coin;
 fn init(deps: DepsMut) -> Response {
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
     };
 
@@ -7,19 +7,20 @@ use andromeda_finance::rate_limiting_withdrawals::{
 use andromeda_std::ado_contract::ADOContract;
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::{
+    ado_base::ownership::is_ownership_msg,
     ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
+    andr_migrate,
     common::encode_binary,
-    error::{from_semver, ContractError},
+    error::ContractError,
 };
 
 use cosmwasm_std::{
     ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
     Response, Uint128,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 
 use cw_utils::{nonpayable, one_coin};
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-rate-limiting-withdrawals";
@@ -79,6 +80,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
     let mod_resp =
@@ -96,36 +98,42 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    let contract = ADOContract::default();
-
-    if !matches!(msg, ExecuteMsg::UpdateAppContract { .. })
-        && !matches!(msg, ExecuteMsg::UpdateOwner { .. })
-    {
-        contract.module_hook::<Response>(
-            &deps.as_ref(),
-            AndromedaHook::OnExecute {
-                sender: info.sender.to_string(),
-                payload: encode_binary(&msg)?,
-            },
-        )?;
-    }
-
     let ctx = ExecuteContext::new(deps, info, env);
 
     match msg {
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
-pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+
+    let hook_events =
+        if !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg) {
+            let (_, events) = contract.module_hook::<Response>(
+                &ctx.deps.as_ref(),
+                &mut ctx.resolved,
+                AndromedaHook::OnExecute {
+                    sender: ctx.info.sender.to_string(),
+                    payload: encode_binary(&msg)?,
+                },
+            )?;
+            events
+        } else {
+            vec![]
+        };
     match msg {
         ExecuteMsg::Deposits { recipient } => execute_deposit(ctx, recipient),
         ExecuteMsg::Withdraws { amount } => execute_withdraw(ctx, amount),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
+    .map(|res| res.add_events(hook_events))
 }
 
 fn execute_deposit(
@@ -277,37 +285,8 @@ fn execute_withdraw(ctx: ExecuteContext, amount: Uint128) -> Result<Response, Co
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[entry_point]
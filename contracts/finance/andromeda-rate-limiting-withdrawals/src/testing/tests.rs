@@ -22,6 +22,7 @@ fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
     let msg = InstantiateMsg {
         modules,
         owner: Some(OWNER.to_owned()),
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         allowed_coin: CoinAndLimit {
             coin: "junox".to_string(),
@@ -209,6 +210,7 @@ fn test_withdraw_over_allowed_limit() {
         },
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
     let exec = ExecuteMsg::Deposits {
@@ -243,6 +245,7 @@ fn test_withdraw_works() {
         },
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
     let exec = ExecuteMsg::Deposits {
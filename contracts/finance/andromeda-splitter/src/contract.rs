@@ -1,23 +1,20 @@
-use crate::state::SPLITTER;
+use crate::state::{grant_operators_updater_role, SPLITTER, UPDATER_ROLE};
 use andromeda_finance::splitter::{
     validate_recipient_list, AddressPercent, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg,
     MigrateMsg, QueryMsg, Splitter,
 };
 
 use andromeda_std::{
-    ado_base::InstantiateMsg as BaseInstantiateMsg,
-    amp::messages::AMPPkt,
-    common::encode_binary,
-    error::{from_semver, ContractError},
+    ado_base::InstantiateMsg as BaseInstantiateMsg, amp::messages::AMPPkt, andr_migrate,
+    common::encode_binary, error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
     attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
     Reply, Response, StdError, SubMsg, Timestamp, Uint128,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_utils::{nonpayable, Expiration};
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-splitter";
@@ -80,6 +77,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
 
@@ -110,7 +108,7 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
@@ -119,6 +117,9 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
         ExecuteMsg::UpdateRecipients { recipients } => execute_update_recipients(ctx, recipients),
         ExecuteMsg::UpdateLock { lock_time } => execute_update_lock(ctx, lock_time),
         ExecuteMsg::Send {} => execute_send(ctx),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -209,7 +210,11 @@ fn execute_update_recipients(
     nonpayable(&info)?;
 
     ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ADOContract::default().is_owner_or_role(
+            deps.storage,
+            UPDATER_ROLE,
+            info.sender.as_str()
+        )?,
         ContractError::Unauthorized {}
     );
 
@@ -242,7 +247,11 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: u64) -> Result<Response,
     nonpayable(&info)?;
 
     ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ADOContract::default().is_owner_or_role(
+            deps.storage,
+            UPDATER_ROLE,
+            info.sender.as_str()
+        )?,
         ContractError::Unauthorized {}
     );
 
@@ -277,37 +286,10 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: u64) -> Result<Response,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION, {
+        "0.2.4" => grant_operators_updater_role,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -1,6 +1,6 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 
-use crate::contract::{execute, instantiate, query, reply};
+use crate::contract::{execute, instantiate, migrate, query, reply};
 use andromeda_finance::splitter::{AddressPercent, ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_testing::{mock_ado, mock_contract::ExecuteResult, MockADO, MockContract};
 use cosmwasm_std::{Addr, Coin, Empty};
@@ -20,7 +20,14 @@ impl MockSplitter {
         owner: Option<String>,
     ) -> Self {
         let msg = mock_splitter_instantiate_msg(recipients, kernel_address, lock_time, owner);
-        let res = app.instantiate_contract(code_id, sender, &msg, &[], "Andromeda Splitter", None);
+        let res = app.instantiate_contract(
+            code_id,
+            sender.clone(),
+            &msg,
+            &[],
+            "Andromeda Splitter",
+            Some(sender.to_string()),
+        );
 
         Self(res.unwrap())
     }
@@ -33,7 +40,9 @@ impl MockSplitter {
 }
 
 pub fn mock_andromeda_splitter() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new_with_empty(execute, instantiate, query).with_reply(reply);
+    let contract = ContractWrapper::new_with_empty(execute, instantiate, query)
+        .with_reply(reply)
+        .with_migrate(migrate);
     Box::new(contract)
 }
 
@@ -48,6 +57,7 @@ pub fn mock_splitter_instantiate_msg(
         lock_time,
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
 
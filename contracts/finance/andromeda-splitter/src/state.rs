@@ -1,6 +1,19 @@
 use andromeda_finance::splitter::Splitter;
-use cosmwasm_std::Addr;
+use andromeda_std::{ado_contract::ADOContract, error::ContractError};
+use cosmwasm_std::{Addr, DepsMut};
 use cw_storage_plus::Item;
 
+/// Role granting permission to update the recipients and lock alongside the owner.
+pub const UPDATER_ROLE: &str = "updater";
+
 pub const SPLITTER: Item<Splitter> = Item::new("splitter");
 pub const KERNEL_ADDRESS: Item<Addr> = Item::new("kernel_address");
+
+/// Grants the updater role to the current operators, who could update splitters before the role existed
+pub fn grant_operators_updater_role(deps: DepsMut) -> Result<(), ContractError> {
+    let contract = ADOContract::default();
+    for operator in contract.query_operators(deps.as_ref())?.operators {
+        contract.grant_role(deps.storage, UPDATER_ROLE, &Addr::unchecked(operator))?;
+    }
+    Ok(())
+}
@@ -1,7 +1,13 @@
 use andromeda_std::{
+    ado_base::{
+        pausing::IsPausedResponse,
+        permissioning::{Permission, PermissionInfo},
+    },
+    ado_contract::ADOContract,
     amp::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
+        AndrAddr,
     },
     error::ContractError,
 };
@@ -11,18 +17,20 @@ use cosmwasm_std::{
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
     to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Response, SubMsg, Timestamp,
 };
+use cw2::set_contract_version;
 use cw_utils::Expiration;
 pub const OWNER: &str = "creator";
 
 use super::mock_querier::MOCK_KERNEL_CONTRACT;
 
 use crate::{
-    contract::{execute, instantiate, query},
-    state::SPLITTER,
+    contract::{execute, instantiate, migrate, query},
+    state::{SPLITTER, UPDATER_ROLE},
     testing::mock_querier::mock_dependencies_custom,
 };
 use andromeda_finance::splitter::{
-    AddressPercent, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg, QueryMsg, Splitter,
+    AddressPercent, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg, MigrateMsg, QueryMsg,
+    Splitter,
 };
 
 fn init(deps: DepsMut) -> Response {
@@ -32,6 +40,7 @@ fn init(deps: DepsMut) -> Response {
     }];
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         recipients: mock_recipient,
         lock_time: Some(100_000),
@@ -128,6 +137,315 @@ fn test_execute_update_recipients() {
     assert_eq!(splitter.recipients, recipient);
 }
 
+#[test]
+fn test_execute_update_recipients_with_updater_role() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateRecipients {
+        recipients: vec![AddressPercent {
+            recipient: Recipient::from_string(String::from("addr1")),
+            percent: Decimal::percent(100),
+        }],
+    };
+    let splitter = Splitter {
+        recipients: vec![],
+        lock: Expiration::AtTime(Timestamp::from_seconds(0)),
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let info = mock_info("updater", &[]);
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+    assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
+
+    let grant_msg = ExecuteMsg::GrantRole {
+        role: UPDATER_ROLE.to_string(),
+        actor: AndrAddr::from_string("updater"),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), grant_msg).unwrap();
+
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::UpdateLock { lock_time: 100_000 },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_migrate_grants_operators_updater_role() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateOperators {
+            operators: vec!["operator".to_string()],
+        },
+    )
+    .unwrap();
+    set_contract_version(
+        deps.as_mut().storage,
+        "crates.io:andromeda-splitter",
+        "0.2.3",
+    )
+    .unwrap();
+
+    migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+
+    // Operators keep the ability to update the splitter through the updater role
+    assert!(ADOContract::default()
+        .has_role(deps.as_ref().storage, UPDATER_ROLE, "operator")
+        .unwrap());
+}
+
+#[test]
+fn test_execute_batch() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let splitter = Splitter {
+        recipients: vec![],
+        lock: Expiration::AtTime(Timestamp::from_seconds(0)),
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let recipients = vec![AddressPercent {
+        recipient: Recipient::from_string(String::from("addr1")),
+        percent: Decimal::percent(100),
+    }];
+    let batch = ExecuteMsg::Batch {
+        msgs: vec![
+            ExecuteMsg::UpdateRecipients {
+                recipients: recipients.clone(),
+            },
+            ExecuteMsg::UpdateLock { lock_time: 100_000 },
+            ExecuteMsg::SetPermission {
+                actor: AndrAddr::from_string("sender"),
+                action: "Send".to_string(),
+                permission: Permission::blacklisted(None),
+            },
+        ],
+        atomic: true,
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        batch.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[Coin::new(100, "uandr")]),
+        batch.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "Batches cannot be sent with funds".to_string()
+        }
+    );
+
+    let res = execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), batch).unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("action", "update_recipients")));
+    assert!(res.attributes.contains(&attr("action", "update_lock")));
+    assert!(res.attributes.contains(&attr("action", "set_permission")));
+
+    let splitter = SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert_eq!(splitter.recipients, recipients);
+    assert!(!splitter.lock.is_expired(&env.block));
+    let permissions: Vec<PermissionInfo> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Permissions {
+                actor: "sender".to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(permissions.len(), 1);
+
+    // The splitter is now locked so updating the recipients fails
+    let msgs = vec![
+        ExecuteMsg::UpdateRecipients {
+            recipients: vec![AddressPercent {
+                recipient: Recipient::from_string(String::from("addr2")),
+                percent: Decimal::percent(100),
+            }],
+        },
+        ExecuteMsg::RemovePermission {
+            action: "Send".to_string(),
+            actor: AndrAddr::from_string("sender"),
+        },
+    ];
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::Batch {
+            msgs: msgs.clone(),
+            atomic: true,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ContractLocked {});
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::Batch {
+            msgs,
+            atomic: false,
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr(
+        "batch_error:0",
+        ContractError::ContractLocked {}.to_string()
+    )));
+    assert!(res
+        .attributes
+        .contains(&attr("action", "remove_permission")));
+    let splitter = SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert_eq!(splitter.recipients, recipients);
+    let permissions: Vec<PermissionInfo> = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Permissions {
+                actor: "sender".to_string(),
+                limit: None,
+                start_after: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(permissions.is_empty());
+}
+
+#[test]
+fn test_execute_batch_paused() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let splitter = Splitter {
+        recipients: vec![],
+        lock: Expiration::AtTime(Timestamp::from_seconds(0)),
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let owner_info = mock_info(OWNER, &[]);
+    let pause_msg = ExecuteMsg::Pause {
+        actions: Some(vec!["UpdateRecipients".to_string()]),
+    };
+    execute(deps.as_mut(), env.clone(), owner_info.clone(), pause_msg).unwrap();
+
+    let msgs = vec![
+        ExecuteMsg::UpdateRecipients {
+            recipients: vec![AddressPercent {
+                recipient: Recipient::from_string(String::from("addr1")),
+                percent: Decimal::percent(100),
+            }],
+        },
+        ExecuteMsg::UpdateLock { lock_time: 100_000 },
+    ];
+    let paused_err = ContractError::ActionPaused {
+        action: "UpdateRecipients".to_string(),
+    };
+
+    // Atomic batches fail on the paused message
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::Batch {
+            msgs: msgs.clone(),
+            atomic: true,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, paused_err);
+
+    // Non-atomic batches skip the paused message and handle the rest
+    let res = execute(
+        deps.as_mut(),
+        env,
+        owner_info,
+        ExecuteMsg::Batch {
+            msgs,
+            atomic: false,
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("batch_error:0", paused_err.to_string())));
+    assert!(res.attributes.contains(&attr("action", "update_lock")));
+    let splitter = SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert!(splitter.recipients.is_empty());
+}
+
+#[test]
+fn test_execute_send_paused() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let owner_info = mock_info(OWNER, &[]);
+    let pause_msg = ExecuteMsg::Pause {
+        actions: Some(vec!["Send".to_string()]),
+    };
+    execute(deps.as_mut(), env.clone(), owner_info.clone(), pause_msg).unwrap();
+
+    let info = mock_info("sender", &[Coin::new(10000, "uluna")]);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::Send {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ActionPaused {
+            action: "Send".to_string()
+        }
+    );
+
+    let query_msg = QueryMsg::IsPaused {
+        action: Some("Send".to_string()),
+    };
+    let res: IsPausedResponse =
+        from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert!(res.is_paused);
+
+    let unpause_msg = ExecuteMsg::Unpause { actions: None };
+    execute(deps.as_mut(), env.clone(), owner_info, unpause_msg).unwrap();
+    execute(deps.as_mut(), env, info, ExecuteMsg::Send {}).unwrap();
+}
+
 #[test]
 fn test_execute_send() {
     let mut deps = mock_dependencies_custom(&[]);
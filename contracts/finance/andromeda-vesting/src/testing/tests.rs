@@ -29,6 +29,7 @@ fn init(deps: DepsMut) -> Response {
         unbonding_duration: Duration::Height(UNBONDING_BLOCK_DURATION),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -371,6 +372,7 @@ fn test_create_batch_multi_batch_not_supported() {
         unbonding_duration: Duration::Height(0u64),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
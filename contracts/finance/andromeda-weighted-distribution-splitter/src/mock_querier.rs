@@ -128,7 +128,7 @@ impl WasmMockQuerier {
                                 })),
                             ],
                         ),
-                        Funds::Cw20(_) => {
+                        Funds::Cw20(_) | Funds::Multi(_) => {
                             return SystemResult::Ok(ContractResult::Ok(
                                 to_json_binary(&None::<Response>).unwrap(),
                             ))
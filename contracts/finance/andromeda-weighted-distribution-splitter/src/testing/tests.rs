@@ -2,11 +2,8 @@ use andromeda_std::testing::mock_querier::{
     mock_dependencies_custom, MOCK_ADDRESS_LIST_CONTRACT, MOCK_KERNEL_CONTRACT,
 };
 use andromeda_std::{
-    ado_base::modules::Module,
-    ado_base::InstantiateMsg as BaseInstantiateMsg,
-    ado_contract::ADOContract,
-    amp::{recipient::Recipient, AndrAddr},
-    error::ContractError,
+    ado_base::modules::Module, ado_base::InstantiateMsg as BaseInstantiateMsg,
+    ado_contract::ADOContract, amp::recipient::Recipient, error::ContractError,
 };
 use cosmwasm_std::{
     attr,
@@ -29,11 +26,11 @@ const MOCK_RECIPIENT2: &str = "recipient2";
 fn test_update_app_contract() {
     let mut deps = mock_dependencies_custom(&[]);
 
-    let modules: Vec<Module> = vec![Module {
-        name: Some("address_list".to_string()),
-        address: AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT.to_string()),
-        is_mutable: false,
-    }];
+    let modules: Vec<Module> = vec![Module::new(
+        "address_list".to_string(),
+        MOCK_ADDRESS_LIST_CONTRACT.to_string(),
+        false,
+    )];
 
     let info = mock_info("app_contract", &[]);
     let msg = InstantiateMsg {
@@ -51,6 +48,7 @@ fn test_update_app_contract() {
         lock_time: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
 
     let _res = instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
@@ -120,6 +118,7 @@ fn test_instantiate() {
         lock_time: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let res = instantiate(deps.as_mut(), env, info, msg).unwrap();
     assert_eq!(0, res.messages.len());
@@ -157,6 +156,7 @@ fn test_execute_update_lock() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -210,6 +210,7 @@ fn test_execute_update_lock_too_short() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -251,6 +252,7 @@ fn test_execute_update_lock_too_long() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -292,6 +294,7 @@ fn test_execute_update_lock_already_locked() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -333,6 +336,7 @@ fn test_execute_update_lock_unauthorized() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -377,6 +381,7 @@ fn test_execute_remove_recipient() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -468,6 +473,7 @@ fn test_execute_remove_recipient_not_on_list() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -530,6 +536,7 @@ fn test_execute_remove_recipient_contract_locked() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -600,6 +607,7 @@ fn test_execute_remove_recipient_unauthorized() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -646,6 +654,7 @@ fn test_update_recipient_weight() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -741,6 +750,7 @@ fn test_update_recipient_weight_locked_contract() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -814,6 +824,7 @@ fn test_update_recipient_weight_user_not_found() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -885,6 +896,7 @@ fn test_update_recipient_weight_invalid_weight() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -952,6 +964,7 @@ fn test_execute_add_recipient() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1056,6 +1069,7 @@ fn test_execute_add_recipient_duplicate_recipient() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1133,6 +1147,7 @@ fn test_execute_add_recipient_invalid_weight() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1202,6 +1217,7 @@ fn test_execute_add_recipient_locked_contract() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1254,6 +1270,7 @@ fn test_execute_add_recipient_unauthorized() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1289,6 +1306,7 @@ fn test_execute_update_recipients() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1359,6 +1377,7 @@ fn test_execute_update_recipients_invalid_weight() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1413,6 +1432,7 @@ fn test_execute_update_recipients_contract_locked() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -1465,6 +1485,7 @@ fn test_execute_update_recipients_unauthorized() {
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
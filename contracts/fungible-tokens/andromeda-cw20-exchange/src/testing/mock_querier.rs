@@ -35,6 +35,7 @@ pub fn mock_dependencies_custom(
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -29,6 +29,7 @@ fn init(deps: DepsMut) -> Result<Response, ContractError> {
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
         token_address: AndrAddr::from_string("cw20"),
     };
@@ -39,6 +39,7 @@ fn init(
         additional_rewards,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
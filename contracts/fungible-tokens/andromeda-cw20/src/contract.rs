@@ -1,26 +1,27 @@
 use andromeda_fungible_tokens::cw20::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use andromeda_std::{
+    ado_base::ownership::is_ownership_msg,
     ado_base::{
         hooks::AndromedaHook, AndromedaMsg, AndromedaQuery, InstantiateMsg as BaseInstantiateMsg,
     },
     ado_contract::ADOContract,
+    andr_migrate,
     common::Funds,
     common::{context::ExecuteContext, encode_binary},
-    error::{from_semver, ContractError},
+    error::ContractError,
 };
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, from_json, to_json_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
+    from_json, to_json_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
 use cw20_base::{
     contract::{execute as execute_cw20, instantiate as cw20_instantiate, query as cw20_query},
     state::BALANCES,
 };
-use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-cw20";
@@ -28,7 +29,7 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
@@ -46,18 +47,21 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.clone().kernel_address,
             owner: msg.clone().owner,
+            metadata: msg.clone().metadata,
         },
     )?;
     let modules_resp =
         contract.register_modules(info.sender.as_str(), deps.storage, msg.clone().modules)?;
 
-    let cw20_resp = cw20_instantiate(deps, env, info, msg.into())?;
+    let cw20_resp = cw20_instantiate(deps.branch(), env, info.clone(), msg.into())?;
 
-    Ok(resp
+    let resp = resp
         .add_submessages(modules_resp.messages)
         .add_attributes(modules_resp.attributes)
         .add_submessages(cw20_resp.messages)
-        .add_attributes(cw20_resp.attributes))
+        .add_attributes(cw20_resp.attributes);
+
+    contract.on_instantiate(&deps.as_ref(), info.sender.to_string(), resp)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -73,24 +77,32 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
-pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
-    if !matches!(msg, ExecuteMsg::UpdateAppContract { .. })
-        && !matches!(msg, ExecuteMsg::UpdateOwner { .. })
-    {
-        contract.module_hook::<Response>(
+    let is_hooked = !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg);
+    let hook_events = if is_hooked {
+        let (_, events) = contract.module_hook::<Response>(
             &ctx.deps.as_ref(),
+            &mut ctx.resolved,
             AndromedaHook::OnExecute {
                 sender: ctx.info.sender.to_string(),
                 payload: encode_binary(&msg)?,
             },
         )?;
-    }
-    match msg {
+        events
+    } else {
+        vec![]
+    };
+
+    let action = msg.as_ref().to_string();
+    // The message is handled over a branch of the context so it remains available for the post execute hook
+    let mut parent_ctx = ctx;
+    let ctx = parent_ctx.branch();
+    let res = match msg {
         ExecuteMsg::Transfer { recipient, amount } => execute_transfer(ctx, recipient, amount),
         ExecuteMsg::Burn { amount } => execute_burn(ctx, amount),
         ExecuteMsg::Send {
@@ -99,6 +111,9 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
             msg,
         } => execute_send(ctx, contract, amount, msg),
         ExecuteMsg::Mint { recipient, amount } => execute_mint(ctx, recipient, amount),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => {
             let serialized = encode_binary(&msg)?;
             match from_json::<AndromedaMsg>(&serialized) {
@@ -106,6 +121,22 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
                 _ => Ok(execute_cw20(ctx.deps, ctx.env, ctx.info, msg.into())?),
             }
         }
+    }?
+    .add_events(hook_events);
+
+    if is_hooked {
+        contract.merge_module_hook(
+            &parent_ctx.deps.as_ref(),
+            &mut parent_ctx.resolved,
+            AndromedaHook::PostExecute {
+                action,
+                sender: parent_ctx.info.sender.to_string(),
+                attributes: res.attributes.clone(),
+            },
+            res,
+        )
+    } else {
+        Ok(res)
     }
 }
 
@@ -115,11 +146,16 @@ fn execute_transfer(
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
 
     let (msgs, events, remainder) = ADOContract::default().on_funds_transfer(
         &deps.as_ref(),
+        &mut resolved,
         info.sender.to_string(),
         Funds::Cw20(Cw20Coin {
             address: env.contract.address.to_string(),
@@ -132,7 +168,7 @@ fn execute_transfer(
     )?;
 
     let remaining_amount = match remainder {
-        Funds::Native(..) => amount, //What do we do in the case that the rates returns remaining amount as native funds?
+        Funds::Native(..) | Funds::Multi(..) => amount, //What do we do in the case that the rates returns remaining amount as native funds?
         Funds::Cw20(coin) => coin.amount,
     };
 
@@ -175,15 +211,25 @@ fn transfer_tokens(
 
 fn execute_burn(ctx: ExecuteContext, amount: Uint128) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        mut deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
+    let sender = info.sender.to_string();
 
-    Ok(execute_cw20(
-        deps,
-        env,
-        info,
-        Cw20ExecuteMsg::Burn { amount },
-    )?)
+    let resp = execute_cw20(deps.branch(), env, info, Cw20ExecuteMsg::Burn { amount })?;
+    ADOContract::default().merge_module_hook(
+        &deps.as_ref(),
+        &mut resolved,
+        AndromedaHook::OnBurn {
+            token_id: None,
+            amount: Some(amount),
+            sender,
+        },
+        resp,
+    )
 }
 
 fn execute_send(
@@ -193,11 +239,16 @@ fn execute_send(
     msg: Binary,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
 
     let (msgs, events, remainder) = ADOContract::default().on_funds_transfer(
         &deps.as_ref(),
+        &mut resolved,
         info.sender.to_string(),
         Funds::Cw20(Cw20Coin {
             address: env.contract.address.to_string(),
@@ -211,7 +262,7 @@ fn execute_send(
     )?;
 
     let remaining_amount = match remainder {
-        Funds::Native(..) => amount, //What do we do in the case that the rates returns remaining amount as native funds?
+        Funds::Native(..) | Funds::Multi(..) => amount, //What do we do in the case that the rates returns remaining amount as native funds?
         Funds::Cw20(coin) => coin.amount,
     };
 
@@ -241,15 +292,32 @@ fn execute_mint(
     amount: Uint128,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        mut deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
 
-    Ok(execute_cw20(
-        deps,
+    let resp = execute_cw20(
+        deps.branch(),
         env,
         info,
-        Cw20ExecuteMsg::Mint { recipient, amount },
-    )?)
+        Cw20ExecuteMsg::Mint {
+            recipient: recipient.clone(),
+            amount,
+        },
+    )?;
+    ADOContract::default().merge_module_hook(
+        &deps.as_ref(),
+        &mut resolved,
+        AndromedaHook::OnMint {
+            token_id: None,
+            amount: Some(amount),
+            recipient,
+        },
+        resp,
+    )
 }
 
 fn filter_out_cw20_messages(
@@ -279,37 +347,8 @@ fn filter_out_cw20_messages(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
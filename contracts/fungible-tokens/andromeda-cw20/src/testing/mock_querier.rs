@@ -18,6 +18,7 @@ pub const MOCK_CW20_CONTRACT: &str = "mock_cw20_contract";
 pub const MOCK_RATES_CONTRACT: &str = "mock_rates_contract";
 pub const MOCK_TAX_RECIPIENT: &str = "tax_recipient";
 pub const MOCK_ROYALTY_RECIPIENT: &str = "royalty_recipient";
+pub const MOCK_HOOKS_CONTRACT: &str = "mock_hooks_contract";
 
 pub fn mock_dependencies_custom(
     contract_balance: &[Coin],
@@ -43,6 +44,7 @@ pub fn mock_dependencies_custom(
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -80,6 +82,7 @@ impl WasmMockQuerier {
                     MOCK_CW20_CONTRACT => self.handle_cw20_query(msg),
                     MOCK_RATES_CONTRACT => self.handle_rates_query(msg),
                     MOCK_ADDRESS_LIST_CONTRACT => self.handle_addresslist_query(msg),
+                    MOCK_HOOKS_CONTRACT => self.handle_hooks_query(msg),
                     _ => MockAndromedaQuerier::default().handle_query(&self.base, request),
                 }
             }
@@ -120,6 +123,24 @@ impl WasmMockQuerier {
         }
     }
 
+    /// Responds to hooks with a `hook` attribute naming the hook that was called
+    fn handle_hooks_query(&self, msg: &Binary) -> QuerierResult {
+        let HookMsg::AndrHook(hook_msg) = from_json(msg).unwrap();
+        let hook = match hook_msg {
+            AndromedaHook::OnInstantiate { .. } => "on_instantiate",
+            AndromedaHook::OnMint { .. } => "on_mint",
+            AndromedaHook::OnBurn { .. } => "on_burn",
+            AndromedaHook::PostExecute { .. } => "post_execute",
+            _ => {
+                return SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&None::<Response>).unwrap(),
+                ))
+            }
+        };
+        let response: Response = Response::default().add_attribute("hook", hook);
+        SystemResult::Ok(ContractResult::Ok(to_json_binary(&Some(response)).unwrap()))
+    }
+
     fn handle_rates_query(&self, msg: &Binary) -> QuerierResult {
         match from_json(msg).unwrap() {
             HookMsg::AndrHook(hook_msg) => match hook_msg {
@@ -155,7 +176,7 @@ impl WasmMockQuerier {
                             ],
                         ),
                         // TODO: IMPLEMENT CW20
-                        Funds::Cw20(_) => {
+                        Funds::Cw20(_) | Funds::Multi(_) => {
                             let resp = OnFundsTransferResponse::default();
                             return SystemResult::Ok(ContractResult::Ok(
                                 to_json_binary(&resp).unwrap(),
@@ -3,17 +3,17 @@ use crate::testing::mock_querier::mock_dependencies_custom;
 use andromeda_fungible_tokens::cw20::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::testing::mock_querier::MOCK_ADDRESS_LIST_CONTRACT;
 use andromeda_std::{
-    ado_base::Module, amp::addresses::AndrAddr, error::ContractError,
-    testing::mock_querier::MOCK_KERNEL_CONTRACT,
+    ado_base::Module, error::ContractError, testing::mock_querier::MOCK_KERNEL_CONTRACT,
 };
 use cosmwasm_std::{
+    attr,
     testing::{mock_env, mock_info},
     to_json_binary, Addr, DepsMut, Response, StdError, Uint128,
 };
-use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw20::{Cw20Coin, Cw20ReceiveMsg, MinterResponse};
 use cw20_base::state::BALANCES;
 
-use super::mock_querier::MOCK_CW20_CONTRACT;
+use super::mock_querier::{MOCK_CW20_CONTRACT, MOCK_HOOKS_CONTRACT};
 
 fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
     let msg = InstantiateMsg {
@@ -29,6 +29,7 @@ fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
         modules,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
 
     let info = mock_info("owner", &[]);
@@ -48,12 +49,11 @@ fn test_andr_query() {
 
 #[test]
 fn test_transfer() {
-    let modules: Vec<Module> = vec![Module {
-        name: Some(MOCK_ADDRESS_LIST_CONTRACT.to_owned()),
-        address: AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT.to_owned()),
-
-        is_mutable: false,
-    }];
+    let modules: Vec<Module> = vec![Module::new(
+        MOCK_ADDRESS_LIST_CONTRACT.to_owned(),
+        MOCK_ADDRESS_LIST_CONTRACT.to_owned(),
+        false,
+    )];
 
     let mut deps = mock_dependencies_custom(&[]);
     let res = init(deps.as_mut(), Some(modules));
@@ -188,3 +188,58 @@ fn test_send() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_module_hooks() {
+    let modules: Vec<Module> = vec![Module::new(
+        MOCK_HOOKS_CONTRACT.to_owned(),
+        MOCK_HOOKS_CONTRACT.to_owned(),
+        false,
+    )];
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        name: MOCK_CW20_CONTRACT.into(),
+        symbol: "Symbol".into(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            amount: 1000u128.into(),
+            address: "sender".to_string(),
+        }],
+        mint: Some(MinterResponse {
+            minter: "owner".to_string(),
+            cap: None,
+        }),
+        marketing: None,
+        modules: Some(modules),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        metadata: None,
+    };
+    let res = instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+    assert!(res.attributes.contains(&attr("hook", "on_instantiate")));
+
+    let msg = ExecuteMsg::Mint {
+        recipient: "recipient".to_string(),
+        amount: 100u128.into(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+    let hooks: Vec<&str> = res
+        .attributes
+        .iter()
+        .filter(|attr| attr.key == "hook")
+        .map(|attr| attr.value.as_str())
+        .collect();
+    assert_eq!(hooks, vec!["on_mint", "post_execute"]);
+
+    let msg = ExecuteMsg::Burn {
+        amount: 100u128.into(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("recipient", &[]), msg).unwrap();
+    let hooks: Vec<&str> = res
+        .attributes
+        .iter()
+        .filter(|attr| attr.key == "hook")
+        .map(|attr| attr.value.as_str())
+        .collect();
+    assert_eq!(hooks, vec!["on_burn", "post_execute"]);
+}
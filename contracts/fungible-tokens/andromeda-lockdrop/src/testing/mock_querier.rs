@@ -54,6 +54,7 @@ pub fn mock_dependencies_custom(
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -130,7 +131,7 @@ impl WasmMockQuerier {
                                 })),
                             ],
                         ),
-                        Funds::Cw20(_) => {
+                        Funds::Cw20(_) | Funds::Multi(_) => {
                             let resp: Response = Response::default();
                             return SystemResult::Ok(ContractResult::Ok(
                                 to_json_binary(&resp).unwrap(),
@@ -38,6 +38,7 @@ fn init(deps: DepsMut) -> Result<Response, ContractError> {
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -102,6 +103,7 @@ fn test_instantiate_init_timestamp_past() {
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -131,6 +133,7 @@ fn test_instantiate_init_deposit_window_zero() {
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -154,6 +157,7 @@ fn test_instantiate_init_withdrawal_window_zero() {
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -177,6 +181,7 @@ fn test_instantiate_init_deposit_window_less_than_withdrawal_window() {
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
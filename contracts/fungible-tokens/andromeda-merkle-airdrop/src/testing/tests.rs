@@ -28,6 +28,7 @@ fn proper_instantiation() {
         asset_info: AssetInfoUnchecked::cw20("anchor0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -61,6 +62,7 @@ fn register_merkle_root() {
         asset_info: AssetInfoUnchecked::cw20("anchor0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -132,6 +134,7 @@ fn claim() {
         asset_info: AssetInfoUnchecked::cw20("token0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -296,6 +299,7 @@ fn claim_native() {
         asset_info: AssetInfoUnchecked::native("uusd"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -383,6 +387,7 @@ fn multiple_claim() {
         asset_info: AssetInfoUnchecked::cw20("token0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -453,6 +458,7 @@ fn stage_expires() {
         asset_info: AssetInfoUnchecked::cw20("anchor0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -496,6 +502,7 @@ fn cant_burn() {
         asset_info: AssetInfoUnchecked::cw20("token0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -536,6 +543,7 @@ fn can_burn() {
         asset_info: AssetInfoUnchecked::cw20("token0000"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
@@ -621,6 +629,7 @@ fn can_burn_native() {
         asset_info: AssetInfoUnchecked::native("uusd"),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
         modules: None,
     };
 
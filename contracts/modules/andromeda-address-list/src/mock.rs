@@ -60,6 +60,7 @@ pub fn mock_address_list_instantiate_msg(
         is_inclusive,
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
 
@@ -25,6 +25,7 @@ fn init(deps: DepsMut, info: MessageInfo) {
             is_inclusive: true,
             kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
             owner: None,
+            metadata: None,
         },
     )
     .unwrap();
@@ -261,6 +262,42 @@ fn test_execute_hook_blacklist() {
     assert_eq!(ContractError::Unauthorized {}, res_err);
 }
 
+#[test]
+fn test_unhandled_hooks() {
+    let mut deps = mock_dependencies_custom(&[]);
+    // Mark it as a whitelist.
+    IS_INCLUSIVE.save(deps.as_mut().storage, &true).unwrap();
+    init(deps.as_mut(), mock_info("creator", &[]));
+
+    // Hooks other than `OnExecute` are not restricted by the list
+    let hooks = vec![
+        AndromedaHook::OnInstantiate {
+            sender: "random".to_string(),
+        },
+        AndromedaHook::OnMint {
+            token_id: Some("token_id".to_string()),
+            amount: None,
+            recipient: "random".to_string(),
+        },
+        AndromedaHook::OnBurn {
+            token_id: None,
+            amount: Some(100u128.into()),
+            sender: "random".to_string(),
+        },
+        AndromedaHook::PostExecute {
+            action: "Mint".to_string(),
+            sender: "random".to_string(),
+            attributes: vec![attr("action", "mint")],
+        },
+    ];
+    for hook in hooks {
+        let msg = QueryMsg::AndrHook(hook);
+        let res: Option<Response> =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(None, res);
+    }
+}
+
 #[test]
 fn test_andr_get_query() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -2,7 +2,7 @@
 use crate::state::{Config, CONFIG};
 use andromeda_modules::rates::{
     calculate_fee, ExecuteMsg, InstantiateMsg, MigrateMsg, PaymentAttribute, PaymentsResponse,
-    QueryMsg, RateInfo,
+    QueryMsg, Rate, RateInfo,
 };
 use andromeda_std::{
     ado_base::{
@@ -10,18 +10,19 @@ use andromeda_std::{
         InstantiateMsg as BaseInstantiateMsg,
     },
     ado_contract::ADOContract,
+    andr_migrate,
     common::{context::ExecuteContext, deduct_funds, encode_binary, Funds},
-    error::{from_semver, ContractError},
+    error::ContractError,
+    os::aos_querier::ResolvedAddresses,
 };
 
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, coin, ensure, Binary, Coin, Deps, DepsMut, Env, Event, MessageInfo, Response, SubMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw20::Cw20Coin;
 use cw_utils::nonpayable;
-use semver::Version;
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-rates";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -48,6 +49,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
 
@@ -67,13 +69,16 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
 pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::UpdateRates { rates } => execute_update_rates(ctx, rates),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -97,37 +102,8 @@ fn execute_update_rates(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -144,7 +120,12 @@ fn handle_andromeda_hook(deps: Deps, msg: AndromedaHook) -> Result<Binary, Contr
         AndromedaHook::OnFundsTransfer { amount, .. } => {
             encode_binary(&query_deducted_funds(deps, amount)?)
         }
-        _ => Ok(encode_binary(&None::<Response>)?),
+        AndromedaHook::OnExecute { .. }
+        | AndromedaHook::OnTokenTransfer { .. }
+        | AndromedaHook::OnInstantiate { .. }
+        | AndromedaHook::OnMint { .. }
+        | AndromedaHook::OnBurn { .. }
+        | AndromedaHook::PostExecute { .. } => Ok(encode_binary(&None::<Response>)?),
     }
 }
 
@@ -161,14 +142,93 @@ pub fn query_deducted_funds(
     funds: Funds,
 ) -> Result<OnFundsTransferResponse, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let is_multi = matches!(funds, Funds::Multi(_));
+    let mut assets: Vec<(Coin, bool)> = vec![];
+    for asset in funds.into_assets() {
+        assets.push(match asset {
+            Funds::Native(coin) => (coin, true),
+            Funds::Cw20(cw20_coin) => (coin(cw20_coin.amount.u128(), cw20_coin.address), false),
+            Funds::Multi(_) => {
+                return Err(ContractError::InvalidFunds {
+                    msg: "Nested multi funds are not supported".to_string(),
+                })
+            }
+        });
+    }
+
+    let mut msgs: Vec<SubMsg> = vec![];
+    let mut events: Vec<Event> = vec![];
+    let mut leftover_funds: Vec<Funds> = vec![];
+    // Recipients shared by several rates or assets are only resolved once
+    let mut resolved = ResolvedAddresses::default();
+    let asset_index = |denom: &str| assets.iter().position(|(asset, _)| asset.denom == denom);
+    for (idx, (coin, is_native)) in assets.iter().enumerate() {
+        let rates: Vec<&RateInfo> = config
+            .rates
+            .iter()
+            .filter(|rate_info| match &rate_info.rate {
+                // Flat rates are only charged once, in their own asset
+                Rate::Flat(rate) if is_multi => asset_index(&rate.denom) == Some(idx),
+                _ => true,
+            })
+            .collect();
+        let asset_resp = deduct_asset_funds(deps, &mut resolved, &rates, coin.clone(), *is_native)?;
+        msgs.extend(asset_resp.msgs);
+        if is_multi {
+            // Tag each event with its asset so taxes and royalties can be told apart per asset
+            events.extend(
+                asset_resp
+                    .events
+                    .into_iter()
+                    .map(|event| event.add_attribute("asset", coin.denom.clone())),
+            );
+        } else {
+            events.extend(asset_resp.events);
+        }
+        leftover_funds.push(asset_resp.leftover_funds);
+    }
+    if is_multi {
+        // Flat rates in a denom that was not transferred are charged once and not tagged with an asset,
+        // only additive rates can be charged as there is nothing to deduct them from
+        for rate_info in config.rates.iter() {
+            if let Rate::Flat(rate) = &rate_info.rate {
+                if asset_index(&rate.denom).is_none() {
+                    let rate_resp = deduct_asset_funds(
+                        deps,
+                        &mut resolved,
+                        &[rate_info],
+                        coin(0, rate.denom.clone()),
+                        true,
+                    )?;
+                    msgs.extend(rate_resp.msgs);
+                    events.extend(rate_resp.events);
+                }
+            }
+        }
+    }
+    Ok(OnFundsTransferResponse {
+        msgs,
+        leftover_funds: if is_multi {
+            Funds::Multi(leftover_funds)
+        } else {
+            leftover_funds.remove(0)
+        },
+        events,
+    })
+}
+
+/// Applies the given rates to a single asset
+fn deduct_asset_funds(
+    deps: Deps,
+    resolved: &mut ResolvedAddresses,
+    rates: &[&RateInfo],
+    coin: Coin,
+    is_native: bool,
+) -> Result<OnFundsTransferResponse, ContractError> {
     let mut msgs: Vec<SubMsg> = vec![];
     let mut events: Vec<Event> = vec![];
-    let (coin, is_native): (Coin, bool) = match funds {
-        Funds::Native(coin) => (coin, true),
-        Funds::Cw20(cw20_coin) => (coin(cw20_coin.amount.u128(), cw20_coin.address), false),
-    };
     let mut leftover_funds = vec![coin.clone()];
-    for rate_info in config.rates.iter() {
+    for rate_info in rates.iter() {
         let event_name = if rate_info.is_additive {
             "tax"
         } else {
@@ -194,10 +254,11 @@ pub fn query_deducted_funds(
                 .to_string(),
             );
             let msg = if is_native {
-                receiver.generate_direct_msg(&deps, vec![fee.clone()])?
+                receiver.generate_direct_msg_cached(&deps, resolved, vec![fee.clone()])?
             } else {
-                receiver.generate_msg_cw20(
+                receiver.generate_msg_cw20_cached(
                     &deps,
+                    resolved,
                     Cw20Coin {
                         amount: fee.amount,
                         address: fee.denom.to_string(),
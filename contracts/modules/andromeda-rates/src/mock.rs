@@ -19,5 +19,6 @@ pub fn mock_rates_instantiate_msg(
         rates,
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
@@ -43,6 +43,7 @@ fn test_instantiate_query() {
         rates: rates.clone(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -87,6 +88,7 @@ fn test_andr_receive() {
         rates: rates.clone(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let _res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
 
@@ -125,6 +127,7 @@ fn test_query_deducted_funds_native() {
         rates,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
     let res = query_deducted_funds(deps.as_ref(), Funds::Native(coin(100, "uusd"))).unwrap();
@@ -188,6 +191,7 @@ fn test_query_deducted_funds_cw20() {
         rates,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
 
@@ -241,3 +245,111 @@ fn test_query_deducted_funds_cw20() {
         res
     );
 }
+
+#[test]
+fn test_query_deducted_funds_multi() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_OWNER, &[]);
+    let rates = vec![
+        RateInfo {
+            rate: Rate::Flat(coin(20, "uusd")),
+            is_additive: true,
+            description: Some("desc2".to_string()),
+            recipients: vec![Recipient::from_string(MOCK_RECIPIENT1)],
+        },
+        RateInfo {
+            rate: Rate::from(Decimal::percent(10)),
+            is_additive: false,
+            description: Some("desc1".to_string()),
+            recipients: vec![Recipient::from_string(MOCK_RECIPIENT2)],
+        },
+    ];
+    let msg = InstantiateMsg {
+        rates,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        metadata: None,
+    };
+    let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    let res = query_deducted_funds(
+        deps.as_ref(),
+        Funds::from_coins(vec![coin(200, "uluna"), coin(100, "uusd")]),
+    )
+    .unwrap();
+
+    let expected_msgs: Vec<SubMsg> = vec![
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: MOCK_RECIPIENT2.into(),
+            amount: coins(20, "uluna"),
+        })),
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: MOCK_RECIPIENT1.into(),
+            amount: coins(20, "uusd"),
+        })),
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: MOCK_RECIPIENT2.into(),
+            amount: coins(10, "uusd"),
+        })),
+    ];
+
+    assert_eq!(
+        OnFundsTransferResponse {
+            msgs: expected_msgs,
+            // The flat rate is only charged once, in its own asset
+            leftover_funds: Funds::from_coins(vec![coin(180, "uluna"), coin(90, "uusd")]),
+            events: vec![
+                Event::new("royalty")
+                    .add_attribute("description", "desc1")
+                    .add_attribute("deducted", "20uluna")
+                    .add_attribute("payment", "recipient2<20uluna")
+                    .add_attribute("asset", "uluna"),
+                Event::new("tax")
+                    .add_attribute("description", "desc2")
+                    .add_attribute("payment", "recipient1<20uusd")
+                    .add_attribute("asset", "uusd"),
+                Event::new("royalty")
+                    .add_attribute("description", "desc1")
+                    .add_attribute("deducted", "10uusd")
+                    .add_attribute("payment", "recipient2<10uusd")
+                    .add_attribute("asset", "uusd"),
+            ]
+        },
+        res
+    );
+    // A flat rate in a denom that was not transferred is charged once, untagged
+    let res = query_deducted_funds(
+        deps.as_ref(),
+        Funds::from_coins(vec![coin(200, "uluna"), coin(100, "uatom")]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT2.into(),
+                amount: coins(20, "uluna"),
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT2.into(),
+                amount: coins(10, "uatom"),
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT1.into(),
+                amount: coins(20, "uusd"),
+            })),
+        ],
+        res.msgs
+    );
+    assert_eq!(
+        Funds::from_coins(vec![coin(180, "uluna"), coin(90, "uatom")]),
+        res.leftover_funds
+    );
+    assert_eq!(
+        &Event::new("tax")
+            .add_attribute("description", "desc2")
+            .add_attribute("payment", "recipient1<20uusd"),
+        res.events.last().unwrap()
+    );
+}
@@ -7,23 +7,25 @@ use andromeda_non_fungible_tokens::auction::{
 };
 
 use andromeda_std::{
+    ado_base::ownership::is_ownership_msg,
     ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
+    amp::AndrAddr,
+    andr_migrate,
     common::Funds,
     common::{encode_binary, expiration::expiration_from_milliseconds, OrderBy},
     common::{expiration::MILLISECONDS_TO_NANOSECONDS_RATIO, rates::get_tax_amount},
-    error::{from_semver, ContractError},
+    error::ContractError,
+    os::aos_querier::{AOSQuerier, ResolvedAddresses},
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 
 use cosmwasm_std::{
     attr, coins, ensure, entry_point, from_json, Addr, BankMsg, Binary, BlockInfo, Coin, CosmosMsg,
-    Deps, DepsMut, Env, MessageInfo, QuerierWrapper, QueryRequest, Response, Storage, SubMsg,
-    Uint128, WasmMsg, WasmQuery,
+    Deps, DepsMut, Env, MessageInfo, Response, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, Expiration, OwnerOfResponse};
 use cw_utils::nonpayable;
-use semver::Version;
 
 const CONTRACT_NAME: &str = "crates.io:andromeda_auction";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -49,6 +51,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
     let modules_resp =
@@ -72,24 +75,27 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
-pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
 
-    if !matches!(msg, ExecuteMsg::UpdateAppContract { .. })
-        && !matches!(msg, ExecuteMsg::UpdateOwner { .. })
-    {
-        contract.module_hook::<Response>(
-            &ctx.deps.as_ref(),
-            AndromedaHook::OnExecute {
-                sender: ctx.info.sender.to_string(),
-                payload: encode_binary(&msg)?,
-            },
-        )?;
-    }
+    let hook_events =
+        if !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg) {
+            let (_, events) = contract.module_hook::<Response>(
+                &ctx.deps.as_ref(),
+                &mut ctx.resolved,
+                AndromedaHook::OnExecute {
+                    sender: ctx.info.sender.to_string(),
+                    payload: encode_binary(&msg)?,
+                },
+            )?;
+            events
+        } else {
+            vec![]
+        };
     match msg {
         ExecuteMsg::ReceiveNft(msg) => handle_receive_cw721(ctx, msg),
         ExecuteMsg::UpdateAuction {
@@ -122,8 +128,12 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
             token_id,
             token_address,
         } => execute_claim(ctx, token_id, token_address),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
+    .map(|res| res.add_events(hook_events))
 }
 
 fn handle_receive_cw721(
@@ -440,7 +450,11 @@ fn execute_claim(
     token_address: String,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
     nonpayable(&info)?;
     let token_auction_state =
@@ -450,7 +464,8 @@ fn execute_claim(
         ContractError::AuctionNotEnded {}
     );
     let token_owner = query_owner_of(
-        deps.querier,
+        &deps.as_ref(),
+        &mut resolved,
         token_auction_state.token_address.clone(),
         token_id.clone(),
     )?
@@ -484,7 +499,12 @@ fn execute_claim(
     }
 
     // Calculate the funds to be received after tax
-    let after_tax_payment = purchase_token(deps.as_ref(), &info, token_auction_state.clone())?;
+    let after_tax_payment = purchase_token(
+        deps.as_ref(),
+        &mut resolved,
+        &info,
+        token_auction_state.clone(),
+    )?;
 
     Ok(Response::new()
         .add_submessages(after_tax_payment.1)
@@ -512,6 +532,7 @@ fn execute_claim(
 
 fn purchase_token(
     deps: Deps,
+    resolved: &mut ResolvedAddresses,
     info: &MessageInfo,
     state: TokenAuctionState,
 ) -> Result<(Coin, Vec<SubMsg>), ContractError> {
@@ -521,6 +542,7 @@ fn purchase_token(
 
     let (msgs, _events, remainder) = ADOContract::default().on_funds_transfer(
         &deps,
+        resolved,
         info.sender.to_string(),
         Funds::Native(total_cost),
         encode_binary(&"")?,
@@ -652,8 +674,13 @@ fn query_is_claimed(
     let token_auction_state =
         get_existing_token_auction_state(deps.storage, &token_id, &token_address)?;
 
-    let token_owner =
-        query_owner_of(deps.querier, token_auction_state.token_address, token_id)?.owner;
+    let token_owner = query_owner_of(
+        &deps,
+        &mut ResolvedAddresses::default(),
+        token_auction_state.token_address,
+        token_id,
+    )?
+    .owner;
 
     // if token owner isn't the contract, it means that it has been claimed. If they're equal it means that it hasn't been claimed and will return false
     Ok(token_owner != env.contract.address)
@@ -743,51 +770,23 @@ fn query_auction_state(
 }
 
 fn query_owner_of(
-    querier: QuerierWrapper,
+    deps: &Deps,
+    resolved: &mut ResolvedAddresses,
     token_addr: String,
     token_id: String,
 ) -> Result<OwnerOfResponse, ContractError> {
-    let res: OwnerOfResponse = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: token_addr,
-        msg: encode_binary(&Cw721QueryMsg::OwnerOf {
+    AOSQuerier::query_ado(
+        deps,
+        resolved,
+        &AndrAddr::from_string(token_addr),
+        &Cw721QueryMsg::OwnerOf {
             token_id,
             include_expired: None,
-        })?,
-    }))?;
-
-    Ok(res)
+        },
+    )
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
@@ -117,6 +117,7 @@ pub fn mock_auction_instantiate_msg(
         modules,
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
 
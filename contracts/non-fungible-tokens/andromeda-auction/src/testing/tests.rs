@@ -32,6 +32,7 @@ use cw_utils::Expiration;
 fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
     let msg = InstantiateMsg {
         owner: None,
+        metadata: None,
         modules,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
     };
@@ -1,6 +1,6 @@
 use crate::state::{
     get_available_tokens, Purchase, AVAILABLE_TOKENS, CONFIG, NUMBER_OF_TOKENS_AVAILABLE,
-    PURCHASES, SALE_CONDUCTED, STATE,
+    PURCHASES, SALE_ADMIN_ROLE, SALE_CONDUCTED, STATE,
 };
 use andromeda_non_fungible_tokens::{
     crowdfund::{
@@ -9,15 +9,18 @@ use andromeda_non_fungible_tokens::{
     cw721::{ExecuteMsg as Cw721ExecuteMsg, MintMsg, QueryMsg as Cw721QueryMsg},
 };
 use andromeda_std::amp::{messages::AMPPkt, recipient::Recipient};
-use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
+use andromeda_std::{
+    ado_base::ownership::is_ownership_msg, ado_contract::ADOContract, andr_migrate,
+    common::context::ExecuteContext,
+};
 
 use andromeda_std::{
     ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
     common::{deduct_funds, encode_binary, merge_sub_msgs, rates::get_tax_amount, Funds},
-    error::{from_semver, ContractError},
+    error::ContractError,
+    os::aos_querier::ResolvedAddresses,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
@@ -64,14 +67,16 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
     let mod_resp =
         ADOContract::default().register_modules(info.sender.as_str(), deps.storage, msg.modules)?;
-
-    Ok(inst_resp
+    let resp = inst_resp
         .add_attributes(mod_resp.attributes)
-        .add_submessages(mod_resp.messages))
+        .add_submessages(mod_resp.messages);
+
+    ADOContract::default().on_instantiate(&deps.as_ref(), info.sender.to_string(), resp)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -98,25 +103,33 @@ pub fn execute(
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
-pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
 
-    if !matches!(msg, ExecuteMsg::UpdateAppContract { .. })
-        && !matches!(msg, ExecuteMsg::UpdateOwner { .. })
-    {
-        contract.module_hook::<Response>(
+    let is_hooked = !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg);
+    let hook_events = if is_hooked {
+        let (_, events) = contract.module_hook::<Response>(
             &ctx.deps.as_ref(),
+            &mut ctx.resolved,
             AndromedaHook::OnExecute {
                 sender: ctx.info.sender.to_string(),
                 payload: encode_binary(&msg)?,
             },
         )?;
-    }
-    match msg {
+        events
+    } else {
+        vec![]
+    };
+
+    let action = msg.as_ref().to_string();
+    // The message is handled over a branch of the context so it remains available for the post execute hook
+    let mut parent_ctx = ctx;
+    let ctx = parent_ctx.branch();
+    let res = match msg {
         ExecuteMsg::Mint(mint_msgs) => execute_mint(ctx, mint_msgs),
         ExecuteMsg::StartSale {
             expiration,
@@ -136,7 +149,26 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
         ExecuteMsg::PurchaseByTokenId { token_id } => execute_purchase_by_token_id(ctx, token_id),
         ExecuteMsg::ClaimRefund {} => execute_claim_refund(ctx),
         ExecuteMsg::EndSale { limit } => execute_end_sale(ctx, limit),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
+    }?
+    .add_events(hook_events);
+
+    if is_hooked {
+        contract.merge_module_hook(
+            &parent_ctx.deps.as_ref(),
+            &mut parent_ctx.resolved,
+            AndromedaHook::PostExecute {
+                action,
+                sender: parent_ctx.info.sender.to_string(),
+                attributes: res.attributes.clone(),
+            },
+            res,
+        )
+    } else {
+        Ok(res)
     }
 }
 
@@ -145,7 +177,11 @@ fn execute_mint(
     mint_msgs: Vec<CrowdfundMintMsg>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = ctx;
     nonpayable(&info)?;
 
@@ -157,7 +193,7 @@ fn execute_mint(
     );
     let contract = ADOContract::default();
     ensure!(
-        contract.is_contract_owner(deps.storage, info.sender.as_str())?,
+        contract.is_owner_or_role(deps.storage, SALE_ADMIN_ROLE, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
     // Can only mint when no sale is ongoing.
@@ -174,19 +210,32 @@ fn execute_mint(
 
     let token_contract = config.token_address;
     let crowdfund_contract = env.contract.address.to_string();
-    let resolved_path = token_contract.get_raw_address(&deps.as_ref())?;
+    let resolved_path = resolved.resolve(&deps.as_ref(), &token_contract)?;
 
     let mut resp = Response::new();
     for mint_msg in mint_msgs {
+        let token_id = mint_msg.token_id.clone();
+        let recipient = mint_msg
+            .owner
+            .clone()
+            .unwrap_or_else(|| crowdfund_contract.clone());
         let mint_resp = mint(
             deps.storage,
             &crowdfund_contract,
             resolved_path.to_string(),
             mint_msg,
         )?;
-        resp = resp
-            .add_attributes(mint_resp.attributes)
-            .add_submessages(mint_resp.messages);
+        resp = contract.merge_module_hook(
+            &deps.as_ref(),
+            &mut resolved,
+            AndromedaHook::OnMint {
+                token_id: Some(token_id),
+                amount: None,
+                recipient,
+            },
+            resp.add_attributes(mint_resp.attributes)
+                .add_submessages(mint_resp.messages),
+        )?;
     }
 
     Ok(resp)
@@ -247,7 +296,7 @@ fn execute_start_sale(
     // Validate recipient
     ado_contract.validate_andr_addresses(&deps.as_ref(), vec![recipient.address.clone()])?;
     ensure!(
-        ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?,
+        ado_contract.is_owner_or_role(deps.storage, SALE_ADMIN_ROLE, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
     ensure!(
@@ -297,6 +346,7 @@ fn execute_purchase_by_token_id(
         mut deps,
         info,
         env,
+        mut resolved,
         ..
     } = ctx;
     let sender = info.sender.to_string();
@@ -327,6 +377,7 @@ fn execute_purchase_by_token_id(
 
     purchase_tokens(
         &mut deps,
+        &mut resolved,
         vec![token_id.clone()],
         &info,
         &mut state,
@@ -349,6 +400,7 @@ fn execute_purchase(
         mut deps,
         info,
         env,
+        mut resolved,
         ..
     } = ctx;
     let sender = info.sender.to_string();
@@ -380,8 +432,14 @@ fn execute_purchase(
 
     let number_of_tokens_purchased = token_ids.len();
 
-    let required_payment =
-        purchase_tokens(&mut deps, token_ids, &info, &mut state, &mut purchases)?;
+    let required_payment = purchase_tokens(
+        &mut deps,
+        &mut resolved,
+        token_ids,
+        &info,
+        &mut state,
+        &mut purchases,
+    )?;
 
     PURCHASES.save(deps.storage, &sender, &purchases)?;
     STATE.save(deps.storage, &state)?;
@@ -415,6 +473,7 @@ fn execute_purchase(
 
 fn purchase_tokens(
     deps: &mut DepsMut,
+    resolved: &mut ResolvedAddresses,
     token_ids: Vec<String>,
     info: &MessageInfo,
     state: &mut State,
@@ -440,6 +499,7 @@ fn purchase_tokens(
     // This is the same for each token, so we only need to do it once.
     let (msgs, _events, remainder) = ADOContract::default().on_funds_transfer(
         &deps.as_ref(),
+        resolved,
         info.sender.to_string(),
         Funds::Native(state.price.clone()),
         encode_binary(&"")?,
@@ -519,6 +579,7 @@ fn execute_end_sale(ctx: ExecuteContext, limit: Option<u32>) -> Result<Response,
         info,
         env,
         amp_ctx,
+        resolved,
     } = ctx;
     nonpayable(&info)?;
 
@@ -540,6 +601,7 @@ fn execute_end_sale(ctx: ExecuteContext, limit: Option<u32>) -> Result<Response,
                 info,
                 env,
                 amp_ctx,
+                resolved,
             },
             limit,
         )
@@ -845,35 +907,6 @@ fn query_is_token_available(deps: Deps, id: String) -> bool {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
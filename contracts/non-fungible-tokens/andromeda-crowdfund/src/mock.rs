@@ -137,6 +137,7 @@ pub fn mock_crowdfund_instantiate_msg(
         modules,
         kernel_address: kernel_address.into(),
         owner,
+        metadata: None,
     }
 }
 
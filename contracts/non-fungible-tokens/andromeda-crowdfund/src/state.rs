@@ -4,6 +4,9 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Order, Storage, SubMsg, Uint128};
 use cw_storage_plus::{Bound, Item, Map};
 
+/// Role granting permission to mint tokens and start sales alongside the owner.
+pub const SALE_ADMIN_ROLE: &str = "sale_admin";
+
 /// The config.
 pub const CONFIG: Item<Config> = Item::new("config");
 
@@ -1,8 +1,8 @@
 use crate::{
     contract::{execute, instantiate, query, MAX_MINT_LIMIT},
     state::{
-        Purchase, AVAILABLE_TOKENS, CONFIG, NUMBER_OF_TOKENS_AVAILABLE, PURCHASES, SALE_CONDUCTED,
-        STATE,
+        Purchase, AVAILABLE_TOKENS, CONFIG, NUMBER_OF_TOKENS_AVAILABLE, PURCHASES, SALE_ADMIN_ROLE,
+        SALE_CONDUCTED, STATE,
     },
     testing::mock_querier::{
         mock_dependencies_custom, MOCK_ADDRESS_LIST_CONTRACT, MOCK_APP_CONTRACT,
@@ -90,6 +90,7 @@ fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
     let msg = InstantiateMsg {
         token_address: AndrAddr::from_string(MOCK_TOKEN_CONTRACT.to_owned()),
         owner: None,
+        metadata: None,
         modules,
         can_mint_after_sale: true,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -103,11 +104,11 @@ fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
 fn test_instantiate() {
     let mut deps = mock_dependencies_custom(&[]);
 
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
 
     let res = init(deps.as_mut(), Some(modules));
 
@@ -198,6 +199,7 @@ fn test_mint_sale_conducted_cant_mint_after_sale() {
         token_address: AndrAddr::from_string(MOCK_TOKEN_CONTRACT.to_owned()),
         modules: None,
         owner: None,
+        metadata: None,
         can_mint_after_sale: false,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
     };
@@ -413,6 +415,36 @@ fn test_start_sale_unauthorized() {
     assert_eq!(ContractError::Unauthorized {}, res.unwrap_err());
 }
 
+#[test]
+fn test_start_sale_sale_admin() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut(), None);
+
+    let grant_msg = ExecuteMsg::GrantRole {
+        role: SALE_ADMIN_ROLE.to_string(),
+        actor: AndrAddr::from_string("sale_admin"),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        grant_msg,
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::StartSale {
+        expiration: Expiration::AtHeight(mock_env().block.height + 1),
+        price: coin(100, "uusd"),
+        min_tokens_sold: Uint128::from(1u128),
+        max_amount_per_wallet: None,
+        recipient: Recipient::from_string("recipient"),
+    };
+
+    let info = mock_info("sale_admin", &[]);
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert!(STATE.may_load(deps.as_ref().storage).unwrap().is_some());
+}
+
 #[test]
 fn test_start_sale_max_default() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -636,11 +668,11 @@ fn test_purchase_wrong_denom() {
 #[test]
 fn test_purchase_not_enough_for_price() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     mint(deps.as_mut(), MOCK_TOKENS_FOR_SALE[0]).unwrap();
@@ -679,11 +711,11 @@ fn test_purchase_not_enough_for_price() {
 #[test]
 fn test_purchase_not_enough_for_tax() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     mint(deps.as_mut(), MOCK_TOKENS_FOR_SALE[0]).unwrap();
@@ -734,11 +766,11 @@ fn test_purchase_not_enough_for_tax() {
 #[test]
 fn test_purchase_by_token_id_not_available() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     mint(deps.as_mut(), MOCK_TOKENS_FOR_SALE[0]).unwrap();
@@ -771,11 +803,11 @@ fn test_purchase_by_token_id_not_available() {
 #[test]
 fn test_purchase_by_token_id() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     mint(deps.as_mut(), MOCK_TOKENS_FOR_SALE[0]).unwrap();
@@ -832,11 +864,11 @@ fn test_purchase_by_token_id() {
 #[test]
 fn test_multiple_purchases() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     // Mint four tokens.
@@ -1013,11 +1045,11 @@ fn test_multiple_purchases() {
 #[test]
 fn test_purchase_more_than_allowed_per_wallet() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     // Mint four tokens.
@@ -1101,11 +1133,11 @@ fn mint(deps: DepsMut, token_id: impl Into<String>) -> Result<Response, Contract
 #[test]
 fn test_integration_conditions_not_met() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
 
     // Mint all tokens.
@@ -1277,11 +1309,11 @@ fn test_integration_conditions_not_met() {
 fn test_integration_conditions_met() {
     let mut deps = mock_dependencies_custom(&[]);
     deps.querier.contract_address = MOCK_CONDITIONS_MET_CONTRACT.to_string();
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     init(deps.as_mut(), Some(modules));
     let mut env = mock_env();
     env.contract.address = Addr::unchecked(MOCK_CONDITIONS_MET_CONTRACT);
@@ -1647,6 +1679,7 @@ fn test_validate_andr_addresses_regular_address() {
     let msg = InstantiateMsg {
         token_address: AndrAddr::from_string("terra1asdf1ssdfadf".to_owned()),
         owner: None,
+        metadata: None,
         modules: None,
         can_mint_after_sale: true,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -1672,16 +1705,17 @@ fn test_validate_andr_addresses_regular_address() {
 #[test]
 fn test_addresslist() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(ADDRESS_LIST.to_owned()),
-        address: AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        ADDRESS_LIST.to_owned(),
+        MOCK_ADDRESS_LIST_CONTRACT.to_owned(),
+        false,
+    )];
     let msg = InstantiateMsg {
         token_address: AndrAddr::from_string(MOCK_TOKEN_CONTRACT.to_owned()),
         modules: Some(modules),
         can_mint_after_sale: true,
         owner: None,
+        metadata: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
     };
 
@@ -6,28 +6,30 @@ use cosmwasm_std::{
 };
 
 use crate::state::{
-    is_archived, ANDR_MINTER, ARCHIVED, BATCH_MINT_ACTION, MINT_ACTION, TRANSFER_AGREEMENTS,
+    is_archived, ANDR_MINTER, ARCHIVED, BATCH_MINT_ACTION, MINTER_ROLE, MINT_ACTION,
+    TRANSFER_AGREEMENTS,
 };
 use andromeda_non_fungible_tokens::cw721::{
     ExecuteMsg, InstantiateMsg, MigrateMsg, MintMsg, QueryMsg, TokenExtension, TransferAgreement,
 };
 use andromeda_std::{
-    ado_base::{AndromedaMsg, AndromedaQuery},
+    ado_base::{ownership::is_ownership_msg, AndromedaMsg, AndromedaQuery},
     ado_contract::{
         permissioning::{is_context_permissioned, is_context_permissioned_strict},
+        roles::has_context_role,
         ADOContract,
     },
+    andr_migrate,
     common::context::ExecuteContext,
 };
-use cw2::{get_contract_version, set_contract_version};
-use semver::Version;
+use cw2::set_contract_version;
 
 use andromeda_std::{
     ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
     common::encode_binary,
     common::rates::get_tax_amount,
     common::Funds,
-    error::{from_semver, ContractError},
+    error::ContractError,
 };
 use cw721::{ContractInfoResponse, Cw721Execute};
 use cw721_base::{state::TokenInfo, Cw721Contract, ExecuteMsg as Cw721ExecuteMsg};
@@ -70,15 +72,17 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
     let modules_resp =
         contract.register_modules(info.sender.as_str(), deps.storage, msg.modules)?;
-
-    Ok(resp
+    let resp = resp
         .add_submessages(modules_resp.messages)
         .add_attributes(modules_resp.attributes)
-        .add_attributes(vec![attr("minter", msg.minter)]))
+        .add_attributes(vec![attr("minter", msg.minter)]);
+
+    contract.on_instantiate(&deps.as_ref(), info.sender.to_string(), resp)
 }
 
 #[cfg_attr(not(feature = "imported"), entry_point)]
@@ -96,15 +100,15 @@ pub fn execute(
         )
     } else {
         let ctx = ExecuteContext::new(deps, info, env);
-        handle_execute(ctx, msg)
+        ADOContract::default().execute_msg(ctx, msg, handle_execute)
     }
 }
 
-fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
     let contract = ADOContract::default();
     ensure!(
         is_context_permissioned(
-            ctx.deps.storage,
+            &mut ctx.deps,
             &ctx.info,
             &ctx.env,
             &ctx.amp_ctx,
@@ -113,19 +117,14 @@ fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Cont
         ContractError::Unauthorized {}
     );
 
-    let payee = if let Some(amp_ctx) = ctx.amp_ctx.clone() {
-        ctx.deps
-            .api
-            .addr_validate(amp_ctx.ctx.get_origin().as_str())?
-    } else {
-        ctx.info.sender.clone()
-    };
-
-    let fee_msg = ADOContract::default().pay_fee(
+    let (value, fee_funds) = fee_payment(ctx.deps.as_ref(), &ctx.info, &msg)?;
+    let fee_msg = ADOContract::default().pay_fee_with_funds(
         ctx.deps.storage,
         &ctx.deps.querier,
         msg.as_ref().to_string(),
-        payee,
+        ctx.fee_payee()?,
+        value,
+        fee_funds,
     )?;
 
     if let ExecuteMsg::Approve { token_id, .. } = &msg {
@@ -135,6 +134,11 @@ fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Cont
         );
     }
 
+    let is_hooked = !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg);
+    let action = msg.as_ref().to_string();
+    // The message is handled over a branch of the context so it remains available for the post execute hook
+    let mut parent_ctx = ctx;
+    let ctx = parent_ctx.branch();
     let res = match msg {
         ExecuteMsg::Mint {
             token_id,
@@ -158,6 +162,9 @@ fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Cont
             token_id,
             msg,
         } => execute_send_nft(ctx, token_id, contract, msg),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            contract.execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => {
             let serialized = to_json_binary(&msg)?;
 
@@ -167,9 +174,48 @@ fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Cont
             }
         }
     }?;
+    let res = if is_hooked {
+        contract.merge_module_hook(
+            &parent_ctx.deps.as_ref(),
+            &mut parent_ctx.resolved,
+            AndromedaHook::PostExecute {
+                action,
+                sender: parent_ctx.info.sender.to_string(),
+                attributes: res.attributes.clone(),
+            },
+            res,
+        )?
+    } else {
+        res
+    };
     Ok(res.add_submessage(fee_msg))
 }
 
+/// The value moved by the given message, used to charge value based action fees, and the attached funds
+/// available to pay the fee
+///
+/// Funds attached to a transfer under a transfer agreement pay for the agreement, any other attached funds
+/// are forwarded to pay the fee.
+fn fee_payment(
+    deps: Deps,
+    info: &MessageInfo,
+    msg: &ExecuteMsg,
+) -> Result<(Option<Funds>, Vec<Coin>), ContractError> {
+    let agreement = match msg {
+        ExecuteMsg::TransferNft { token_id, .. } => {
+            TRANSFER_AGREEMENTS.may_load(deps.storage, token_id)?
+        }
+        _ => None,
+    };
+    match agreement {
+        Some(agreement) => {
+            let amount = get_transfer_agreement_amount(deps.api, &deps.querier, &agreement)?;
+            Ok((Some(Funds::Native(amount)), vec![]))
+        }
+        None => Ok((None, info.funds.clone())),
+    }
+}
+
 fn execute_cw721(
     ctx: ExecuteContext,
     msg: Cw721ExecuteMsg<TokenExtension, ExecuteMsg>,
@@ -179,19 +225,20 @@ fn execute_cw721(
 }
 
 fn execute_mint(
-    ctx: ExecuteContext,
+    mut ctx: ExecuteContext,
     token_id: String,
     token_uri: Option<String>,
     owner: String,
     extension: TokenExtension,
 ) -> Result<Response, ContractError> {
-    let minter = ANDR_MINTER
-        .load(ctx.deps.storage)?
-        .get_raw_address(&ctx.deps.as_ref())?;
+    let minter = ctx
+        .resolved
+        .resolve(&ctx.deps.as_ref(), &ANDR_MINTER.load(ctx.deps.storage)?)?;
     ensure!(
         ctx.contains_sender(minter.as_str())
+            | has_context_role(ctx.deps.storage, &ctx.info, &ctx.amp_ctx, MINTER_ROLE)?
             | is_context_permissioned_strict(
-                ctx.deps.storage,
+                &mut ctx.deps,
                 &ctx.info,
                 &ctx.env,
                 &ctx.amp_ctx,
@@ -203,7 +250,7 @@ fn execute_mint(
 }
 
 fn mint(
-    ctx: ExecuteContext,
+    mut ctx: ExecuteContext,
     token_id: String,
     token_uri: Option<String>,
     owner: String,
@@ -226,11 +273,21 @@ fn mint(
 
     cw721_contract.increment_tokens(ctx.deps.storage)?;
 
-    Ok(Response::new()
+    let resp = Response::new()
         .add_attribute("action", "mint")
         .add_attribute("minter", ctx.info.sender)
-        .add_attribute("owner", owner)
-        .add_attribute("token_id", token_id))
+        .add_attribute("owner", owner.clone())
+        .add_attribute("token_id", token_id.clone());
+    ADOContract::default().merge_module_hook(
+        &ctx.deps.as_ref(),
+        &mut ctx.resolved,
+        AndromedaHook::OnMint {
+            token_id: Some(token_id),
+            amount: None,
+            recipient: owner,
+        },
+        resp,
+    )
 }
 
 fn execute_batch_mint(
@@ -238,13 +295,14 @@ fn execute_batch_mint(
     tokens_to_mint: Vec<MintMsg>,
 ) -> Result<Response, ContractError> {
     let mut resp = Response::default();
-    let minter = ANDR_MINTER
-        .load(ctx.deps.storage)?
-        .get_raw_address(&ctx.deps.as_ref())?;
+    let minter = ctx
+        .resolved
+        .resolve(&ctx.deps.as_ref(), &ANDR_MINTER.load(ctx.deps.storage)?)?;
     ensure!(
         ctx.contains_sender(minter.as_str())
+            | has_context_role(ctx.deps.storage, &ctx.info, &ctx.amp_ctx, MINTER_ROLE)?
             | is_context_permissioned_strict(
-                ctx.deps.storage,
+                &mut ctx.deps,
                 &ctx.info,
                 &ctx.env,
                 &ctx.amp_ctx,
@@ -259,13 +317,13 @@ fn execute_batch_mint(
         })
     );
     for msg in tokens_to_mint {
-        let ctx = ExecuteContext {
-            deps: ctx.deps.branch(),
-            info: ctx.info.clone(),
-            env: ctx.env.clone(),
-            amp_ctx: ctx.amp_ctx.clone(),
-        };
-        let mint_resp = mint(ctx, msg.token_id, msg.token_uri, msg.owner, msg.extension)?;
+        let mint_resp = mint(
+            ctx.branch(),
+            msg.token_id,
+            msg.token_uri,
+            msg.owner,
+            msg.extension,
+        )?;
         resp = resp
             .add_attributes(mint_resp.attributes)
             .add_submessages(mint_resp.messages);
@@ -280,11 +338,16 @@ fn execute_transfer(
     token_id: String,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
-        deps, info, env, ..
+        deps,
+        info,
+        env,
+        mut resolved,
+        ..
     } = env;
     let base_contract = ADOContract::default();
-    let responses = base_contract.module_hook::<Response>(
+    let (responses, events) = base_contract.module_hook::<Response>(
         &deps.as_ref(),
+        &mut resolved,
         AndromedaHook::OnTokenTransfer {
             token_id: token_id.clone(),
             sender: info.sender.to_string(),
@@ -299,7 +362,8 @@ fn execute_transfer(
                 .add_events(r.events)
                 .add_attributes(r.attributes)
         })
-        .unwrap_or_else(Response::new);
+        .unwrap_or_else(Response::new)
+        .add_events(events);
 
     let contract = AndrCW721Contract::default();
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
@@ -314,6 +378,7 @@ fn execute_transfer(
         let agreement_amount = get_transfer_agreement_amount(deps.api, &deps.querier, agreement)?;
         let (mut msgs, events, remainder) = base_contract.on_funds_transfer(
             &deps.as_ref(),
+            &mut resolved,
             info.sender.to_string(),
             Funds::Native(agreement_amount.clone()),
             encode_binary(&ExecuteMsg::TransferNft {
@@ -456,7 +521,12 @@ fn execute_archive(env: ExecuteContext, token_id: String) -> Result<Response, Co
 }
 
 fn execute_burn(env: ExecuteContext, token_id: String) -> Result<Response, ContractError> {
-    let ExecuteContext { deps, info, .. } = env;
+    let ExecuteContext {
+        deps,
+        info,
+        mut resolved,
+        ..
+    } = env;
     let contract = AndrCW721Contract::default();
     let token = contract.tokens.load(deps.storage, &token_id)?;
     ensure!(token.owner == info.sender, ContractError::Unauthorized {});
@@ -471,11 +541,21 @@ fn execute_burn(env: ExecuteContext, token_id: String) -> Result<Response, Contr
     let count = contract.token_count.load(deps.storage)?;
     contract.token_count.save(deps.storage, &(count - 1))?;
 
-    Ok(Response::default().add_attributes(vec![
+    let resp = Response::default().add_attributes(vec![
         attr("action", "burn"),
-        attr("token_id", token_id),
-        attr("sender", info.sender),
-    ]))
+        attr("token_id", token_id.clone()),
+        attr("sender", info.sender.clone()),
+    ]);
+    ADOContract::default().merge_module_hook(
+        &deps.as_ref(),
+        &mut resolved,
+        AndromedaHook::OnBurn {
+            token_id: Some(token_id),
+            amount: None,
+            sender: info.sender.to_string(),
+        },
+        resp,
+    )
 }
 
 fn execute_send_nft(
@@ -526,35 +606,6 @@ pub fn query_minter(deps: Deps) -> Result<String, ContractError> {
 }
 
 #[cfg_attr(not(feature = "imported"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
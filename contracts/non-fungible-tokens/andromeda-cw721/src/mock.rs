@@ -110,6 +110,7 @@ pub fn mock_cw721_instantiate_msg(
         modules,
         kernel_address,
         owner,
+        metadata: None,
     }
 }
 
@@ -9,6 +9,8 @@ pub const ARCHIVED: Map<&str, bool> = Map::new("archived_tokens");
 
 pub const MINT_ACTION: &str = "can_mint";
 pub const BATCH_MINT_ACTION: &str = "can_batch_mint";
+/// Role granting permission to mint alongside the configured minter
+pub const MINTER_ROLE: &str = "minter";
 
 pub fn is_archived(storage: &dyn Storage, token_id: &str) -> Result<bool, ContractError> {
     let archived_opt = ARCHIVED.may_load(storage, token_id)?.unwrap_or(false);
@@ -8,7 +8,10 @@ use andromeda_std::error::ContractError;
 use andromeda_std::{ado_base::modules::Module, testing::mock_querier::FAKE_VFS_PATH};
 use andromeda_std::{ado_contract::ADOContract, amp::addresses::AndrAddr};
 
-use crate::{contract::*, state::TRANSFER_AGREEMENTS};
+use crate::{
+    contract::*,
+    state::{MINTER_ROLE, TRANSFER_AGREEMENTS},
+};
 use andromeda_non_fungible_tokens::cw721::{
     ExecuteMsg, InstantiateMsg, MintMsg, QueryMsg, TokenExtension, TransferAgreement,
 };
@@ -32,6 +35,7 @@ fn init_setup(deps: DepsMut, env: Env, modules: Option<Vec<Module>>) {
         modules,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
 
     instantiate(deps, env, info, inst_msg).unwrap();
@@ -298,6 +302,7 @@ fn test_burn() {
             &deps.as_ref().querier,
             "Burn".to_string(),
             Addr::unchecked("creator".to_string()),
+            None,
         )
         .unwrap();
 
@@ -562,6 +567,7 @@ fn test_update_app_contract_invalid_minter() {
         modules: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: Some("owner".to_string()),
+        metadata: None,
     };
 
     instantiate(deps.as_mut(), mock_env(), info.clone(), inst_msg).unwrap();
@@ -590,6 +596,7 @@ fn test_batch_mint() {
         modules: None,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
+        metadata: None,
     };
     let owner = "owner";
     let mut mint_msgs: Vec<MintMsg> = Vec::new();
@@ -634,3 +641,69 @@ fn test_batch_mint() {
         i += 1;
     }
 }
+
+#[test]
+fn test_mint_with_minter_role() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone(), None);
+
+    let mint_msg = |token_id: &str| ExecuteMsg::Mint {
+        token_id: token_id.to_string(),
+        owner: "owner".to_string(),
+        token_uri: None,
+        extension: TokenExtension {
+            publisher: "publisher".to_string(),
+        },
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("role_minter", &[]),
+        mint_msg("1"),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let grant_msg = ExecuteMsg::GrantRole {
+        role: MINTER_ROLE.to_string(),
+        actor: AndrAddr::from_string("role_minter"),
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MINTER, &[]),
+        grant_msg,
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("role_minter", &[]),
+        mint_msg("1"),
+    )
+    .unwrap();
+
+    let revoke_msg = ExecuteMsg::RevokeRole {
+        role: MINTER_ROLE.to_string(),
+        actor: AndrAddr::from_string("role_minter"),
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MINTER, &[]),
+        revoke_msg,
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("role_minter", &[]),
+        mint_msg("2"),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
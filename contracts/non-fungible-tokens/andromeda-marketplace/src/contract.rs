@@ -13,20 +13,22 @@ use andromeda_std::common::expiration::{
     expiration_from_milliseconds, MILLISECONDS_TO_NANOSECONDS_RATIO,
 };
 use andromeda_std::{
+    ado_base::ownership::is_ownership_msg,
     ado_base::{hooks::AndromedaHook, InstantiateMsg as BaseInstantiateMsg},
+    amp::AndrAddr,
+    andr_migrate,
     common::{encode_binary, rates::get_tax_amount, Funds},
-    error::{from_semver, ContractError},
+    error::ContractError,
+    os::aos_querier::{AOSQuerier, ResolvedAddresses},
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
-use semver::Version;
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, ensure, from_json, has_coins, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, QuerierWrapper, QueryRequest, Response, Storage, SubMsg, Uint128, WasmMsg,
-    WasmQuery,
+    MessageInfo, Response, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw_utils::{nonpayable, Expiration};
@@ -54,6 +56,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: msg.metadata,
         },
     )?;
     let mod_resp =
@@ -71,31 +74,33 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    let contract = ADOContract::default();
-
-    if !matches!(msg, ExecuteMsg::UpdateAppContract { .. })
-        && !matches!(msg, ExecuteMsg::UpdateOwner { .. })
-    {
-        contract.module_hook::<Response>(
-            &deps.as_ref(),
-            AndromedaHook::OnExecute {
-                sender: info.sender.to_string(),
-                payload: encode_binary(&msg)?,
-            },
-        )?;
-    }
-
     let ctx = ExecuteContext::new(deps, info, env);
 
     match msg {
         ExecuteMsg::AMPReceive(pkt) => {
             ADOContract::default().execute_amp_receive(ctx, pkt, handle_execute)
         }
-        _ => handle_execute(ctx, msg),
+        _ => ADOContract::default().execute_msg(ctx, msg, handle_execute),
     }
 }
 
-pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+pub fn handle_execute(mut ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+
+    let hook_events =
+        if !matches!(msg, ExecuteMsg::UpdateAppContract { .. }) && !is_ownership_msg(&msg) {
+            let (_, events) = contract.module_hook::<Response>(
+                &ctx.deps.as_ref(),
+                &mut ctx.resolved,
+                AndromedaHook::OnExecute {
+                    sender: ctx.info.sender.to_string(),
+                    payload: encode_binary(&msg)?,
+                },
+            )?;
+            events
+        } else {
+            vec![]
+        };
     match msg {
         ExecuteMsg::ReceiveNft(msg) => handle_receive_cw721(ctx, msg),
         ExecuteMsg::UpdateSale {
@@ -112,8 +117,12 @@ pub fn handle_execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response,
             token_id,
             token_address,
         } => execute_cancel(ctx, token_id, token_address),
+        ExecuteMsg::Batch { msgs, atomic } => {
+            ADOContract::default().execute_batch(ctx, msgs, atomic, handle_execute)
+        }
         _ => ADOContract::default().execute(ctx, msg),
     }
+    .map(|res| res.add_events(hook_events))
 }
 
 fn handle_receive_cw721(
@@ -265,6 +274,7 @@ fn execute_buy(
         mut deps,
         info,
         env,
+        mut resolved,
         ..
     } = ctx;
 
@@ -307,7 +317,8 @@ fn execute_buy(
     );
 
     let token_owner = query_owner_of(
-        deps.querier,
+        &deps.as_ref(),
+        &mut resolved,
         token_sale_state.token_address.clone(),
         token_id.clone(),
     )?
@@ -340,7 +351,8 @@ fn execute_buy(
     TOKEN_SALE_STATE.save(deps.storage, key, &token_sale_state)?;
 
     // Calculate the funds to be received after tax
-    let after_tax_payment = purchase_token(&mut deps, &info, token_sale_state.clone())?;
+    let after_tax_payment =
+        purchase_token(&mut deps, &mut resolved, &info, token_sale_state.clone())?;
 
     Ok(Response::new()
         .add_submessages(after_tax_payment.1)
@@ -415,6 +427,7 @@ fn execute_cancel(
 
 fn purchase_token(
     deps: &mut DepsMut,
+    resolved: &mut ResolvedAddresses,
     info: &MessageInfo,
     state: TokenSaleState,
 ) -> Result<(Coin, Vec<SubMsg>), ContractError> {
@@ -424,6 +437,7 @@ fn purchase_token(
 
     let (msgs, _events, remainder) = ADOContract::default().on_funds_transfer(
         &deps.as_ref(),
+        resolved,
         info.sender.to_string(),
         Funds::Native(total_cost),
         encode_binary(&"")?,
@@ -557,53 +571,25 @@ fn query_sale_state(deps: Deps, sale_id: Uint128) -> Result<SaleStateResponse, C
 }
 
 fn query_owner_of(
-    querier: QuerierWrapper,
+    deps: &Deps,
+    resolved: &mut ResolvedAddresses,
     token_addr: String,
     token_id: String,
 ) -> Result<OwnerOfResponse, ContractError> {
-    let res: OwnerOfResponse = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: token_addr,
-        msg: encode_binary(&Cw721QueryMsg::OwnerOf {
+    AOSQuerier::query_ado(
+        deps,
+        resolved,
+        &AndrAddr::from_string(token_addr),
+        &Cw721QueryMsg::OwnerOf {
             token_id,
             include_expired: None,
-        })?,
-    }))?;
-
-    Ok(res)
+        },
+    )
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
-        }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
-        }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
-
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 // #[cfg(test)]
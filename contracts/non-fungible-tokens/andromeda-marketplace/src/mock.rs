@@ -61,6 +61,7 @@ pub fn mock_marketplace_instantiate_msg(
         modules,
         kernel_address,
         owner,
+        metadata: None,
     }
 }
 
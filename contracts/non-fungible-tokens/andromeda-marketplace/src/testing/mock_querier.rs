@@ -1,4 +1,5 @@
 use andromeda_app::app::QueryMsg as AppQueryMsg;
+use andromeda_non_fungible_tokens::marketplace::ExecuteMsg;
 use andromeda_std::testing::mock_querier::MockAndromedaQuerier;
 pub use andromeda_std::testing::mock_querier::{MOCK_APP_CONTRACT, MOCK_KERNEL_CONTRACT};
 use andromeda_std::{
@@ -53,6 +54,7 @@ pub fn mock_dependencies_custom(
                 operators: None,
                 kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 owner: None,
+                metadata: None,
             },
         )
         .unwrap();
@@ -163,7 +165,7 @@ impl WasmMockQuerier {
                                 })),
                             ],
                         ),
-                        Funds::Cw20(_) => {
+                        Funds::Cw20(_) | Funds::Multi(_) => {
                             return SystemResult::Ok(ContractResult::Ok(
                                 to_json_binary(&None::<Response>).unwrap(),
                             ))
@@ -186,10 +188,13 @@ impl WasmMockQuerier {
     fn handle_addresslist_query(&self, msg: &Binary) -> QuerierResult {
         match from_json(msg).unwrap() {
             HookMsg::AndrHook(hook_msg) => match hook_msg {
-                AndromedaHook::OnExecute { sender, payload: _ } => {
+                AndromedaHook::OnExecute { sender, payload } => {
+                    // Only whitelisted addresses can cancel sales
                     let whitelisted_addresses = ["sender"];
+                    let is_cancel =
+                        matches!(from_json(&payload), Ok(ExecuteMsg::CancelSale { .. }));
                     let response: Response = Response::default();
-                    if whitelisted_addresses.contains(&sender.as_str()) {
+                    if !is_cancel || whitelisted_addresses.contains(&sender.as_str()) {
                         SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
                     } else {
                         SystemResult::Ok(ContractResult::Err("InvalidAddress".to_string()))
@@ -3,7 +3,6 @@ use andromeda_non_fungible_tokens::marketplace::{
 };
 use andromeda_std::{
     ado_base::modules::Module,
-    amp::addresses::AndrAddr,
     common::{
         encode_binary,
         expiration::{expiration_from_milliseconds, MILLISECONDS_TO_NANOSECONDS_RATIO},
@@ -13,7 +12,7 @@ use andromeda_std::{
 use cosmwasm_std::{
     coin, coins,
     testing::{mock_env, mock_info},
-    BankMsg, CosmosMsg, Deps, DepsMut, Env, Response, SubMsg, Uint128, WasmMsg,
+    BankMsg, CosmosMsg, Deps, DepsMut, Env, Response, StdError, SubMsg, Uint128, WasmMsg,
 };
 use cw721::{Cw721ExecuteMsg, Cw721ReceiveMsg};
 use cw_utils::Expiration;
@@ -23,8 +22,8 @@ use crate::{
     contract::{execute, instantiate},
     state::{sale_infos, SaleInfo, TokenSaleState, TOKEN_SALE_STATE},
     testing::mock_querier::{
-        mock_dependencies_custom, MOCK_RATES_CONTRACT, MOCK_TOKEN_ADDR, MOCK_TOKEN_OWNER,
-        MOCK_UNCLAIMED_TOKEN, RATES,
+        mock_dependencies_custom, MOCK_ADDRESSLIST_CONTRACT, MOCK_RATES_CONTRACT, MOCK_TOKEN_ADDR,
+        MOCK_TOKEN_OWNER, MOCK_UNCLAIMED_TOKEN, RATES,
     },
 };
 
@@ -90,6 +89,7 @@ fn start_sale_future_start_with_duration(deps: DepsMut, env: Env) {
 fn init(deps: DepsMut, modules: Option<Vec<Module>>) -> Response {
     let msg = InstantiateMsg {
         owner: None,
+        metadata: None,
         modules,
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
     };
@@ -444,14 +444,44 @@ fn test_execute_start_sale_invalid_price() {
     assert_eq!(err, ContractError::InvalidZeroAmount {})
 }
 
+#[test]
+fn test_execute_batched_cancel_runs_module_hooks() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let modules = vec![Module::new(
+        "address_list",
+        MOCK_ADDRESSLIST_CONTRACT.to_owned(),
+        false,
+    )];
+    let _res = init(deps.as_mut(), Some(modules));
+
+    start_sale(deps.as_mut());
+
+    // Messages within a batch are checked by the address list individually
+    let msg = ExecuteMsg::Batch {
+        msgs: vec![ExecuteMsg::CancelSale {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        }],
+        atomic: true,
+    };
+    let info = mock_info(MOCK_TOKEN_OWNER, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "Querier contract error: InvalidAddress"
+        ))
+    );
+}
+
 #[test]
 fn test_execute_buy_with_tax_and_royalty_insufficient_funds() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     let _res = init(deps.as_mut(), Some(modules));
 
     start_sale(deps.as_mut());
@@ -470,11 +500,11 @@ fn test_execute_buy_with_tax_and_royalty_insufficient_funds() {
 #[test]
 fn test_execute_buy_with_tax_and_royalty_works() {
     let mut deps = mock_dependencies_custom(&[]);
-    let modules = vec![Module {
-        name: Some(RATES.to_owned()),
-        address: AndrAddr::from_string(MOCK_RATES_CONTRACT.to_owned()),
-        is_mutable: false,
-    }];
+    let modules = vec![Module::new(
+        RATES.to_owned(),
+        MOCK_RATES_CONTRACT.to_owned(),
+        false,
+    )];
     let _res = init(deps.as_mut(), Some(modules));
 
     start_sale(deps.as_mut());
@@ -1,21 +1,26 @@
 use crate::state::{
-    read_code_id, read_latest_code_id, store_code_id, ACTION_FEES, ADO_TYPE, CODE_ID,
-    LATEST_VERSION, PUBLISHER,
+    backfill_publisher_ados, read_all_versions, read_code_id, read_latest_code_id,
+    read_version_status, resolve_version, save_publisher, store_code_id, ACTION_FEES, ADO_TYPE,
+    CODE_ID, CODE_INFO, LATEST_VERSION, MIGRATION_PATHS, PUBLISHER, PUBLISHER_ADOS,
+    PUBLISHER_PROFILES, RELEASE_METADATA, VERSION_STATUS,
 };
 use andromeda_std::ado_base::InstantiateMsg as BaseInstantiateMsg;
 use andromeda_std::ado_contract::ADOContract;
+use andromeda_std::andr_migrate;
 use andromeda_std::common::encode_binary;
 use andromeda_std::error::{from_semver, ContractError};
 use andromeda_std::os::adodb::{
-    ADOMetadata, ADOVersion, ActionFee, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+    ADOMetadata, ADOVersion, ActionFee, CatalogueEntry, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    MigrationPath, PublishedCodeInfo, PublisherProfile, QueryMsg, ReleaseMetadata, ResolvedVersion,
+    VersionInfo, VersionStatus, VersionStatusInfo,
 };
 use cosmwasm_std::{
-    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
-    StdError, StdResult, Storage,
+    attr, ensure, entry_point, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Order, Reply,
+    Response, StdError, StdResult, Storage,
 };
-use cw2::{get_contract_version, set_contract_version};
+use cw2::set_contract_version;
 use cw_storage_plus::Bound;
-use semver::Version;
+use semver::{Version, VersionReq};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-adodb";
@@ -40,6 +45,7 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: None,
         },
     )
 }
@@ -69,6 +75,8 @@ pub fn execute(
             action_fees,
             version,
             publisher,
+            checksum,
+            release,
         } => publish(
             deps,
             env,
@@ -78,6 +86,8 @@ pub fn execute(
             version,
             action_fees,
             publisher,
+            checksum,
+            release,
         ),
         ExecuteMsg::UpdateActionFees {
             action_fees,
@@ -92,6 +102,31 @@ pub fn execute(
             ado_type,
             publisher,
         } => execute_update_publisher(deps, info, &ADOVersion::from_string(ado_type), publisher),
+        ExecuteMsg::Deprecate {
+            ado_version,
+            reason,
+        } => execute_deprecate(deps, info, &ADOVersion::from_string(ado_version), reason),
+        ExecuteMsg::Yank { ado_version } => {
+            execute_yank(deps, info, &ADOVersion::from_string(ado_version))
+        }
+        ExecuteMsg::AddMigrationPath {
+            ado_type,
+            from,
+            to,
+            migrate_msg,
+        } => execute_add_migration_path(deps, info, ado_type, from, to, migrate_msg),
+        ExecuteMsg::RemoveMigrationPath { ado_type, from, to } => {
+            execute_remove_migration_path(deps, info, ado_type, from, to)
+        }
+        ExecuteMsg::RegisterPublisher {
+            name,
+            url,
+            payout_address,
+        } => execute_register_publisher(deps, info, name, url, payout_address),
+        ExecuteMsg::TransferPublisher {
+            ado_type,
+            new_publisher,
+        } => execute_transfer_publisher(deps, info, ado_type, new_publisher),
     }
 }
 
@@ -101,6 +136,7 @@ pub fn update_action_fees(
     fees: Vec<ActionFee>,
 ) -> Result<(), ContractError> {
     for action_fee in fees {
+        action_fee.validate()?;
         ACTION_FEES.save(
             storage,
             &(ado_version.clone().into_string(), action_fee.clone().action),
@@ -121,11 +157,19 @@ pub fn publish(
     version: String,
     action_fees: Option<Vec<ActionFee>>,
     publisher: Option<String>,
+    checksum: Option<HexBinary>,
+    release: Option<ReleaseMetadata>,
 ) -> Result<Response, ContractError> {
     ensure!(
         ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
+    ensure!(
+        Version::parse(&version).is_ok(),
+        ContractError::InvalidADOVersion {
+            msg: Some("Published versions must be exact semver versions".to_string())
+        }
+    );
     let current_ado_version = LATEST_VERSION.may_load(deps.storage, &ado_type)?;
     if let Some(ado_version) = current_ado_version {
         let new_version = semver::Version::parse(&version).unwrap();
@@ -138,7 +182,16 @@ pub fn publish(
         );
     }
 
-    //TODO: Get Code ID info with cosmwasm 1.2
+    let code_info = deps.querier.query_wasm_code_info(code_id)?;
+    if let Some(expected) = checksum {
+        ensure!(
+            expected == code_info.checksum,
+            ContractError::ChecksumMismatch {
+                expected: expected.to_hex(),
+                actual: code_info.checksum.to_hex(),
+            }
+        );
+    }
 
     let version = ADOVersion::from_type(ado_type).with_version(version);
     ensure!(
@@ -156,12 +209,24 @@ pub fn publish(
     );
 
     store_code_id(deps.storage, &version, code_id)?;
-    PUBLISHER.save(
+    save_publisher(
         deps.storage,
-        version.as_str(),
+        &version,
         &publisher.clone().unwrap_or(info.sender.to_string()),
     )?;
 
+    CODE_INFO.save(
+        deps.storage,
+        version.as_str(),
+        &PublishedCodeInfo {
+            checksum: code_info.checksum.clone(),
+            creator: code_info.creator,
+        },
+    )?;
+    if let Some(release) = release {
+        RELEASE_METADATA.save(deps.storage, version.as_str(), &release)?;
+    }
+
     if let Some(fees) = action_fees {
         update_action_fees(deps.storage, &version, fees)?;
     }
@@ -170,6 +235,7 @@ pub fn publish(
         attr("action", "publish_ado"),
         attr("ado_type", version.into_string()),
         attr("code_id", code_id.to_string()),
+        attr("checksum", code_info.checksum.to_hex()),
         attr("publisher", publisher.unwrap_or(info.sender.to_string())),
     ]))
 }
@@ -253,7 +319,7 @@ fn execute_update_publisher(
         }
     );
 
-    PUBLISHER.save(deps.storage, ado_version.as_str(), &publisher)?;
+    save_publisher(deps.storage, ado_version, &publisher)?;
 
     Ok(Response::default().add_attributes(vec![
         attr("action", "update_publisher"),
@@ -262,38 +328,218 @@ fn execute_update_publisher(
     ]))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
+/// Ensures the given exact version is published and the sender is either its publisher or the contract owner
+fn ensure_can_update_version(
+    storage: &dyn Storage,
+    sender: &str,
+    ado_version: &ADOVersion,
+) -> Result<(), ContractError> {
+    ensure!(
+        CODE_ID.has(storage, ado_version.as_str()),
+        ContractError::InvalidADOVersion {
+            msg: Some("ADO version does not exist".to_string())
+        }
+    );
+    let publisher = PUBLISHER.may_load(storage, ado_version.as_str())?;
+    ensure!(
+        publisher.as_deref() == Some(sender)
+            || ADOContract::default().is_contract_owner(storage, sender)?,
+        ContractError::Unauthorized {}
+    );
+    Ok(())
+}
+
+fn execute_deprecate(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_version: &ADOVersion,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_can_update_version(deps.storage, info.sender.as_str(), ado_version)?;
+
+    let current_status = read_version_status(deps.storage, ado_version)?;
+    ensure!(
+        !current_status.is_yanked(),
+        ContractError::YankedADOVersion {
+            ado_version: ado_version.clone().into_string()
+        }
+    );
+
+    VERSION_STATUS.save(
+        deps.storage,
+        ado_version.as_str(),
+        &VersionStatusInfo {
+            status: VersionStatus::Deprecated,
+            reason: reason.clone(),
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "deprecate"),
+        attr("ado_version", ado_version.clone().into_string()),
+        attr("reason", reason.unwrap_or_default()),
+    ]))
+}
+
+fn execute_yank(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_version: &ADOVersion,
+) -> Result<Response, ContractError> {
+    ensure_can_update_version(deps.storage, info.sender.as_str(), ado_version)?;
 
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+    // Keep any reason given when the version was deprecated
+    let current_status = read_version_status(deps.storage, ado_version)?;
+    VERSION_STATUS.save(
+        deps.storage,
+        ado_version.as_str(),
+        &VersionStatusInfo {
+            status: VersionStatus::Yanked,
+            reason: current_status.reason,
+        },
+    )?;
 
-    let contract = ADOContract::default();
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "yank"),
+        attr("ado_version", ado_version.clone().into_string()),
+    ]))
+}
 
+fn execute_add_migration_path(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    from: String,
+    to: String,
+    migrate_msg: Option<Binary>,
+) -> Result<Response, ContractError> {
+    let to_version = ADOVersion::from_type(&ado_type).with_version(&to);
+    ensure_can_update_version(deps.storage, info.sender.as_str(), &to_version)?;
     ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
+        VersionReq::parse(&from).is_ok(),
+        ContractError::InvalidADOVersion {
+            msg: Some(format!("Invalid migration range: {from}"))
         }
     );
 
-    // New version has to be newer/greater than the old version
+    MIGRATION_PATHS.save(
+        deps.storage,
+        (ado_type.as_str(), to.as_str(), from.as_str()),
+        &MigrationPath {
+            from: from.clone(),
+            to: to.clone(),
+            migrate_msg,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "add_migration_path"),
+        attr("ado_type", ado_type),
+        attr("from", from),
+        attr("to", to),
+    ]))
+}
+
+fn execute_remove_migration_path(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    from: Option<String>,
+    to: String,
+) -> Result<Response, ContractError> {
+    let to_version = ADOVersion::from_type(&ado_type).with_version(&to);
+    ensure_can_update_version(deps.storage, info.sender.as_str(), &to_version)?;
+    let ranges = match &from {
+        Some(from) => vec![from.clone()],
+        None => MIGRATION_PATHS
+            .prefix((ado_type.as_str(), to.as_str()))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?,
+    };
+    for range in ranges.iter() {
+        MIGRATION_PATHS.remove(
+            deps.storage,
+            (ado_type.as_str(), to.as_str(), range.as_str()),
+        );
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "remove_migration_path"),
+        attr("ado_type", ado_type),
+        attr("from", from.unwrap_or_else(|| "all".to_string())),
+        attr("to", to),
+    ]))
+}
+
+fn execute_register_publisher(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    url: Option<String>,
+    payout_address: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !name.trim().is_empty(),
+        ContractError::EmptyPublisherName {}
+    );
+    let payout_address = payout_address
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    PUBLISHER_PROFILES.save(
+        deps.storage,
+        info.sender.as_str(),
+        &PublisherProfile {
+            name: name.clone(),
+            url,
+            payout_address,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "register_publisher"),
+        attr("publisher", info.sender),
+        attr("name", name),
+    ]))
+}
+
+/// Hands every version of an ADO type over to a new publisher.
+/// The sender must be the publisher of the latest version or the contract owner.
+fn execute_transfer_publisher(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    new_publisher: String,
+) -> Result<Response, ContractError> {
+    let (latest_version, _) = read_latest_code_id(deps.storage, ado_type.clone())?;
+    ensure_can_update_version(
+        deps.storage,
+        info.sender.as_str(),
+        &ADOVersion::from_type(&ado_type).with_version(latest_version),
+    )?;
     ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
+        PUBLISHER_PROFILES.has(deps.storage, &new_publisher),
+        ContractError::PublisherNotRegistered {
+            publisher: new_publisher
         }
     );
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    for (ado_version, _) in read_all_versions(deps.storage, &ado_type)? {
+        save_publisher(deps.storage, &ado_version, &new_publisher)?;
+    }
 
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "transfer_publisher"),
+        attr("ado_type", ado_type),
+        attr("publisher", new_publisher),
+    ]))
+}
 
-    Ok(Response::default())
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION, {
+        "0.2.2" => backfill_publisher_ados,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -321,6 +567,42 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
         QueryMsg::ActionFeeByCodeId { code_id, action } => {
             encode_binary(&query_action_fee_by_code_id(deps, code_id, action)?)
         }
+        QueryMsg::VersionStatus { ado_version } => {
+            encode_binary(&query_version_status(deps, ado_version)?)
+        }
+        QueryMsg::ResolveVersion { ado_version } => {
+            encode_binary(&query_resolve_version(deps, ado_version)?)
+        }
+        QueryMsg::VersionInfo { ado_version } => {
+            encode_binary(&query_version_info(deps, ado_version)?)
+        }
+        QueryMsg::Publisher { address } => encode_binary(&query_publisher(deps, address)?),
+        QueryMsg::PublisherADOs {
+            publisher,
+            start_after,
+            limit,
+        } => encode_binary(&query_publisher_ados(
+            deps.storage,
+            &publisher,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Catalogue { start_after, limit } => {
+            encode_binary(&query_catalogue(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::MigrationPaths { ado_type } => {
+            encode_binary(&query_migration_paths(deps, ado_type)?)
+        }
+        QueryMsg::MigrationPath {
+            ado_type,
+            from_version,
+            to_version,
+        } => encode_binary(&query_migration_path(
+            deps,
+            ado_type,
+            from_version,
+            to_version,
+        )?),
     }
 }
 
@@ -407,3 +689,124 @@ fn query_action_fee_by_code_id(
     let ado_version = ADO_TYPE.load(deps.storage, code_id)?;
     Ok(ACTION_FEES.may_load(deps.storage, &(ado_version, action))?)
 }
+
+fn query_version_status(
+    deps: Deps,
+    ado_version: String,
+) -> Result<VersionStatusInfo, ContractError> {
+    let ado_version = ADOVersion::from_string(ado_version);
+    Ok(read_version_status(deps.storage, &ado_version)?)
+}
+
+fn query_resolve_version(
+    deps: Deps,
+    ado_version: String,
+) -> Result<ResolvedVersion, ContractError> {
+    let (ado_version, code_id) =
+        resolve_version(deps.storage, &ADOVersion::from_string(ado_version))?;
+    Ok(ResolvedVersion {
+        ado_version: ado_version.into_string(),
+        code_id,
+    })
+}
+
+fn query_version_info(deps: Deps, ado_version: String) -> Result<VersionInfo, ContractError> {
+    let (ado_version, code_id) =
+        resolve_version(deps.storage, &ADOVersion::from_string(ado_version))?;
+    Ok(VersionInfo {
+        ado_version: ado_version.clone().into_string(),
+        code_id,
+        publisher: PUBLISHER.load(deps.storage, ado_version.as_str())?,
+        status: read_version_status(deps.storage, &ado_version)?,
+        code_info: CODE_INFO.may_load(deps.storage, ado_version.as_str())?,
+        release: RELEASE_METADATA.may_load(deps.storage, ado_version.as_str())?,
+    })
+}
+
+fn query_migration_paths(
+    deps: Deps,
+    ado_type: String,
+) -> Result<Vec<MigrationPath>, ContractError> {
+    let paths: StdResult<Vec<MigrationPath>> = MIGRATION_PATHS
+        .sub_prefix(ado_type.as_str())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, path)| path))
+        .collect();
+    Ok(paths?)
+}
+
+fn query_migration_path(
+    deps: Deps,
+    ado_type: String,
+    from_version: String,
+    to_version: String,
+) -> Result<Option<MigrationPath>, ContractError> {
+    let from_version = Version::parse(&from_version).map_err(from_semver)?;
+    for path in MIGRATION_PATHS
+        .prefix((ado_type.as_str(), to_version.as_str()))
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, path) = path?;
+        let matches = VersionReq::parse(&path.from)
+            .map(|req| req.matches(&from_version))
+            .unwrap_or(false);
+        if matches {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+fn query_publisher(deps: Deps, address: String) -> Result<Option<PublisherProfile>, ContractError> {
+    Ok(PUBLISHER_PROFILES.may_load(deps.storage, &address)?)
+}
+
+pub fn query_publisher_ados(
+    storage: &dyn Storage,
+    publisher: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<String>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let ado_types: StdResult<Vec<String>> = PUBLISHER_ADOS
+        .prefix(publisher)
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect();
+    Ok(ado_types?)
+}
+
+pub fn query_catalogue(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<CatalogueEntry>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    LATEST_VERSION
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (ado_type, (latest_version, code_id)) = item?;
+            let ado_version = ADOVersion::from_type(&ado_type).with_version(&latest_version);
+            let action_fees: StdResult<Vec<ActionFee>> = ACTION_FEES
+                .prefix(ado_version.clone().into_string())
+                .range(storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, fee)| fee))
+                .collect();
+            Ok(CatalogueEntry {
+                publisher: PUBLISHER
+                    .may_load(storage, ado_version.as_str())?
+                    .unwrap_or_default(),
+                action_fees: action_fees?,
+                status: read_version_status(storage, &ado_version)?,
+                ado_type,
+                latest_version,
+                code_id,
+            })
+        })
+        .collect()
+}
@@ -1,12 +1,13 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, migrate, query};
 use andromeda_std::os::adodb::{ActionFee, ExecuteMsg, InstantiateMsg, QueryMsg};
 use cosmwasm_std::Empty;
 use cw_multi_test::{Contract, ContractWrapper};
 
 pub fn mock_andromeda_adodb() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new_with_empty(execute, instantiate, query);
+    let contract =
+        ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate);
     Box::new(contract)
 }
 
@@ -33,6 +34,8 @@ pub fn mock_publish(
         version: version.into(),
         publisher,
         action_fees,
+        checksum: None,
+        release: None,
     }
 }
 
@@ -1,9 +1,13 @@
 use andromeda_std::{
     error::ContractError,
-    os::adodb::{ADOVersion, ActionFee},
+    os::adodb::{
+        ADOVersion, ActionFee, MigrationPath, PublishedCodeInfo, PublisherProfile, ReleaseMetadata,
+        VersionStatusInfo,
+    },
 };
-use cosmwasm_std::{ensure, StdResult, Storage};
-use cw_storage_plus::Map;
+use cosmwasm_std::{ensure, DepsMut, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use semver::Version;
 
 /// Stores a mapping from an ADO type/version to its code ID
 pub const CODE_ID: Map<&str, u64> = Map::new("code_id");
@@ -15,6 +19,19 @@ pub const ADO_TYPE: Map<u64, String> = Map::new("ado_type");
 pub const PUBLISHER: Map<&str, String> = Map::new("publisher");
 /// Stores a mapping from an (ADO,Action) to its action fees
 pub const ACTION_FEES: Map<&(String, String), ActionFee> = Map::new("action_fees");
+/// Stores the checksum and creator of the code published for an ADO version
+pub const CODE_INFO: Map<&str, PublishedCodeInfo> = Map::new("published_code_info");
+/// Stores the release metadata provided when an ADO version was published
+pub const RELEASE_METADATA: Map<&str, ReleaseMetadata> = Map::new("release_metadata");
+/// Stores a mapping from an (ADO type, target version, source version range) to the migration path
+/// from that range into the target version
+pub const MIGRATION_PATHS: Map<(&str, &str, &str), MigrationPath> = Map::new("migration_paths");
+/// Stores the deprecation/yank status of an ADO version, versions without an entry are active
+pub const VERSION_STATUS: Map<&str, VersionStatusInfo> = Map::new("version_status");
+/// Stores the profiles of registered publishers
+pub const PUBLISHER_PROFILES: Map<&str, PublisherProfile> = Map::new("publisher_profiles");
+/// Indexes the ADO types each publisher has published at least one version of
+pub const PUBLISHER_ADOS: Map<(&str, &str), bool> = Map::new("publisher_ados");
 
 pub fn store_code_id(
     storage: &mut dyn Storage,
@@ -43,12 +60,76 @@ pub fn store_code_id(
     Ok(())
 }
 
-pub fn read_code_id(storage: &dyn Storage, ado_version: &ADOVersion) -> StdResult<u64> {
+/// Sets the publisher of an ADO version, keeping the publisher index up to date
+pub fn save_publisher(
+    storage: &mut dyn Storage,
+    ado_version: &ADOVersion,
+    publisher: &str,
+) -> Result<(), ContractError> {
+    let ado_type = ado_version.get_type();
+    let previous = PUBLISHER.may_load(storage, ado_version.as_str())?;
+    PUBLISHER.save(storage, ado_version.as_str(), &publisher.to_string())?;
+    PUBLISHER_ADOS.save(storage, (publisher, &ado_type), &true)?;
+
+    if let Some(previous) = previous.filter(|previous| previous != publisher) {
+        let mut still_publishes = false;
+        for (version, _) in read_all_versions(storage, &ado_type)? {
+            if PUBLISHER.may_load(storage, version.as_str())?.as_deref() == Some(&previous) {
+                still_publishes = true;
+                break;
+            }
+        }
+        if !still_publishes {
+            PUBLISHER_ADOS.remove(storage, (&previous, &ado_type));
+        }
+    }
+    Ok(())
+}
+
+/// Indexes the publisher of every published ADO version, for ADO versions published before the index existed
+pub fn backfill_publisher_ados(deps: DepsMut) -> Result<(), ContractError> {
+    let publishers = PUBLISHER
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, String)>>>()?;
+    for (ado_version, publisher) in publishers {
+        let ado_type = ADOVersion::from_string(ado_version).get_type();
+        PUBLISHER_ADOS.save(deps.storage, (&publisher, &ado_type), &true)?;
+    }
+    Ok(())
+}
+
+/// Reads the code ID for the given ADO version
+///
+/// Exact versions are always returned, `latest` and semver ranges resolve to the newest matching version that has not been yanked
+pub fn read_code_id(storage: &dyn Storage, ado_version: &ADOVersion) -> Result<u64, ContractError> {
+    let (_version, code_id) = resolve_version(storage, ado_version)?;
+    Ok(code_id)
+}
+
+/// Resolves the given ADO version to an exact published version and its code ID
+pub fn resolve_version(
+    storage: &dyn Storage,
+    ado_version: &ADOVersion,
+) -> Result<(ADOVersion, u64), ContractError> {
+    let ado_type = ado_version.get_type();
     if ado_version.get_version() == "latest" {
-        let (_version, code_id) = read_latest_code_id(storage, ado_version.get_type())?;
-        Ok(code_id)
+        let (version, code_id) = read_latest_active_code_id(storage, ado_type.clone())?;
+        Ok((
+            ADOVersion::from_type(ado_type).with_version(version),
+            code_id,
+        ))
+    } else if let Some(req) = ado_version.get_version_req() {
+        read_newest_active_version(storage, &ado_type, |version| req.matches(version))?.ok_or(
+            ContractError::InvalidADOVersion {
+                msg: Some(format!(
+                    "No published version matches {}",
+                    ado_version.as_str()
+                )),
+            },
+        )
     } else {
-        CODE_ID.load(storage, ado_version.as_str())
+        let code_id = CODE_ID.load(storage, ado_version.as_str())?;
+        Ok((ado_version.clone(), code_id))
     }
 }
 
@@ -56,10 +137,74 @@ pub fn read_latest_code_id(storage: &dyn Storage, ado_type: String) -> StdResult
     LATEST_VERSION.load(storage, &ado_type)
 }
 
-// pub fn read_all_ado_types(storage: &dyn Storage) -> StdResult<Vec<String>> {
-//     let ado_types = CODE_ID
-//         .keys(storage, None, None, Order::Ascending)
-//         .flatten()
-//         .collect();
-//     Ok(ado_types)
-// }
+/// Reads the newest version of the given ADO type that has not been yanked
+pub fn read_latest_active_code_id(
+    storage: &dyn Storage,
+    ado_type: String,
+) -> Result<(String, u64), ContractError> {
+    let (version, code_id) = read_latest_code_id(storage, ado_type.clone())?;
+    let latest = ADOVersion::from_type(&ado_type).with_version(&version);
+    if !read_version_status(storage, &latest)?.is_yanked() {
+        return Ok((version, code_id));
+    }
+
+    read_newest_active_version(storage, &ado_type, |_| true)?
+        .map(|(ado_version, code_id)| (ado_version.get_version(), code_id))
+        .ok_or(ContractError::YankedADOVersion {
+            ado_version: latest.into_string(),
+        })
+}
+
+/// Reads the newest version of the given ADO type that satisfies `filter` and has not been yanked
+fn read_newest_active_version(
+    storage: &dyn Storage,
+    ado_type: &str,
+    filter: impl Fn(&Version) -> bool,
+) -> Result<Option<(ADOVersion, u64)>, ContractError> {
+    let mut newest: Option<(Version, ADOVersion, u64)> = None;
+    for (ado_version, code_id) in read_all_versions(storage, ado_type)? {
+        let version = match Version::parse(&ado_version.get_version()) {
+            Ok(version) => version,
+            Err(_) => continue,
+        };
+        if !filter(&version) || read_version_status(storage, &ado_version)?.is_yanked() {
+            continue;
+        }
+        let is_newer = match &newest {
+            Some((curr, _, _)) => version > *curr,
+            None => true,
+        };
+        if is_newer {
+            newest = Some((version, ado_version, code_id));
+        }
+    }
+    Ok(newest.map(|(_, ado_version, code_id)| (ado_version, code_id)))
+}
+
+/// Reads every published version of the given ADO type along with its code ID
+pub fn read_all_versions(
+    storage: &dyn Storage,
+    ado_type: &str,
+) -> StdResult<Vec<(ADOVersion, u64)>> {
+    let start_key = format!("{ado_type}@");
+    // All versions have @ as starting point, we can add A which has higher ascii than @ to get the end of the range
+    let end_key = format!("{ado_type}A");
+    CODE_ID
+        .range(
+            storage,
+            Some(Bound::inclusive(start_key.as_str())),
+            Some(Bound::exclusive(end_key.as_str())),
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(key, code_id)| (ADOVersion::from_string(key), code_id)))
+        .collect()
+}
+
+pub fn read_version_status(
+    storage: &dyn Storage,
+    ado_version: &ADOVersion,
+) -> StdResult<VersionStatusInfo> {
+    Ok(VERSION_STATUS
+        .may_load(storage, ado_version.as_str())?
+        .unwrap_or_default())
+}
@@ -1,12 +1,18 @@
 #[cfg(test)]
-use andromeda_std::testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT};
-use cosmwasm_std::{from_json, Uint128};
+use andromeda_std::testing::mock_querier::{
+    mock_dependencies_custom, MOCK_CODE_CHECKSUM, MOCK_CODE_CREATOR, MOCK_KERNEL_CONTRACT,
+};
+use cosmwasm_std::{from_json, to_json_binary, Addr, Decimal, Deps, HexBinary, Uint128};
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, migrate, query};
 use crate::state::{ACTION_FEES, CODE_ID, LATEST_VERSION, PUBLISHER};
 
 use andromeda_std::error::ContractError;
-use andromeda_std::os::adodb::{ADOVersion, ActionFee, ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_std::os::adodb::{
+    ADOVersion, ActionFee, CatalogueEntry, ExecuteMsg, FeeType, InstantiateMsg, MigrateMsg,
+    MigrationPath, PublishedCodeInfo, PublisherProfile, QueryMsg, ReleaseMetadata, ResolvedVersion,
+    VersionInfo, VersionStatus, VersionStatusInfo,
+};
 
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -48,12 +54,16 @@ fn test_publish() {
             amount: Uint128::from(1u128),
             asset: "somecw20token".to_string(),
             receiver: None,
+            fee_type: None,
+            accepted_assets: None,
         },
         ActionFee {
             action: "action2".to_string(),
             amount: Uint128::from(2u128),
             asset: "uusd".to_string(),
             receiver: None,
+            fee_type: None,
+            accepted_assets: None,
         },
     ];
 
@@ -65,6 +75,8 @@ fn test_publish() {
         code_id,
         action_fees: Some(action_fees.clone()),
         publisher: Some(owner.clone()),
+        checksum: None,
+        release: None,
     };
 
     let resp = execute(deps.as_mut(), env.clone(), info, msg.clone());
@@ -129,12 +141,19 @@ fn test_update_action_fees() {
             amount: Uint128::from(1u128),
             asset: "somecw20token".to_string(),
             receiver: None,
+            fee_type: None,
+            accepted_assets: None,
         },
         ActionFee {
             action: "action2".to_string(),
             amount: Uint128::from(2u128),
             asset: "uusd".to_string(),
             receiver: None,
+            fee_type: Some(FeeType::Percentage {
+                percent: Decimal::percent(2),
+                max: None,
+            }),
+            accepted_assets: Some(vec!["uandr".to_string()]),
         },
     ];
 
@@ -155,6 +174,26 @@ fn test_update_action_fees() {
         .save(deps.as_mut().storage, ado_version.as_str(), &code_id)
         .unwrap();
 
+    let invalid_msg = ExecuteMsg::UpdateActionFees {
+        action_fees: vec![ActionFee::new(
+            "action3".to_string(),
+            "uusd".to_string(),
+            Uint128::zero(),
+        )
+        .with_fee_type(FeeType::Percentage {
+            percent: Decimal::percent(150),
+            max: None,
+        })],
+        ado_type: ado_version.clone().into_string(),
+    };
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), invalid_msg).unwrap_err();
+    assert_eq!(
+        res,
+        ContractError::InvalidActionFee {
+            msg: "Fee percentage cannot exceed 100%".to_string()
+        }
+    );
+
     let res = execute(deps.as_mut(), env.clone(), info, msg.clone());
     assert!(res.is_ok());
 
@@ -314,6 +353,8 @@ fn test_get_code_id() {
         code_id,
         action_fees: None,
         publisher: Some(owner),
+        checksum: None,
+        release: None,
     };
     execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -371,6 +412,8 @@ fn test_all_ado_types() {
             code_id,
             action_fees: None,
             publisher: Some(owner.clone()),
+            checksum: None,
+            release: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
         code_id += 1;
@@ -389,3 +432,643 @@ fn test_all_ado_types() {
     ];
     assert_eq!(value, expected);
 }
+
+#[test]
+fn test_deprecate_and_yank() {
+    let owner = String::from("owner");
+    let publisher = String::from("publisher");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let first_version = ADOVersion::from_string("ado_type@0.1.0");
+    let second_version = ADOVersion::from_string("ado_type@0.1.1");
+    for (code_id, ado_version) in [(1u64, &first_version), (2u64, &second_version)] {
+        let msg = ExecuteMsg::Publish {
+            ado_type: ado_version.get_type(),
+            version: ado_version.get_version(),
+            code_id,
+            action_fees: None,
+            publisher: Some(publisher.clone()),
+            checksum: None,
+            release: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    // Only the publisher or owner can deprecate a version
+    let msg = ExecuteMsg::Deprecate {
+        ado_version: second_version.clone().into_string(),
+        reason: Some("Known bug".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_publisher", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info(&publisher, &[]), msg).unwrap();
+
+    let query_msg = QueryMsg::VersionStatus {
+        ado_version: second_version.clone().into_string(),
+    };
+    let res: VersionStatusInfo =
+        from_json(query(deps.as_ref(), env.clone(), query_msg.clone()).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        VersionStatusInfo {
+            status: VersionStatus::Deprecated,
+            reason: Some("Known bug".to_string()),
+        }
+    );
+
+    // Deprecated versions are still resolved as the latest version
+    let code_id_query = QueryMsg::CodeId {
+        key: "ado_type".to_string(),
+    };
+    let res: u64 =
+        from_json(query(deps.as_ref(), env.clone(), code_id_query.clone()).unwrap()).unwrap();
+    assert_eq!(res, 2);
+
+    let msg = ExecuteMsg::Yank {
+        ado_version: second_version.clone().into_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let res: VersionStatusInfo =
+        from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        VersionStatusInfo {
+            status: VersionStatus::Yanked,
+            reason: Some("Known bug".to_string()),
+        }
+    );
+
+    // Yanked versions are skipped when resolving the latest version but can be looked up exactly
+    let res: u64 =
+        from_json(query(deps.as_ref(), env.clone(), code_id_query.clone()).unwrap()).unwrap();
+    assert_eq!(res, 1);
+    let query_msg = QueryMsg::CodeId {
+        key: second_version.clone().into_string(),
+    };
+    let res: u64 = from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert_eq!(res, 2);
+
+    // Yanked versions cannot be deprecated again
+    let msg = ExecuteMsg::Deprecate {
+        ado_version: second_version.clone().into_string(),
+        reason: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::YankedADOVersion {
+            ado_version: second_version.into_string()
+        }
+    );
+
+    let msg = ExecuteMsg::Yank {
+        ado_version: first_version.clone().into_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let err = query(deps.as_ref(), env, code_id_query).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::YankedADOVersion {
+            ado_version: "ado_type@0.1.1".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_resolve_version_range() {
+    let owner = String::from("owner");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let versions = ["0.3.0", "0.3.2", "1.2.0", "1.2.5", "1.10.0", "2.0.0"];
+    for (idx, version) in versions.iter().enumerate() {
+        let msg = ExecuteMsg::Publish {
+            ado_type: "cw721".to_string(),
+            version: version.to_string(),
+            code_id: idx as u64 + 1,
+            action_fees: None,
+            publisher: None,
+            checksum: None,
+            release: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let resolve = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, key: &str| -> ResolvedVersion {
+        let query_msg = QueryMsg::ResolveVersion {
+            ado_version: key.to_string(),
+        };
+        from_json(query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap()
+    };
+
+    let res = resolve(&deps, "cw721@^1.2");
+    assert_eq!(
+        res,
+        ResolvedVersion {
+            ado_version: "cw721@1.10.0".to_string(),
+            code_id: 5,
+        }
+    );
+    assert_eq!(resolve(&deps, "cw721@~1.2").ado_version, "cw721@1.2.5");
+    assert_eq!(resolve(&deps, "cw721@~0.3").ado_version, "cw721@0.3.2");
+    assert_eq!(resolve(&deps, "cw721").ado_version, "cw721@2.0.0");
+    assert_eq!(resolve(&deps, "cw721@1.2.0").ado_version, "cw721@1.2.0");
+
+    // Ranges are also accepted by code ID lookups
+    let query_msg = QueryMsg::CodeId {
+        key: "cw721@~1.2".to_string(),
+    };
+    let res: u64 = from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert_eq!(res, 4);
+
+    // Yanked versions are skipped
+    let msg = ExecuteMsg::Yank {
+        ado_version: "cw721@1.2.5".to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(resolve(&deps, "cw721@~1.2").ado_version, "cw721@1.2.0");
+
+    let query_msg = QueryMsg::ResolveVersion {
+        ado_version: "cw721@^3".to_string(),
+    };
+    let err = query(deps.as_ref(), env.clone(), query_msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidADOVersion {
+            msg: Some("No published version matches cw721@^3".to_string())
+        }
+    );
+
+    // Ranges cannot be published
+    let msg = ExecuteMsg::Publish {
+        ado_type: "cw721".to_string(),
+        version: "^3.0".to_string(),
+        code_id: 10,
+        action_fees: None,
+        publisher: None,
+        checksum: None,
+        release: None,
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidADOVersion {
+            msg: Some("Published versions must be exact semver versions".to_string())
+        }
+    );
+}
+
+#[test]
+fn test_publish_checksum_and_release() {
+    let owner = String::from("owner");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let wrong_checksum = HexBinary::from(vec![0u8; 32]);
+    let msg = ExecuteMsg::Publish {
+        ado_type: "ado_type".to_string(),
+        version: "0.1.0".to_string(),
+        code_id: 1,
+        action_fees: None,
+        publisher: None,
+        checksum: Some(wrong_checksum.clone()),
+        release: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ChecksumMismatch {
+            expected: wrong_checksum.to_hex(),
+            actual: MOCK_CODE_CHECKSUM.to_string(),
+        }
+    );
+
+    let release = ReleaseMetadata {
+        changelog_url: Some("https://example.com/changelog".to_string()),
+        schema_hash: Some("schemahash".to_string()),
+        audit_reference: Some("audit-2024-01".to_string()),
+        min_kernel_version: Some("1.0.0".to_string()),
+    };
+    let msg = ExecuteMsg::Publish {
+        ado_type: "ado_type".to_string(),
+        version: "0.1.0".to_string(),
+        code_id: 1,
+        action_fees: None,
+        publisher: None,
+        checksum: Some(HexBinary::from_hex(MOCK_CODE_CHECKSUM).unwrap()),
+        release: Some(release.clone()),
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let query_msg = QueryMsg::VersionInfo {
+        ado_version: "ado_type@0.1.0".to_string(),
+    };
+    let res: VersionInfo = from_json(query(deps.as_ref(), env, query_msg).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        VersionInfo {
+            ado_version: "ado_type@0.1.0".to_string(),
+            code_id: 1,
+            publisher: owner,
+            status: VersionStatusInfo::default(),
+            code_info: Some(PublishedCodeInfo {
+                checksum: HexBinary::from_hex(MOCK_CODE_CHECKSUM).unwrap(),
+                creator: MOCK_CODE_CREATOR.to_string(),
+            }),
+            release: Some(release),
+        }
+    );
+}
+
+#[test]
+fn test_migration_paths() {
+    let owner = String::from("owner");
+    let publisher = String::from("publisher");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    for (code_id, version) in [(1u64, "1.0.0"), (2u64, "1.1.0"), (3u64, "1.2.0")] {
+        let msg = ExecuteMsg::Publish {
+            ado_type: "splitter".to_string(),
+            version: version.to_string(),
+            code_id,
+            action_fees: None,
+            publisher: Some(publisher.clone()),
+            checksum: None,
+            release: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let migrate_msg = to_json_binary(&"migrate").unwrap();
+    let msg = ExecuteMsg::AddMigrationPath {
+        ado_type: "splitter".to_string(),
+        from: ">=1.0.0, <1.2.0".to_string(),
+        to: "1.2.0".to_string(),
+        migrate_msg: Some(migrate_msg.clone()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_publisher", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info(&publisher, &[]), msg).unwrap();
+
+    // Target versions must be published
+    let msg = ExecuteMsg::AddMigrationPath {
+        ado_type: "splitter".to_string(),
+        from: "^1".to_string(),
+        to: "2.0.0".to_string(),
+        migrate_msg: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidADOVersion {
+            msg: Some("ADO version does not exist".to_string())
+        }
+    );
+
+    let expected = MigrationPath {
+        from: ">=1.0.0, <1.2.0".to_string(),
+        to: "1.2.0".to_string(),
+        migrate_msg: Some(migrate_msg),
+    };
+    let query_msg = QueryMsg::MigrationPaths {
+        ado_type: "splitter".to_string(),
+    };
+    let res: Vec<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert_eq!(res, vec![expected.clone()]);
+
+    let path_query = |from_version: &str| QueryMsg::MigrationPath {
+        ado_type: "splitter".to_string(),
+        from_version: from_version.to_string(),
+        to_version: "1.2.0".to_string(),
+    };
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("1.1.0")).unwrap()).unwrap();
+    assert_eq!(res, Some(expected.clone()));
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("0.9.0")).unwrap()).unwrap();
+    assert_eq!(res, None);
+
+    // A second range into the same version does not replace the first
+    let msg = ExecuteMsg::AddMigrationPath {
+        ado_type: "splitter".to_string(),
+        from: "<1.0.0".to_string(),
+        to: "1.2.0".to_string(),
+        migrate_msg: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let res: Vec<MigrationPath> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::MigrationPaths {
+                ado_type: "splitter".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.len(), 2);
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("0.9.0")).unwrap()).unwrap();
+    assert_eq!(res.unwrap().from, "<1.0.0");
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("1.1.0")).unwrap()).unwrap();
+    assert_eq!(res, Some(expected));
+
+    let msg = ExecuteMsg::RemoveMigrationPath {
+        ado_type: "splitter".to_string(),
+        from: Some("<1.0.0".to_string()),
+        to: "1.2.0".to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("0.9.0")).unwrap()).unwrap();
+    assert_eq!(res, None);
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env.clone(), path_query("1.1.0")).unwrap()).unwrap();
+    assert!(res.is_some());
+
+    let msg = ExecuteMsg::RemoveMigrationPath {
+        ado_type: "splitter".to_string(),
+        from: None,
+        to: "1.2.0".to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let res: Option<MigrationPath> =
+        from_json(query(deps.as_ref(), env, path_query("1.1.0")).unwrap()).unwrap();
+    assert_eq!(res, None);
+}
+
+fn publisher_ados(deps: Deps, publisher: &str) -> Vec<String> {
+    from_json(
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::PublisherADOs {
+                publisher: publisher.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_publisher_registry_and_catalogue() {
+    let owner = String::from("owner");
+    let publisher = String::from("publisher");
+    let new_publisher = String::from("new_publisher");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(owner.as_str(), &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(&owner, &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let fee = ActionFee::new("action".to_string(), "uusd".to_string(), Uint128::new(1));
+    for (code_id, ado_type, version) in [
+        (1u64, "splitter", "1.0.0"),
+        (2u64, "splitter", "1.1.0"),
+        (3u64, "cw721", "1.0.0"),
+    ] {
+        let msg = ExecuteMsg::Publish {
+            ado_type: ado_type.to_string(),
+            version: version.to_string(),
+            code_id,
+            action_fees: Some(vec![fee.clone()]),
+            publisher: Some(publisher.clone()),
+            checksum: None,
+            release: None,
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let msg = ExecuteMsg::RegisterPublisher {
+        name: "".to_string(),
+        url: None,
+        payout_address: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info(&publisher, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::EmptyPublisherName {});
+
+    let msg = ExecuteMsg::RegisterPublisher {
+        name: "Publisher".to_string(),
+        url: Some("https://example.com".to_string()),
+        payout_address: Some("payout".to_string()),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(&publisher, &[]), msg).unwrap();
+
+    let res: Option<PublisherProfile> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Publisher {
+                address: publisher.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        Some(PublisherProfile {
+            name: "Publisher".to_string(),
+            url: Some("https://example.com".to_string()),
+            payout_address: Some(Addr::unchecked("payout")),
+        })
+    );
+
+    assert_eq!(
+        publisher_ados(deps.as_ref(), &publisher),
+        vec!["cw721".to_string(), "splitter".to_string()]
+    );
+
+    let res: Vec<CatalogueEntry> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Catalogue {
+                start_after: Some("cw721".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        vec![CatalogueEntry {
+            ado_type: "splitter".to_string(),
+            latest_version: "1.1.0".to_string(),
+            code_id: 2,
+            publisher: publisher.clone(),
+            action_fees: vec![fee],
+            status: VersionStatusInfo::default(),
+        }]
+    );
+
+    // Types can only be handed over to registered publishers
+    let msg = ExecuteMsg::TransferPublisher {
+        ado_type: "splitter".to_string(),
+        new_publisher: new_publisher.clone(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(&publisher, &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::PublisherNotRegistered {
+            publisher: new_publisher.clone()
+        }
+    );
+
+    let register_msg = ExecuteMsg::RegisterPublisher {
+        name: "New Publisher".to_string(),
+        url: None,
+        payout_address: None,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(&new_publisher, &[]),
+        register_msg,
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_publisher", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(deps.as_mut(), env, mock_info(&publisher, &[]), msg).unwrap();
+    assert_eq!(
+        publisher_ados(deps.as_ref(), &publisher),
+        vec!["cw721".to_string()]
+    );
+    assert_eq!(
+        publisher_ados(deps.as_ref(), &new_publisher),
+        vec!["splitter".to_string()]
+    );
+    assert_eq!(
+        PUBLISHER
+            .load(deps.as_ref().storage, "splitter@1.0.0")
+            .unwrap(),
+        new_publisher
+    );
+}
+
+#[test]
+fn test_migrate_backfills_publisher_ados() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    // Versions published before the publisher index existed
+    for (ado_version, publisher) in [
+        ("splitter@0.1.0", "publisher"),
+        ("splitter@0.2.0", "publisher"),
+        ("cw721@0.1.0", "other_publisher"),
+    ] {
+        PUBLISHER
+            .save(deps.as_mut().storage, ado_version, &publisher.to_string())
+            .unwrap();
+    }
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:andromeda-adodb", "0.2.1").unwrap();
+    assert!(publisher_ados(deps.as_ref(), "publisher").is_empty());
+
+    migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+    assert_eq!(
+        publisher_ados(deps.as_ref(), "publisher"),
+        vec!["splitter".to_string()]
+    );
+    assert_eq!(
+        publisher_ados(deps.as_ref(), "other_publisher"),
+        vec!["cw721".to_string()]
+    );
+}
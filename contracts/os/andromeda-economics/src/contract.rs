@@ -1,24 +1,44 @@
-use crate::state::BALANCES;
-use andromeda_std::ado_base::{AndromedaQuery, InstantiateMsg as BaseInstantiateMsg};
+use crate::fees::{
+    attached_amount, available_balance, charge_balances, discount_key, fee_asset_key,
+    load_allowances, load_app_allowances, publisher_receiver, quote_fee, sponsored_amount,
+    take_attached, validate_discount, with_refund,
+};
+use crate::passes::{load_active_passes, pass_key, use_pass};
+use crate::state::{
+    fee_receipts, ALLOWANCES, APP_ALLOWANCES, BALANCES, DISCOUNTS, FEE_TOTALS, PASSES,
+    PASS_OFFERINGS, RECEIPT_COUNT,
+};
+use andromeda_std::ado_base::InstantiateMsg as BaseInstantiateMsg;
 use andromeda_std::ado_contract::ADOContract;
-use andromeda_std::amp::AndrAddr;
+use andromeda_std::amp::messages::AMPPkt;
+use andromeda_std::amp::{AndrAddr, Recipient};
+use andromeda_std::andr_migrate;
 
-use andromeda_std::error::{from_semver, ContractError};
+use andromeda_std::common::Funds;
+use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::ADOVersion;
 use andromeda_std::os::aos_querier::AOSQuerier;
-use andromeda_std::os::economics::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use andromeda_std::os::economics::{
+    AccessPass, Allowance, AssetBalance, AssetWithdrawal, Cw20HookMsg, EffectiveFeeResponse,
+    ExecuteMsg, FeeDiscount, FeeHistoryFilter, FeeReceipt, FeeTarget, FeeTotal, InstantiateMsg,
+    MigrateMsg, PassOffering, QueryMsg, RevenueResponse, WithdrawAsset,
+};
 #[allow(unused_imports)]
 use cosmwasm_std::{
-    attr, coin, ensure, entry_point, from_json, to_json_binary, Addr, BankMsg, Binary, CosmosMsg,
-    Deps, DepsMut, Empty, Env, MessageInfo, Response, Storage, SubMsg, Uint128, WasmMsg,
+    attr, coin, ensure, entry_point, from_json, to_json_binary, Addr, BankMsg, Binary, Coin,
+    CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, QuerierWrapper, Reply, Response,
+    StdError, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cw2::{get_contract_version, set_contract_version};
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
-use semver::Version;
+use cw2::set_contract_version;
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-economics";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const KERNEL_WITHDRAW_ID: u64 = 1;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -38,10 +58,22 @@ pub fn instantiate(
             operators: None,
             kernel_address: msg.kernel_address,
             owner: msg.owner,
+            metadata: None,
         },
     )
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.result.is_err() {
+        return Err(ContractError::Std(StdError::generic_err(
+            msg.result.unwrap_err(),
+        )));
+    }
+
+    Ok(Response::default())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -51,14 +83,88 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Deposit { address } => execute_deposit_native(deps, info, address),
-        ExecuteMsg::PayFee { payee, action } => execute_pay_fee(deps, env, info, payee, action),
-        ExecuteMsg::Withdraw { amount, asset } => {
-            execute_withdraw_native(deps, info, amount, asset)
-        }
+        ExecuteMsg::PayFee {
+            payee,
+            action,
+            value,
+        } => execute_pay_fee(deps, env, info, payee, action, value),
+        ExecuteMsg::Withdraw {
+            amount,
+            asset,
+            recipient,
+        } => execute_withdraw(
+            deps,
+            env,
+            info,
+            vec![AssetWithdrawal {
+                asset: WithdrawAsset::Native(asset),
+                amount,
+            }],
+            recipient,
+        ),
         ExecuteMsg::Receive(cw20msg) => cw20_receive(deps, env, info, cw20msg),
-        ExecuteMsg::WithdrawCW20 { amount, asset } => {
-            execute_withdraw_cw20(deps, info, amount, asset)
+        ExecuteMsg::WithdrawCW20 {
+            amount,
+            asset,
+            recipient,
+        } => execute_withdraw(
+            deps,
+            env,
+            info,
+            vec![AssetWithdrawal {
+                asset: WithdrawAsset::Cw20(asset),
+                amount,
+            }],
+            recipient,
+        ),
+        ExecuteMsg::BatchWithdraw {
+            withdrawals,
+            recipient,
+        } => execute_withdraw(deps, env, info, withdrawals, recipient),
+        ExecuteMsg::SetDiscount { target, discount } => {
+            execute_set_discount(deps, info, target, Some(discount))
+        }
+        ExecuteMsg::RemoveDiscount { target } => execute_set_discount(deps, info, target, None),
+        ExecuteMsg::GrantAllowance {
+            payee,
+            asset,
+            limit,
+            period,
+        } => execute_grant_allowance(deps, env, info, payee, asset, limit, period),
+        ExecuteMsg::RevokeAllowance { payee, asset } => {
+            execute_revoke_allowance(deps, info, payee, asset)
         }
+        ExecuteMsg::GrantAppAllowance {
+            app,
+            asset,
+            limit,
+            period,
+        } => execute_grant_app_allowance(deps, env, info, app, asset, limit, period),
+        ExecuteMsg::RevokeAppAllowance { app, asset } => {
+            execute_revoke_app_allowance(deps, info, app, asset)
+        }
+        ExecuteMsg::SetPassOffering {
+            ado_type,
+            action,
+            asset,
+            price,
+            duration,
+        } => execute_set_pass_offering(deps, info, ado_type, action, asset, price, duration),
+        ExecuteMsg::RemovePassOffering { ado_type, action } => {
+            execute_remove_pass_offering(deps, info, ado_type, action)
+        }
+        ExecuteMsg::BuyPass {
+            ado_type,
+            action,
+            holder,
+            auto_renew,
+        } => execute_buy_pass(deps, env, info, ado_type, action, holder, auto_renew),
+        ExecuteMsg::SetPassAutoRenew {
+            ado_type,
+            action,
+            holder,
+            auto_renew,
+        } => execute_set_pass_auto_renew(deps, info, ado_type, action, holder, auto_renew),
     }
 }
 
@@ -184,155 +290,563 @@ pub(crate) fn spend_balance(
     Ok(remainder)
 }
 
+pub(crate) fn credit_balance(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    asset: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let balance = BALANCES
+        .load(storage, (addr.clone(), asset.to_string()))
+        .unwrap_or_default();
+    BALANCES.save(
+        storage,
+        (addr.clone(), asset.to_string()),
+        &(balance + amount),
+    )?;
+    Ok(())
+}
+
 /// Charges a fee depending on the sending ADO and the action being performed.
 /// Sender must be an ADO contract else this will error.
 ///
-/// Fees are charged in the following order:
+/// Funds attached to the message are used first, the rest of the fee is charged in the following order:
 /// 1. ADO
 /// 2. App
-/// 3. Payee
+/// 3. Sponsors of the payee
+/// 4. Payee
+///
+/// Flat fees accepting several assets are paid in the first asset that can cover the fee.
+///
+/// No fee is charged while the payee or App holds a valid pass for the ADO type or action.
+///
+/// Any attached funds not used to pay the fee are returned to the payee.
 fn execute_pay_fee(
-    deps: DepsMut,
-    _env: Env,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     payee: Addr,
     action: String,
+    value: Option<Funds>,
 ) -> Result<Response, ContractError> {
-    let mut resp = Response::default();
-
-    resp.attributes = vec![
+    let resp = Response::default().add_attributes(vec![
         attr("action", action.clone()),
         attr("sender", info.sender.to_string()),
         attr("payee", payee.to_string()),
-    ];
+    ]);
+    let mut funds = info.funds;
+    let now = env.block.time;
+
+    let quote = match quote_fee(
+        &deps.as_ref(),
+        &info.sender,
+        &payee,
+        &action,
+        value.as_ref(),
+    )? {
+        Some(quote) if !quote.amount.is_zero() => quote,
+        // No fee
+        _ => return Ok(with_refund(resp, &payee, funds)),
+    };
 
-    let contract_info = deps.querier.query_wasm_contract_info(info.sender.clone());
-    if let Ok(contract_info) = contract_info {
-        let code_id = contract_info.code_id;
-        let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
-        let ado_type = AOSQuerier::ado_type_getter(&deps.querier, &adodb_addr, code_id)?;
-        if ado_type.is_none() {
-            // Not an ADO
-            return Ok(resp);
+    // Actions covered by a pass are not charged
+    if let Some(pass) = use_pass(
+        &mut deps,
+        &payee,
+        quote.app.as_ref(),
+        &quote.ado_type,
+        &action,
+        now,
+    )? {
+        let resp = resp.add_attribute("pass_holder", pass.holder.to_string());
+        return Ok(with_refund(resp, &payee, funds));
+    }
+    let amount = quote.amount;
+
+    let fee_asset = if quote.assets.len() == 1 {
+        quote.assets[0].clone()
+    } else {
+        let mut payable_asset = None;
+        for asset in quote.assets {
+            let asset_key = fee_asset_key(&asset);
+            let attached = attached_amount(&funds, &asset_key);
+            let available = if attached >= amount {
+                attached
+            } else {
+                attached
+                    + available_balance(
+                        deps.storage,
+                        &info.sender,
+                        quote.app.as_ref(),
+                        &payee,
+                        &asset_key,
+                        amount - attached,
+                        now,
+                    )?
+            };
+            if available >= amount {
+                payable_asset = Some(asset);
+                break;
+            }
         }
+        payable_asset.ok_or(ContractError::InsufficientFunds {})?
+    };
+    let asset = fee_asset_key(&fee_asset);
 
-        let ado_type = ado_type.unwrap();
-        let fee = AOSQuerier::action_fee_getter(
-            &deps.querier,
-            &adodb_addr,
-            ado_type.as_str(),
-            action.as_str(),
-        )?;
+    let paid_from_funds = take_attached(&mut funds, &asset, amount);
+    let remainder = charge_balances(
+        deps.storage,
+        &info.sender,
+        quote.app.as_ref(),
+        &payee,
+        &asset,
+        amount - paid_from_funds,
+        now,
+    )?;
+
+    // If balance remaining then not enough funds to pay fee
+    ensure!(
+        remainder == Uint128::zero(),
+        ContractError::InsufficientFunds {}
+    );
 
-        match fee {
-            // No fee
-            None => Ok(resp),
-            Some(fee) => {
-                let asset_string = fee.asset.to_string();
-                let asset = asset_string.split(':').last().unwrap();
+    let recipient = if let Some(receiver) = quote.fee.receiver {
+        receiver
+    } else {
+        let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+        let publisher =
+            AOSQuerier::ado_publisher_getter(&deps.querier, &adodb_addr, quote.ado_type.as_str())?;
+        publisher_receiver(&deps.as_ref(), &deps.api.addr_validate(&publisher)?)?
+    };
 
-                // Charge ADO first
-                let mut remainder =
-                    spend_balance(deps.storage, &info.sender, asset.to_string(), fee.amount)?;
+    credit_balance(deps.storage, &recipient, &asset, amount)?;
 
-                // Next charge the app
-                if remainder > Uint128::zero() {
-                    let app_contract = deps.querier.query_wasm_smart::<Option<Addr>>(
-                        &info.sender,
-                        &AndromedaQuery::AppContract {},
-                    )?;
-                    remainder = if let Some(app_contract) = app_contract {
-                        spend_balance(deps.storage, &app_contract, asset.to_string(), remainder)?
-                    } else {
-                        remainder
-                    };
-                }
-
-                // Next charge the payee
-                if remainder > Uint128::zero() {
-                    remainder = spend_balance(deps.storage, &payee, asset.to_string(), remainder)?;
-                }
-
-                // If balance remaining then not enough funds to pay fee
-                ensure!(
-                    remainder == Uint128::zero(),
-                    ContractError::InsufficientFunds {}
-                );
+    let ado_type = ADOVersion::from_string(&quote.ado_type).get_type();
+    FEE_TOTALS.update(
+        deps.storage,
+        (&ado_type, &action, &asset),
+        |total| -> Result<_, ContractError> { Ok(total.unwrap_or_default() + amount) },
+    )?;
 
-                let recipient = if let Some(receiver) = fee.receiver {
-                    receiver
-                } else {
-                    let publisher = AOSQuerier::ado_publisher_getter(
-                        &deps.querier,
-                        &adodb_addr,
-                        ado_type.as_str(),
-                    )?;
-                    deps.api.addr_validate(&publisher)?
-                };
+    let receipt_id = RECEIPT_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    RECEIPT_COUNT.save(deps.storage, &receipt_id)?;
+    fee_receipts().save(
+        deps.storage,
+        receipt_id,
+        &FeeReceipt {
+            receipt_id,
+            ado: info.sender,
+            ado_type: quote.ado_type,
+            action,
+            payee: payee.clone(),
+            receiver: recipient.clone(),
+            asset: fee_asset.clone(),
+            amount,
+            paid_from_funds,
+            refunded: funds.clone(),
+            timestamp: now,
+        },
+    )?;
 
-                let receiver_balance = BALANCES
-                    .load(
-                        deps.as_ref().storage,
-                        (recipient.clone(), asset.to_string()),
-                    )
-                    .unwrap_or_default();
-                BALANCES.save(
-                    deps.storage,
-                    (recipient.clone(), asset.to_string()),
-                    &(receiver_balance + fee.amount),
-                )?;
-
-                resp = resp
-                    .add_attribute("paid_fee", format!("{}{}", fee.amount, fee.asset))
-                    .add_attribute("fee_recipient", recipient.to_string());
-                Ok(resp)
-            }
+    let resp = resp
+        .add_attribute("paid_fee", format!("{}{}", amount, fee_asset))
+        .add_attribute("fee_recipient", recipient.to_string())
+        .add_attribute("receipt_id", receipt_id.to_string());
+    Ok(with_refund(resp, &payee, funds))
+}
+
+fn execute_set_discount(
+    deps: DepsMut,
+    info: MessageInfo,
+    target: FeeTarget,
+    discount: Option<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    let is_owner = ADOContract::default().is_contract_owner(deps.storage, info.sender.as_str())?;
+    let is_app_owner = match &target {
+        FeeTarget::App(app) => {
+            let app = app.get_raw_address(&deps.as_ref())?;
+            // An App whose ownership has been renounced can only be given discounts by the contract owner
+            AOSQuerier::ado_owner_getter(&deps.querier, &app)?.as_ref() == Some(&info.sender)
+        }
+        _ => false,
+    };
+    ensure!(is_owner || is_app_owner, ContractError::Unauthorized {});
+
+    let (kind, id) = discount_key(&deps.as_ref(), &target)?;
+    let resp = Response::default()
+        .add_attributes(vec![attr("target_type", kind), attr("target", id.clone())]);
+    match discount {
+        Some(discount) => {
+            validate_discount(&discount)?;
+            DISCOUNTS.save(deps.storage, (kind, &id), &discount)?;
+            Ok(resp.add_attribute("action", "set_discount"))
+        }
+        None => {
+            DISCOUNTS.remove(deps.storage, (kind, &id));
+            Ok(resp.add_attribute("action", "remove_discount"))
         }
-    } else {
-        // Not a contract
-        Err(ContractError::InvalidSender {})
     }
 }
 
-fn execute_withdraw_native(
+fn execute_grant_allowance(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    amount: Option<Uint128>,
+    payee: AndrAddr,
     asset: String,
+    limit: Uint128,
+    period: Option<u64>,
 ) -> Result<Response, ContractError> {
-    let mut resp = Response::default();
+    ensure!(!limit.is_zero(), ContractError::InvalidZeroAmount {});
+    let payee = payee.get_raw_address(&deps.as_ref())?;
+    ALLOWANCES.save(
+        deps.storage,
+        (&payee, &info.sender, &asset),
+        &Allowance {
+            sponsor: info.sender.clone(),
+            payee: payee.clone(),
+            asset: asset.clone(),
+            limit,
+            spent: Uint128::zero(),
+            period,
+            period_start: env.block.time,
+        },
+    )?;
 
-    let balance = BALANCES
-        .load(deps.storage, (info.sender.clone(), asset.to_string()))
-        .unwrap_or_default();
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "grant_allowance"),
+        attr("sponsor", info.sender),
+        attr("payee", payee),
+        attr("asset", asset),
+        attr("limit", limit),
+    ]))
+}
 
-    let amount = if let Some(amount) = amount {
-        amount
-    } else {
-        balance
-    };
+fn execute_grant_app_allowance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    app: AndrAddr,
+    asset: String,
+    limit: Uint128,
+    period: Option<u64>,
+) -> Result<Response, ContractError> {
+    ensure!(!limit.is_zero(), ContractError::InvalidZeroAmount {});
+    let app = app.get_raw_address(&deps.as_ref())?;
+    APP_ALLOWANCES.save(
+        deps.storage,
+        (&app, &info.sender, &asset),
+        &Allowance {
+            sponsor: info.sender.clone(),
+            payee: app.clone(),
+            asset: asset.clone(),
+            limit,
+            spent: Uint128::zero(),
+            period,
+            period_start: env.block.time,
+        },
+    )?;
 
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "grant_app_allowance"),
+        attr("sponsor", info.sender),
+        attr("app", app),
+        attr("asset", asset),
+        attr("limit", limit),
+    ]))
+}
+
+/// Ensures the sender is the contract owner or the publisher of the given ADO version, returning the publisher
+fn ensure_owner_or_publisher(
+    deps: &Deps,
+    sender: &Addr,
+    ado_type: &str,
+) -> Result<Addr, ContractError> {
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let publisher = AOSQuerier::ado_publisher_getter(&deps.querier, &adodb_addr, ado_type)?;
     ensure!(
-        balance >= amount && !balance.is_zero(),
-        ContractError::InsufficientFunds {}
+        publisher == *sender
+            || ADOContract::default().is_contract_owner(deps.storage, sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    Ok(deps.api.addr_validate(&publisher)?)
+}
+
+fn execute_set_pass_offering(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    action: Option<String>,
+    asset: String,
+    price: Uint128,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let publisher = ensure_owner_or_publisher(&deps.as_ref(), &info.sender, &ado_type)?;
+    ensure!(!price.is_zero(), ContractError::InvalidZeroAmount {});
+    ensure!(duration > 0, ContractError::InvalidExpiration {});
+
+    let (ado_type, action_key) = pass_key(&ado_type, &action);
+    PASS_OFFERINGS.save(
+        deps.storage,
+        (&ado_type, &action_key),
+        &PassOffering {
+            ado_type: ado_type.clone(),
+            publisher,
+            action,
+            asset,
+            price,
+            duration,
+        },
+    )?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "set_pass_offering"),
+        attr("ado_type", ado_type),
+        attr("pass_action", action_key),
+    ]))
+}
+
+fn execute_remove_pass_offering(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    action: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_owner_or_publisher(&deps.as_ref(), &info.sender, &ado_type)?;
+    let (ado_type, action_key) = pass_key(&ado_type, &action);
+    ensure!(
+        PASS_OFFERINGS.has(deps.storage, (&ado_type, &action_key)),
+        ContractError::PassOfferingNotFound {}
     );
+    PASS_OFFERINGS.remove(deps.storage, (&ado_type, &action_key));
 
-    spend_balance(deps.storage, &info.sender, asset.clone(), amount)?;
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "remove_pass_offering"),
+        attr("ado_type", ado_type),
+        attr("pass_action", action_key),
+    ]))
+}
 
-    let bank_msg = BankMsg::Send {
-        to_address: info.sender.clone().into(),
-        amount: vec![coin(amount.u128(), asset)],
+fn execute_buy_pass(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ado_type: String,
+    action: Option<String>,
+    holder: Option<AndrAddr>,
+    auto_renew: bool,
+) -> Result<Response, ContractError> {
+    let (ado_type, action_key) = pass_key(&ado_type, &action);
+    let offering = PASS_OFFERINGS
+        .may_load(deps.storage, (&ado_type, &action_key))?
+        .ok_or(ContractError::PassOfferingNotFound {})?;
+    let holder = match holder {
+        Some(holder) => holder.get_raw_address(&deps.as_ref())?,
+        None => info.sender.clone(),
     };
-    let cosmos_msg: CosmosMsg<Empty> = CosmosMsg::Bank(bank_msg);
 
-    resp.attributes = vec![
+    // Attached funds are used first, the rest is paid from the sender's balance
+    let mut funds = info.funds;
+    let paid_from_funds = take_attached(&mut funds, &offering.asset, offering.price);
+    let remainder = spend_balance(
+        deps.storage,
+        &info.sender,
+        offering.asset.clone(),
+        offering.price - paid_from_funds,
+    )?;
+    ensure!(remainder.is_zero(), ContractError::InsufficientFunds {});
+
+    let receiver = publisher_receiver(&deps.as_ref(), &offering.publisher)?;
+    credit_balance(deps.storage, &receiver, &offering.asset, offering.price)?;
+
+    // Valid passes are extended
+    let now = env.block.time;
+    let key = (&holder, ado_type.as_str(), action_key.as_str());
+    let expires = match PASSES.may_load(deps.storage, key)? {
+        Some(pass) if pass.expires > now => pass.expires,
+        _ => now,
+    }
+    .plus_seconds(offering.duration);
+    PASSES.save(
+        deps.storage,
+        key,
+        &AccessPass {
+            holder: holder.clone(),
+            buyer: info.sender.clone(),
+            ado_type: ado_type.clone(),
+            action,
+            expires,
+            auto_renew,
+        },
+    )?;
+
+    let resp = Response::default().add_attributes(vec![
+        attr("action", "buy_pass"),
+        attr("holder", holder),
+        attr("ado_type", ado_type),
+        attr("pass_action", action_key),
+        attr("expires", expires.to_string()),
+    ]);
+    Ok(with_refund(resp, &info.sender, funds))
+}
+
+fn execute_set_pass_auto_renew(
+    deps: DepsMut,
+    info: MessageInfo,
+    ado_type: String,
+    action: Option<String>,
+    holder: AndrAddr,
+    auto_renew: bool,
+) -> Result<Response, ContractError> {
+    let holder = holder.get_raw_address(&deps.as_ref())?;
+    let (ado_type, action_key) = pass_key(&ado_type, &action);
+    let key = (&holder, ado_type.as_str(), action_key.as_str());
+    let mut pass = PASSES
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::PassNotFound {})?;
+    ensure!(pass.buyer == info.sender, ContractError::Unauthorized {});
+    pass.auto_renew = auto_renew;
+    PASSES.save(deps.storage, key, &pass)?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "set_pass_auto_renew"),
+        attr("holder", holder),
+        attr("auto_renew", auto_renew.to_string()),
+    ]))
+}
+
+fn execute_revoke_allowance(
+    deps: DepsMut,
+    info: MessageInfo,
+    payee: AndrAddr,
+    asset: String,
+) -> Result<Response, ContractError> {
+    let payee = payee.get_raw_address(&deps.as_ref())?;
+    ensure!(
+        ALLOWANCES.has(deps.storage, (&payee, &info.sender, &asset)),
+        ContractError::NoAllowance {}
+    );
+    ALLOWANCES.remove(deps.storage, (&payee, &info.sender, &asset));
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "revoke_allowance"),
+        attr("sponsor", info.sender),
+        attr("payee", payee),
+        attr("asset", asset),
+    ]))
+}
+
+fn execute_revoke_app_allowance(
+    deps: DepsMut,
+    info: MessageInfo,
+    app: AndrAddr,
+    asset: String,
+) -> Result<Response, ContractError> {
+    let app = app.get_raw_address(&deps.as_ref())?;
+    ensure!(
+        APP_ALLOWANCES.has(deps.storage, (&app, &info.sender, &asset)),
+        ContractError::NoAllowance {}
+    );
+    APP_ALLOWANCES.remove(deps.storage, (&app, &info.sender, &asset));
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "revoke_app_allowance"),
+        attr("sponsor", info.sender),
+        attr("app", app),
+        attr("asset", asset),
+    ]))
+}
+
+/// Withdraws the given assets from the sender's balances.
+///
+/// Funds are sent to the sender unless a recipient is provided. Native funds sent to a recipient are routed
+/// via the Kernel so that VFS paths and `ibc://` addresses can be used.
+fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    withdrawals: Vec<AssetWithdrawal>,
+    recipient: Option<Recipient>,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !withdrawals.is_empty(),
+        ContractError::InvalidTokensToWithdraw {
+            msg: "No assets provided".to_string()
+        }
+    );
+    // CW20 tokens cannot be routed to another chain by the Kernel
+    let is_remote = recipient.as_ref().map_or(false, |recipient| {
+        recipient.address.get_protocol().is_some()
+    });
+    ensure!(
+        !is_remote
+            || withdrawals
+                .iter()
+                .all(|withdrawal| matches!(withdrawal.asset, WithdrawAsset::Native(_))),
+        ContractError::UnsupportedProtocol {}
+    );
+
+    let mut resp = Response::default().add_attributes(vec![
         attr("action", "withdraw"),
         attr("sender", info.sender.to_string()),
-        attr("amount", amount),
-    ];
+    ]);
+
+    let mut native_funds: Vec<Coin> = vec![];
+    for withdrawal in withdrawals {
+        let asset = match &withdrawal.asset {
+            WithdrawAsset::Native(denom) => denom,
+            WithdrawAsset::Cw20(address) => address,
+        };
+        let balance = BALANCES
+            .load(deps.storage, (info.sender.clone(), asset.to_string()))
+            .unwrap_or_default();
+        let amount = withdrawal.amount.unwrap_or(balance);
+
+        ensure!(
+            balance >= amount && !balance.is_zero(),
+            ContractError::InsufficientFunds {}
+        );
+
+        spend_balance(deps.storage, &info.sender, asset.to_string(), amount)?;
+        resp = resp.add_attribute("amount", format!("{amount}{asset}"));
+
+        match withdrawal.asset {
+            WithdrawAsset::Native(denom) => native_funds.push(coin(amount.u128(), denom)),
+            WithdrawAsset::Cw20(address) => {
+                let msg = match &recipient {
+                    None => cw20_withdraw_msg(amount, address, info.sender.clone()),
+                    Some(recipient) => {
+                        recipient.generate_msg_cw20(&deps.as_ref(), Cw20Coin { address, amount })?
+                    }
+                };
+                resp = resp.add_submessage(msg);
+            }
+        }
+    }
 
-    resp = resp.add_message(cosmos_msg);
+    if !native_funds.is_empty() {
+        let msg = match &recipient {
+            None => SubMsg::new(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: native_funds,
+            }),
+            Some(recipient) => {
+                let kernel_address =
+                    ADOContract::default().get_kernel_address(deps.as_ref().storage)?;
+                let pkt = AMPPkt::new(
+                    env.contract.address.clone(),
+                    env.contract.address,
+                    vec![recipient.generate_amp_msg(Some(native_funds.clone()))],
+                );
+                pkt.to_sub_msg(kernel_address, Some(native_funds), KERNEL_WITHDRAW_ID)?
+            }
+        };
+        resp = resp.add_submessage(msg);
+    }
 
+    if let Some(recipient) = recipient {
+        resp = resp.add_attribute("recipient", recipient.address.to_string());
+    }
     Ok(resp)
 }
 
@@ -356,85 +870,158 @@ pub(crate) fn cw20_withdraw_msg(
     )
 }
 
-fn execute_withdraw_cw20(
-    deps: DepsMut,
-    info: MessageInfo,
-    amount: Option<Uint128>,
-    asset: String,
-) -> Result<Response, ContractError> {
-    let mut resp = Response::default();
-
-    let balance = BALANCES
-        .load(deps.storage, (info.sender.clone(), asset.to_string()))
-        .unwrap_or_default();
-
-    let amount = if let Some(amount) = amount {
-        amount
-    } else {
-        balance
-    };
-
-    ensure!(
-        balance >= amount && !balance.is_zero(),
-        ContractError::InsufficientFunds {}
-    );
-
-    spend_balance(deps.storage, &info.sender, asset.clone(), amount)?;
-
-    let msg = cw20_withdraw_msg(amount, asset, info.sender.clone());
-
-    resp.attributes = vec![
-        attr("action", "withdraw"),
-        attr("sender", info.sender.to_string()),
-        attr("amount", amount),
-    ];
-
-    resp = resp.add_submessage(msg);
-
-    Ok(resp)
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // New version
-    let version: Version = CONTRACT_VERSION.parse().map_err(from_semver)?;
-
-    // Old version
-    let stored = get_contract_version(deps.storage)?;
-    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
-
-    let contract = ADOContract::default();
-
-    ensure!(
-        stored.contract == CONTRACT_NAME,
-        ContractError::CannotMigrate {
-            previous_contract: stored.contract,
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::Balance { address, asset } => {
+            Ok(to_json_binary(&query_balance(deps, address, asset)?)?)
         }
-    );
-
-    // New version has to be newer/greater than the old version
-    ensure!(
-        storage_version < version,
-        ContractError::CannotMigrate {
-            previous_contract: stored.version,
+        QueryMsg::Receipt { receipt_id } => Ok(to_json_binary(
+            &fee_receipts().may_load(deps.storage, receipt_id)?,
+        )?),
+        QueryMsg::Discount { target } => Ok(to_json_binary(&query_discount(deps, target)?)?),
+        QueryMsg::Allowances { payee } => Ok(to_json_binary(&query_allowances(deps, env, payee)?)?),
+        QueryMsg::AppAllowances { app } => {
+            Ok(to_json_binary(&query_app_allowances(deps, env, app)?)?)
         }
-    );
-
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        QueryMsg::EffectiveFee {
+            ado,
+            action,
+            payee,
+            value,
+        } => Ok(to_json_binary(&query_effective_fee(
+            deps, env, ado, action, payee, value,
+        )?)?),
+        QueryMsg::Balances { address } => Ok(to_json_binary(&query_balances(deps, address)?)?),
+        QueryMsg::FeeHistory {
+            filter,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&query_fee_history(
+            deps,
+            filter,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::FeeTotals { ado_type, action } => {
+            Ok(to_json_binary(&query_fee_totals(deps, ado_type, action)?)?)
+        }
+        QueryMsg::Revenue { publisher } => Ok(to_json_binary(&query_revenue(deps, publisher)?)?),
+        QueryMsg::PassOffering { ado_type, action } => {
+            let (ado_type, action_key) = pass_key(&ado_type, &action);
+            Ok(to_json_binary(
+                &PASS_OFFERINGS.may_load(deps.storage, (&ado_type, &action_key))?,
+            )?)
+        }
+        QueryMsg::ActivePasses { holder } => {
+            let holder = holder.get_raw_address(&deps)?;
+            Ok(to_json_binary(&load_active_passes(
+                deps.storage,
+                &holder,
+                env.block.time,
+            )?)?)
+        }
+    }
+}
 
-    // Update the ADOContract's version
-    contract.execute_update_version(deps)?;
+fn load_balances(storage: &dyn Storage, addr: Addr) -> Result<Vec<AssetBalance>, ContractError> {
+    BALANCES
+        .prefix(addr)
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, amount)) if amount.is_zero()))
+        .map(|item| {
+            let (asset, amount) = item?;
+            Ok(AssetBalance { asset, amount })
+        })
+        .collect()
+}
 
-    Ok(Response::default())
+fn query_balances(deps: Deps, address: AndrAddr) -> Result<Vec<AssetBalance>, ContractError> {
+    let addr = address.get_raw_address(&deps)?;
+    load_balances(deps.storage, addr)
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
-    match msg {
-        QueryMsg::Balance { address, asset } => {
-            Ok(to_json_binary(&query_balance(_deps, address, asset)?)?)
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+fn query_fee_history(
+    deps: Deps,
+    filter: Option<FeeHistoryFilter>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<FeeReceipt>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let receipts = fee_receipts();
+
+    let items = match filter {
+        None => receipts.range(deps.storage, start, None, Order::Ascending),
+        Some(filter) => {
+            let (index, addr) = match filter {
+                FeeHistoryFilter::Ado(addr) => (&receipts.idx.ado, addr),
+                FeeHistoryFilter::Payee(addr) => (&receipts.idx.payee, addr),
+                FeeHistoryFilter::Receiver(addr) => (&receipts.idx.receiver, addr),
+            };
+            let addr = addr.get_raw_address(&deps)?;
+            index
+                .prefix(addr.to_string())
+                .range(deps.storage, start, None, Order::Ascending)
         }
-    }
+    };
+    items.take(limit).map(|item| Ok(item?.1)).collect()
+}
+
+fn query_fee_totals(
+    deps: Deps,
+    ado_type: String,
+    action: Option<String>,
+) -> Result<Vec<FeeTotal>, ContractError> {
+    let ado_type = ADOVersion::from_string(ado_type).get_type();
+    let totals = match action {
+        Some(action) => FEE_TOTALS
+            .prefix((&ado_type, &action))
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (asset, amount) = item?;
+                Ok(FeeTotal {
+                    ado_type: ado_type.clone(),
+                    action: action.clone(),
+                    asset,
+                    amount,
+                })
+            })
+            .collect::<Result<Vec<FeeTotal>, ContractError>>()?,
+        None => FEE_TOTALS
+            .sub_prefix(&ado_type)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let ((action, asset), amount) = item?;
+                Ok(FeeTotal {
+                    ado_type: ado_type.clone(),
+                    action,
+                    asset,
+                    amount,
+                })
+            })
+            .collect::<Result<Vec<FeeTotal>, ContractError>>()?,
+    };
+    Ok(totals)
+}
+
+fn query_revenue(deps: Deps, publisher: AndrAddr) -> Result<RevenueResponse, ContractError> {
+    let publisher = publisher.get_raw_address(&deps)?;
+    let receiver = publisher_receiver(&deps, &publisher)?;
+    let balances = load_balances(deps.storage, receiver.clone())?;
+    Ok(RevenueResponse {
+        publisher,
+        receiver,
+        balances,
+    })
 }
 
 fn query_balance(deps: Deps, address: AndrAddr, asset: String) -> Result<Uint128, ContractError> {
@@ -444,3 +1031,67 @@ fn query_balance(deps: Deps, address: AndrAddr, asset: String) -> Result<Uint128
         .unwrap_or_default();
     Ok(balance)
 }
+
+fn query_discount(deps: Deps, target: FeeTarget) -> Result<Option<FeeDiscount>, ContractError> {
+    let (kind, id) = discount_key(&deps, &target)?;
+    Ok(DISCOUNTS.may_load(deps.storage, (kind, &id))?)
+}
+
+fn query_allowances(
+    deps: Deps,
+    env: Env,
+    payee: AndrAddr,
+) -> Result<Vec<Allowance>, ContractError> {
+    let payee = payee.get_raw_address(&deps)?;
+    load_allowances(deps.storage, &payee, env.block.time)
+}
+
+fn query_app_allowances(
+    deps: Deps,
+    env: Env,
+    app: AndrAddr,
+) -> Result<Vec<Allowance>, ContractError> {
+    let app = app.get_raw_address(&deps)?;
+    load_app_allowances(deps.storage, &app, env.block.time)
+}
+
+fn query_effective_fee(
+    deps: Deps,
+    env: Env,
+    ado: AndrAddr,
+    action: String,
+    payee: AndrAddr,
+    value: Option<Funds>,
+) -> Result<EffectiveFeeResponse, ContractError> {
+    let ado = ado.get_raw_address(&deps)?;
+    let payee = payee.get_raw_address(&deps)?;
+    let quote = match quote_fee(&deps, &ado, &payee, &action, value.as_ref())? {
+        Some(quote) => quote,
+        None => {
+            return Ok(EffectiveFeeResponse {
+                asset: None,
+                base_amount: Uint128::zero(),
+                discount: None,
+                amount: Uint128::zero(),
+                sponsored: Uint128::zero(),
+            })
+        }
+    };
+    let asset = quote.assets[0].clone();
+    let sponsored = sponsored_amount(
+        deps.storage,
+        &payee,
+        quote.app.as_ref(),
+        &fee_asset_key(&asset),
+        env.block.time,
+    )?
+    .min(quote.amount);
+
+    Ok(EffectiveFeeResponse {
+        asset: Some(asset),
+        base_amount: quote.base_amount,
+        discount: quote.discount,
+        amount: quote.amount,
+        sponsored,
+    })
+}
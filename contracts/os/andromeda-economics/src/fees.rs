@@ -0,0 +1,404 @@
+use crate::contract::spend_balance;
+use crate::state::{ALLOWANCES, APP_ALLOWANCES, BALANCES, DISCOUNTS};
+use andromeda_std::ado_contract::ADOContract;
+use andromeda_std::common::Funds;
+use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::{ADOVersion, ActionFee};
+use andromeda_std::os::aos_querier::AOSQuerier;
+use andromeda_std::os::economics::{Allowance, FeeDiscount, FeeTarget};
+use cosmwasm_std::{
+    ensure, Addr, BankMsg, Coin, Decimal, Deps, Order, Response, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::Map;
+
+/// The fee owed by a payee for an ADO action
+pub(crate) struct FeeQuote {
+    pub ado_type: String,
+    pub fee: ActionFee,
+    /// The App the ADO belongs to, if any
+    pub app: Option<Addr>,
+    /// The assets the fee can be paid in, in order of preference
+    pub assets: Vec<String>,
+    pub base_amount: Uint128,
+    pub discount: Option<FeeDiscount>,
+    /// The fee owed once the discount is applied
+    pub amount: Uint128,
+}
+
+/// Returns the balance key for a given fee asset, stripping any asset type prefix
+pub(crate) fn fee_asset_key(asset: &str) -> String {
+    asset.split(':').next_back().unwrap().to_string()
+}
+
+/// Returns the balance key and amount of each asset of the value moved by an action
+fn funds_assets(funds: &Funds) -> Vec<(String, Uint128)> {
+    funds
+        .clone()
+        .into_assets()
+        .into_iter()
+        .filter_map(|asset| match asset {
+            Funds::Native(coin) => Some((coin.denom, coin.amount)),
+            Funds::Cw20(coin) => Some((coin.address, coin.amount)),
+            // Multi funds are flattened into single assets
+            Funds::Multi(_) => None,
+        })
+        .collect()
+}
+
+/// Loads the ADO type of the given ADO and its fee for the given action, if any.
+/// Errors if the ADO is not a contract.
+fn get_action_fee(
+    deps: &Deps,
+    ado: &Addr,
+    action: &str,
+) -> Result<Option<(String, ActionFee)>, ContractError> {
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(ado)
+        // Not a contract
+        .map_err(|_| ContractError::InvalidSender {})?;
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let ado_type = AOSQuerier::ado_type_getter(&deps.querier, &adodb_addr, contract_info.code_id)?;
+    let ado_type = match ado_type {
+        Some(ado_type) => ado_type,
+        // Not an ADO
+        None => return Ok(None),
+    };
+
+    let fee = AOSQuerier::action_fee_getter(&deps.querier, &adodb_addr, &ado_type, action)?;
+    Ok(fee.map(|fee| (ado_type, fee)))
+}
+
+/// Calculates the fee owed by the payee for the given ADO action, returns `None` if the action has no fee.
+///
+/// Percentage and tiered fees are calculated from the provided value and paid in its asset, which must
+/// be accepted by the fee. If the value holds several assets the first accepted by the fee is used. Flat fees can be paid in any of the fee's accepted assets.
+pub(crate) fn quote_fee(
+    deps: &Deps,
+    ado: &Addr,
+    payee: &Addr,
+    action: &str,
+    value: Option<&Funds>,
+) -> Result<Option<FeeQuote>, ContractError> {
+    let (ado_type, fee) = match get_action_fee(deps, ado, action)? {
+        Some(action_fee) => action_fee,
+        None => return Ok(None),
+    };
+
+    let (assets, value_amount) = match value {
+        Some(value) if fee.is_value_based() => {
+            let value_assets = funds_assets(value);
+            let (asset, value_amount) = value_assets
+                .iter()
+                .find_map(|(value_asset, value_amount)| {
+                    fee.assets()
+                        .into_iter()
+                        .find(|asset| fee_asset_key(asset) == *value_asset)
+                        .map(|asset| (asset, *value_amount))
+                })
+                .ok_or_else(|| ContractError::InvalidAsset {
+                    asset: value_assets
+                        .iter()
+                        .map(|(value_asset, _)| value_asset.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(","),
+                })?;
+            (vec![asset], Some(value_amount))
+        }
+        _ => (fee.assets(), None),
+    };
+    let base_amount = fee.calculate(value_amount)?;
+
+    let app = AOSQuerier::ado_app_getter(&deps.querier, ado)?;
+    let discount = best_discount(deps.storage, payee, app.as_ref(), &ado_type)?;
+    let amount = match &discount {
+        Some(FeeDiscount::Exempt) => Uint128::zero(),
+        Some(FeeDiscount::Percent(percent)) => base_amount - base_amount * *percent,
+        None => base_amount,
+    };
+
+    Ok(Some(FeeQuote {
+        ado_type,
+        fee,
+        app,
+        assets,
+        base_amount,
+        discount,
+        amount,
+    }))
+}
+
+/// Returns the address the publisher's fees are paid to, their payout address if one is registered
+pub(crate) fn publisher_receiver(deps: &Deps, publisher: &Addr) -> Result<Addr, ContractError> {
+    let adodb_addr = ADOContract::default().get_adodb_address(deps.storage, &deps.querier)?;
+    let profile =
+        AOSQuerier::publisher_profile_getter(&deps.querier, &adodb_addr, publisher.as_str())?;
+    Ok(profile
+        .and_then(|profile| profile.payout_address)
+        .unwrap_or_else(|| publisher.clone()))
+}
+
+/// Returns the storage key for a discount target, resolving any addresses
+pub(crate) fn discount_key(
+    deps: &Deps,
+    target: &FeeTarget,
+) -> Result<(&'static str, String), ContractError> {
+    Ok(match target {
+        FeeTarget::Payee(addr) => ("payee", addr.get_raw_address(deps)?.to_string()),
+        FeeTarget::App(addr) => ("app", addr.get_raw_address(deps)?.to_string()),
+        FeeTarget::ADOType(ado_type) => ("ado_type", ADOVersion::from_string(ado_type).get_type()),
+    })
+}
+
+/// Returns the largest discount applying to the payee, App or ADO type
+fn best_discount(
+    storage: &dyn Storage,
+    payee: &Addr,
+    app: Option<&Addr>,
+    ado_type: &str,
+) -> Result<Option<FeeDiscount>, ContractError> {
+    let ado_type = ADOVersion::from_string(ado_type).get_type();
+    let mut keys = vec![("payee", payee.to_string()), ("ado_type", ado_type)];
+    if let Some(app) = app {
+        keys.push(("app", app.to_string()));
+    }
+
+    let mut best: Option<FeeDiscount> = None;
+    for (kind, id) in keys {
+        let discount = DISCOUNTS.may_load(storage, (kind, &id))?;
+        best = match (best, discount) {
+            (Some(FeeDiscount::Exempt), _) | (_, Some(FeeDiscount::Exempt)) => {
+                Some(FeeDiscount::Exempt)
+            }
+            (Some(FeeDiscount::Percent(a)), Some(FeeDiscount::Percent(b))) => {
+                Some(FeeDiscount::Percent(a.max(b)))
+            }
+            (best, None) => best,
+            (None, discount) => discount,
+        };
+    }
+    Ok(best)
+}
+
+/// Resets the amount spent from an allowance if its period has elapsed
+fn refresh_allowance(allowance: &mut Allowance, now: Timestamp) {
+    if let Some(period) = allowance.period.filter(|period| *period > 0) {
+        let elapsed = now
+            .seconds()
+            .saturating_sub(allowance.period_start.seconds());
+        if elapsed >= period {
+            allowance.period_start = allowance
+                .period_start
+                .plus_seconds(elapsed - elapsed % period);
+            allowance.spent = Uint128::zero();
+        }
+    }
+}
+
+type AllowanceMap<'a> = Map<'a, (&'a Addr, &'a Addr, &'a str), Allowance>;
+
+/// Loads every allowance granted to the grantee in the given map, with elapsed periods reset
+fn load_grants(
+    storage: &dyn Storage,
+    allowances: &AllowanceMap,
+    grantee: &Addr,
+    now: Timestamp,
+) -> Result<Vec<Allowance>, ContractError> {
+    allowances
+        .sub_prefix(grantee)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, mut allowance) = item?;
+            refresh_allowance(&mut allowance, now);
+            Ok(allowance)
+        })
+        .collect()
+}
+
+/// Loads every allowance granted to the payee, with elapsed periods reset
+pub(crate) fn load_allowances(
+    storage: &dyn Storage,
+    payee: &Addr,
+    now: Timestamp,
+) -> Result<Vec<Allowance>, ContractError> {
+    load_grants(storage, &ALLOWANCES, payee, now)
+}
+
+/// Loads every allowance granted to the users of the App, with elapsed periods reset
+pub(crate) fn load_app_allowances(
+    storage: &dyn Storage,
+    app: &Addr,
+    now: Timestamp,
+) -> Result<Vec<Allowance>, ContractError> {
+    load_grants(storage, &APP_ALLOWANCES, app, now)
+}
+
+/// Returns how much of the given asset sponsors can currently pay on behalf of the payee, including
+/// allowances granted to the users of the App
+pub(crate) fn sponsored_amount(
+    storage: &dyn Storage,
+    payee: &Addr,
+    app: Option<&Addr>,
+    asset: &str,
+    now: Timestamp,
+) -> Result<Uint128, ContractError> {
+    let mut allowances = load_allowances(storage, payee, now)?;
+    if let Some(app) = app {
+        allowances.extend(load_app_allowances(storage, app, now)?);
+    }
+    let mut sponsored = Uint128::zero();
+    for allowance in allowances
+        .into_iter()
+        .filter(|allowance| allowance.asset == asset)
+    {
+        let balance = BALANCES
+            .load(storage, (allowance.sponsor.clone(), asset.to_string()))
+            .unwrap_or_default();
+        sponsored += (allowance.limit - allowance.spent).min(balance);
+    }
+    Ok(sponsored)
+}
+
+/// Charges up to `amount` from the sponsors of the grantee in the given map, returning the amount that
+/// could not be paid
+fn charge_allowances(
+    storage: &mut dyn Storage,
+    allowances: &AllowanceMap,
+    grantee: &Addr,
+    asset: &str,
+    amount: Uint128,
+    now: Timestamp,
+) -> Result<Uint128, ContractError> {
+    let mut remainder = amount;
+    for mut allowance in load_grants(storage, allowances, grantee, now)?
+        .into_iter()
+        .filter(|allowance| allowance.asset == asset)
+    {
+        if remainder.is_zero() {
+            break;
+        }
+        let balance = BALANCES
+            .load(storage, (allowance.sponsor.clone(), asset.to_string()))
+            .unwrap_or_default();
+        let charge = (allowance.limit - allowance.spent)
+            .min(balance)
+            .min(remainder);
+        spend_balance(storage, &allowance.sponsor, asset.to_string(), charge)?;
+        allowance.spent += charge;
+        remainder -= charge;
+        allowances.save(storage, (grantee, &allowance.sponsor, asset), &allowance)?;
+    }
+    Ok(remainder)
+}
+
+/// Returns the combined balance of the ADO, its App, the payee's sponsors and the payee for the given
+/// asset, stopping once `amount` is reached.
+pub(crate) fn available_balance(
+    storage: &dyn Storage,
+    ado: &Addr,
+    app: Option<&Addr>,
+    payee: &Addr,
+    asset: &str,
+    amount: Uint128,
+    now: Timestamp,
+) -> Result<Uint128, ContractError> {
+    let balance_of = |addr: &Addr| {
+        BALANCES
+            .load(storage, (addr.clone(), asset.to_string()))
+            .unwrap_or_default()
+    };
+    let mut available = balance_of(ado);
+    if available < amount {
+        available += app.map(balance_of).unwrap_or_default();
+    }
+    if available < amount {
+        available += sponsored_amount(storage, payee, app, asset, now)?;
+    }
+    if available < amount {
+        available += balance_of(payee);
+    }
+    Ok(available)
+}
+
+/// Charges the given amount from the balances of the ADO, then its App, then the payee's sponsors, then
+/// the sponsors of the App's users and finally the payee.
+///
+/// Returns the amount that could not be paid.
+pub(crate) fn charge_balances(
+    storage: &mut dyn Storage,
+    ado: &Addr,
+    app: Option<&Addr>,
+    payee: &Addr,
+    asset: &str,
+    amount: Uint128,
+    now: Timestamp,
+) -> Result<Uint128, ContractError> {
+    if amount.is_zero() {
+        return Ok(amount);
+    }
+
+    // Charge ADO first
+    let mut remainder = spend_balance(storage, ado, asset.to_string(), amount)?;
+
+    // Next charge the app
+    if let Some(app) = app.filter(|_| !remainder.is_zero()) {
+        remainder = spend_balance(storage, app, asset.to_string(), remainder)?;
+    }
+
+    // Next charge the payee's sponsors
+    if remainder > Uint128::zero() {
+        remainder = charge_allowances(storage, &ALLOWANCES, payee, asset, remainder, now)?;
+    }
+
+    // Next charge the sponsors of the App's users
+    if let Some(app) = app.filter(|_| !remainder.is_zero()) {
+        remainder = charge_allowances(storage, &APP_ALLOWANCES, app, asset, remainder, now)?;
+    }
+
+    // Next charge the payee
+    if remainder > Uint128::zero() {
+        remainder = spend_balance(storage, payee, asset.to_string(), remainder)?;
+    }
+
+    Ok(remainder)
+}
+
+/// Returns the amount of the given asset attached to the message
+pub(crate) fn attached_amount(funds: &[Coin], asset: &str) -> Uint128 {
+    funds
+        .iter()
+        .filter(|coin| coin.denom == asset)
+        .map(|coin| coin.amount)
+        .sum()
+}
+
+/// Takes up to `amount` of the given asset from the attached funds, returning the amount taken
+pub(crate) fn take_attached(funds: &mut Vec<Coin>, asset: &str, amount: Uint128) -> Uint128 {
+    let mut taken = Uint128::zero();
+    for coin in funds.iter_mut().filter(|coin| coin.denom == asset) {
+        let take = coin.amount.min(amount - taken);
+        coin.amount -= take;
+        taken += take;
+    }
+    funds.retain(|coin| !coin.amount.is_zero());
+    taken
+}
+
+/// Returns any unused attached funds to the payee
+pub(crate) fn with_refund(resp: Response, payee: &Addr, funds: Vec<Coin>) -> Response {
+    if funds.is_empty() {
+        return resp;
+    }
+    resp.add_message(BankMsg::Send {
+        to_address: payee.to_string(),
+        amount: funds,
+    })
+}
+
+/// Ensures a discount percentage is valid
+pub(crate) fn validate_discount(discount: &FeeDiscount) -> Result<(), ContractError> {
+    if let FeeDiscount::Percent(percent) = discount {
+        ensure!(*percent <= Decimal::one(), ContractError::InvalidRate {});
+    }
+    Ok(())
+}
@@ -1,6 +1,8 @@
 pub mod contract;
+mod fees;
 #[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 pub mod mock;
+mod passes;
 mod state;
 
 #[cfg(test)]
@@ -1,15 +1,15 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, query, reply};
 use andromeda_std::{
-    amp::AndrAddr,
-    os::economics::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    amp::{AndrAddr, Recipient},
+    os::economics::{AssetWithdrawal, ExecuteMsg, InstantiateMsg, QueryMsg},
 };
 use cosmwasm_std::{Empty, Uint128};
 use cw_multi_test::{Contract, ContractWrapper};
 
 pub fn mock_andromeda_economics() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new_with_empty(execute, instantiate, query);
+    let contract = ContractWrapper::new_with_empty(execute, instantiate, query).with_reply(reply);
     Box::new(contract)
 }
 
@@ -28,11 +28,41 @@ pub fn mock_deposit(address: Option<AndrAddr>) -> ExecuteMsg {
 }
 
 pub fn mock_withdraw(amount: Option<Uint128>, asset: String) -> ExecuteMsg {
-    ExecuteMsg::Withdraw { amount, asset }
+    ExecuteMsg::Withdraw {
+        amount,
+        asset,
+        recipient: None,
+    }
+}
+
+pub fn mock_withdraw_to(
+    amount: Option<Uint128>,
+    asset: String,
+    recipient: Option<Recipient>,
+) -> ExecuteMsg {
+    ExecuteMsg::Withdraw {
+        amount,
+        asset,
+        recipient,
+    }
 }
 
 pub fn mock_withdraw_cw20(amount: Option<Uint128>, asset: String) -> ExecuteMsg {
-    ExecuteMsg::WithdrawCW20 { amount, asset }
+    ExecuteMsg::WithdrawCW20 {
+        amount,
+        asset,
+        recipient: None,
+    }
+}
+
+pub fn mock_batch_withdraw(
+    withdrawals: Vec<AssetWithdrawal>,
+    recipient: Option<Recipient>,
+) -> ExecuteMsg {
+    ExecuteMsg::BatchWithdraw {
+        withdrawals,
+        recipient,
+    }
 }
 
 pub fn mock_balance(address: AndrAddr, asset: impl Into<String>) -> QueryMsg {
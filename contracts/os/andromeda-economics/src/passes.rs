@@ -0,0 +1,88 @@
+use crate::contract::{credit_balance, spend_balance};
+use crate::fees::publisher_receiver;
+use crate::state::{BALANCES, PASSES, PASS_OFFERINGS};
+use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::ADOVersion;
+use andromeda_std::os::economics::{AccessPass, PassOffering};
+use cosmwasm_std::{Addr, DepsMut, Order, Storage, Timestamp};
+
+/// Returns the storage key for a pass offering or pass, passes without an action cover every action
+pub(crate) fn pass_key(ado_type: &str, action: &Option<String>) -> (String, String) {
+    (
+        ADOVersion::from_string(ado_type).get_type(),
+        action.clone().unwrap_or_default(),
+    )
+}
+
+/// Charges the pass price from the payer's balance and credits the publisher.
+///
+/// Returns `false` if the payer's balance cannot cover the price.
+fn charge_renewal(
+    deps: &mut DepsMut,
+    offering: &PassOffering,
+    payer: &Addr,
+) -> Result<bool, ContractError> {
+    let balance = BALANCES
+        .load(deps.storage, (payer.clone(), offering.asset.clone()))
+        .unwrap_or_default();
+    if balance < offering.price {
+        return Ok(false);
+    }
+    spend_balance(deps.storage, payer, offering.asset.clone(), offering.price)?;
+    let receiver = publisher_receiver(&deps.as_ref(), &offering.publisher)?;
+    credit_balance(deps.storage, &receiver, &offering.asset, offering.price)?;
+    Ok(true)
+}
+
+/// Finds a valid pass covering the action for the payee or the App, renewing expired passes from the
+/// buyer's balance if they are set to renew automatically.
+pub(crate) fn use_pass(
+    deps: &mut DepsMut,
+    payee: &Addr,
+    app: Option<&Addr>,
+    ado_type: &str,
+    action: &str,
+    now: Timestamp,
+) -> Result<Option<AccessPass>, ContractError> {
+    let ado_type = ADOVersion::from_string(ado_type).get_type();
+    let holders = [Some(payee), app];
+    for holder in holders.into_iter().flatten() {
+        for action_key in [action, ""] {
+            let key = (holder, ado_type.as_str(), action_key);
+            let mut pass = match PASSES.may_load(deps.storage, key)? {
+                Some(pass) => pass,
+                None => continue,
+            };
+            if pass.expires > now {
+                return Ok(Some(pass));
+            }
+            if !pass.auto_renew {
+                continue;
+            }
+            let offering = match PASS_OFFERINGS.may_load(deps.storage, (&ado_type, action_key))? {
+                Some(offering) => offering,
+                None => continue,
+            };
+            if charge_renewal(deps, &offering, &pass.buyer)? {
+                pass.expires = now.plus_seconds(offering.duration);
+                PASSES.save(deps.storage, key, &pass)?;
+                return Ok(Some(pass));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Loads the unexpired passes held by the given address
+pub(crate) fn load_active_passes(
+    storage: &dyn Storage,
+    holder: &Addr,
+    now: Timestamp,
+) -> Result<Vec<AccessPass>, ContractError> {
+    PASSES
+        .sub_prefix(holder)
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, pass)) if pass.expires <= now))
+        .map(|item| Ok(item?.1))
+        .collect()
+}
@@ -1,5 +1,55 @@
+use andromeda_std::os::economics::{AccessPass, Allowance, FeeDiscount, FeeReceipt, PassOffering};
 use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::Map;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 /// Contains all balances for an address
 pub const BALANCES: Map<(Addr, String), Uint128> = Map::new("balances");
+/// The number of receipts recorded, used to assign receipt IDs
+pub const RECEIPT_COUNT: Item<u64> = Item::new("receipt_count");
+/// Fee discounts keyed by target type and target
+pub const DISCOUNTS: Map<(&str, &str), FeeDiscount> = Map::new("discounts");
+/// Allowances granted by sponsors keyed by (payee, sponsor, asset)
+pub const ALLOWANCES: Map<(&Addr, &Addr, &str), Allowance> = Map::new("allowances");
+/// Allowances granted by sponsors to every user of an App keyed by (app, sponsor, asset)
+pub const APP_ALLOWANCES: Map<(&Addr, &Addr, &str), Allowance> = Map::new("app_allowances");
+/// Pass offerings keyed by (ADO type, action), an empty action covers every action
+pub const PASS_OFFERINGS: Map<(&str, &str), PassOffering> = Map::new("pass_offerings");
+/// Passes keyed by (holder, ADO type, action), an empty action covers every action
+pub const PASSES: Map<(&Addr, &str, &str), AccessPass> = Map::new("passes");
+/// Total fees collected keyed by (ADO type, action, asset)
+pub const FEE_TOTALS: Map<(&str, &str, &str), Uint128> = Map::new("fee_totals");
+
+pub struct FeeReceiptIndices<'a> {
+    pub ado: MultiIndex<'a, String, FeeReceipt, u64>,
+    pub payee: MultiIndex<'a, String, FeeReceipt, u64>,
+    pub receiver: MultiIndex<'a, String, FeeReceipt, u64>,
+}
+
+impl<'a> IndexList<FeeReceipt> for FeeReceiptIndices<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<FeeReceipt>> + '_> {
+        let v: Vec<&dyn Index<FeeReceipt>> = vec![&self.ado, &self.payee, &self.receiver];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Receipts for every fee paid, keyed by receipt ID
+pub fn fee_receipts<'a>() -> IndexedMap<'a, u64, FeeReceipt, FeeReceiptIndices<'a>> {
+    let indexes = FeeReceiptIndices {
+        ado: MultiIndex::new(
+            |_pk: &[u8], r| r.ado.to_string(),
+            "fee_receipts",
+            "fee_receipts__ado",
+        ),
+        payee: MultiIndex::new(
+            |_pk: &[u8], r| r.payee.to_string(),
+            "fee_receipts",
+            "fee_receipts__payee",
+        ),
+        receiver: MultiIndex::new(
+            |_pk: &[u8], r| r.receiver.to_string(),
+            "fee_receipts",
+            "fee_receipts__receiver",
+        ),
+    };
+    IndexedMap::new("fee_receipts", indexes)
+}
@@ -1,17 +1,27 @@
-use andromeda_std::amp::AndrAddr;
+use andromeda_std::amp::messages::AMPPkt;
+use andromeda_std::amp::{AndrAddr, Recipient};
+use andromeda_std::common::Funds;
 use andromeda_std::error::ContractError;
 #[cfg(test)]
 use andromeda_std::testing::mock_querier::{
-    mock_dependencies_custom, MOCK_ACTION, MOCK_KERNEL_CONTRACT,
+    mock_dependencies_custom, MOCK_ACTION, MOCK_KERNEL_CONTRACT, MOCK_MULTI_ASSET_ACTION,
+    MOCK_PERCENTAGE_ACTION,
+};
+use andromeda_std::testing::mock_querier::{MOCK_ADO_PUBLISHER, MOCK_APP_CONTRACT, MOCK_APP_OWNER};
+use cosmwasm_std::{
+    attr, coin, from_json, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal, Deps, Env, SubMsg,
+    Uint128,
 };
-use andromeda_std::testing::mock_querier::{MOCK_ADO_PUBLISHER, MOCK_APP_CONTRACT};
-use cosmwasm_std::{coin, to_json_binary, Addr, BankMsg, CosmosMsg, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::contract::{cw20_withdraw_msg, execute, instantiate, spend_balance};
+use crate::contract::{cw20_withdraw_msg, execute, instantiate, query, spend_balance};
 use crate::state::BALANCES;
 
-use andromeda_std::os::economics::{Cw20HookMsg, ExecuteMsg, InstantiateMsg};
+use andromeda_std::os::economics::{
+    AccessPass, Allowance, AssetBalance, AssetWithdrawal, Cw20HookMsg, EffectiveFeeResponse,
+    ExecuteMsg, FeeDiscount, FeeHistoryFilter, FeeReceipt, FeeTarget, FeeTotal, InstantiateMsg,
+    QueryMsg, RevenueResponse, WithdrawAsset,
+};
 
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
@@ -127,6 +137,7 @@ fn test_pay_fee() {
     let msg = ExecuteMsg::PayFee {
         payee: Addr::unchecked(payee),
         action: MOCK_ACTION.to_string(),
+        value: None,
     };
 
     // Paying fee without funds
@@ -172,6 +183,7 @@ fn test_pay_fee_contract() {
     let msg = ExecuteMsg::PayFee {
         payee: Addr::unchecked(payee),
         action: MOCK_ACTION.to_string(),
+        value: None,
     };
 
     BALANCES
@@ -208,6 +220,7 @@ fn test_pay_fee_app() {
     let msg = ExecuteMsg::PayFee {
         payee: Addr::unchecked(payee),
         action: MOCK_ACTION.to_string(),
+        value: None,
     };
 
     BALANCES
@@ -245,6 +258,7 @@ fn test_pay_fee_joint() {
     let msg = ExecuteMsg::PayFee {
         payee: Addr::unchecked(payee),
         action: MOCK_ACTION.to_string(),
+        value: None,
     };
 
     // Contract balance
@@ -331,6 +345,939 @@ fn test_pay_fee_joint() {
     assert_eq!(res, ContractError::InsufficientFunds {});
 }
 
+#[test]
+fn test_pay_fee_percentage() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let payee = "payee";
+    let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
+
+    let msg = |value: Option<Funds>| ExecuteMsg::PayFee {
+        payee: Addr::unchecked(payee),
+        action: MOCK_PERCENTAGE_ACTION.to_string(),
+        value,
+    };
+
+    // A value is required for percentage fees
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg(None)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: format!("A value is required to calculate the fee for {MOCK_PERCENTAGE_ACTION}")
+        }
+    );
+
+    // The value must be in an accepted asset
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        msg(Some(Funds::Native(coin(1000, "uatom")))),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidAsset {
+            asset: "uatom".to_string()
+        }
+    );
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(payee), "uandr".to_string()),
+            &Uint128::from(100u128),
+        )
+        .unwrap();
+
+    // 5% of 1000uandr
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        msg(Some(Funds::Native(coin(1000, "uandr")))),
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("paid_fee", "50uandr")));
+    let balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (publisher.clone(), "uandr".to_string()),
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::from(50u128));
+
+    // The minimum fee applies to small values
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        msg(Some(Funds::Native(coin(10, "uandr")))),
+    )
+    .unwrap();
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (publisher, "uandr".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(51u128));
+}
+
+#[test]
+fn test_pay_fee_multi_asset() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    let payee = "payee";
+
+    let msg = ExecuteMsg::PayFee {
+        payee: Addr::unchecked(payee),
+        action: MOCK_MULTI_ASSET_ACTION.to_string(),
+        value: None,
+    };
+
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    // Not enough of the primary asset to cover the fee
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(payee), "uusd".to_string()),
+            &Uint128::from(5u128),
+        )
+        .unwrap();
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (Addr::unchecked(payee), "uandr".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+
+    execute(deps.as_mut(), env, info, msg).unwrap();
+
+    let balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (Addr::unchecked(payee), "uusd".to_string()),
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::from(5u128));
+    let balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (Addr::unchecked(MOCK_ADO_PUBLISHER), "uandr".to_string()),
+        )
+        .unwrap();
+    assert_eq!(balance, Uint128::from(10u128));
+}
+
+#[test]
+fn test_pay_fee_attached_funds() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let payee = Addr::unchecked("payee");
+
+    let msg = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_ACTION.to_string(),
+        value: None,
+    };
+
+    // Payee has no balance, the fee is paid from attached funds and the excess returned
+    let info = mock_info("creator", &[coin(15, "uusd"), coin(3, "uandr")]);
+    let res = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: payee.to_string(),
+            amount: vec![coin(5, "uusd"), coin(3, "uandr")],
+        })]
+    );
+    assert!(res.attributes.contains(&attr("receipt_id", "1")));
+
+    let receipt: Option<FeeReceipt> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Receipt { receipt_id: 1 },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        receipt,
+        Some(FeeReceipt {
+            receipt_id: 1,
+            ado: Addr::unchecked("creator"),
+            ado_type: "ADOType".to_string(),
+            action: MOCK_ACTION.to_string(),
+            payee: payee.clone(),
+            receiver: Addr::unchecked(MOCK_ADO_PUBLISHER),
+            asset: "uusd".to_string(),
+            amount: Uint128::from(10u128),
+            paid_from_funds: Uint128::from(10u128),
+            refunded: vec![coin(5, "uusd"), coin(3, "uandr")],
+            timestamp: env.block.time,
+        })
+    );
+
+    // Attached funds are used before the payee's balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+    let info = mock_info("creator", &[coin(4, "uusd")]);
+    let res = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+    assert!(res.messages.is_empty());
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (payee.clone(), "uusd".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(4u128));
+
+    // Not enough attached funds or balance
+    let info = mock_info("creator", &[coin(5, "uusd")]);
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    let publisher_balance = BALANCES
+        .load(
+            deps.as_ref().storage,
+            (Addr::unchecked(MOCK_ADO_PUBLISHER), "uusd".to_string()),
+        )
+        .unwrap();
+    assert_eq!(publisher_balance, Uint128::from(20u128));
+}
+
+#[test]
+fn test_fee_discounts() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let payee = Addr::unchecked("payee");
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    let effective_fee = |deps: Deps| -> EffectiveFeeResponse {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::EffectiveFee {
+                    ado: AndrAddr::from_string("creator"),
+                    action: MOCK_ACTION.to_string(),
+                    payee: AndrAddr::from_string("payee"),
+                    value: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    // Only the owner can set payee and ADO type discounts
+    let msg = ExecuteMsg::SetDiscount {
+        target: FeeTarget::ADOType("ADOType".to_string()),
+        discount: FeeDiscount::Percent(Decimal::percent(20)),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    // Discounts above 100% are invalid
+    let msg = ExecuteMsg::SetDiscount {
+        target: FeeTarget::Payee(AndrAddr::from_string("payee")),
+        discount: FeeDiscount::Percent(Decimal::percent(101)),
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+
+    // The App owner can set a discount for their App, the largest discount applies
+    let msg = ExecuteMsg::SetDiscount {
+        target: FeeTarget::App(AndrAddr::from_string(MOCK_APP_CONTRACT)),
+        discount: FeeDiscount::Percent(Decimal::percent(50)),
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_APP_OWNER, &[]),
+        msg,
+    )
+    .unwrap();
+    assert_eq!(
+        effective_fee(deps.as_ref()),
+        EffectiveFeeResponse {
+            asset: Some("uusd".to_string()),
+            base_amount: Uint128::from(10u128),
+            discount: Some(FeeDiscount::Percent(Decimal::percent(50))),
+            amount: Uint128::from(5u128),
+            sponsored: Uint128::zero(),
+        }
+    );
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+    let msg = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_ACTION.to_string(),
+        value: None,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    let balance = BALANCES
+        .load(deps.as_ref().storage, (payee.clone(), "uusd".to_string()))
+        .unwrap();
+    assert_eq!(balance, Uint128::from(5u128));
+
+    // Exempt payees pay nothing and have any attached funds returned
+    let msg_exempt = ExecuteMsg::SetDiscount {
+        target: FeeTarget::Payee(AndrAddr::from_string("payee")),
+        discount: FeeDiscount::Exempt,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        msg_exempt,
+    )
+    .unwrap();
+    let discount: Option<FeeDiscount> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Discount {
+                target: FeeTarget::Payee(AndrAddr::from_string("payee")),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(discount, Some(FeeDiscount::Exempt));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[coin(10, "uusd")]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: payee.to_string(),
+            amount: vec![coin(10, "uusd")],
+        })]
+    );
+    assert_eq!(effective_fee(deps.as_ref()).amount, Uint128::zero());
+
+    // Removing the exemption restores the App discount
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("owner", &[]),
+        ExecuteMsg::RemoveDiscount {
+            target: FeeTarget::Payee(AndrAddr::from_string("payee")),
+        },
+    )
+    .unwrap();
+    assert_eq!(effective_fee(deps.as_ref()).amount, Uint128::from(5u128));
+}
+
+#[test]
+fn test_sponsor_allowance() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let payee = Addr::unchecked("payee");
+    let sponsor = Addr::unchecked("sponsor");
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (sponsor.clone(), "uusd".to_string()),
+            &Uint128::from(100u128),
+        )
+        .unwrap();
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(10u128),
+        )
+        .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sponsor.as_str(), &[]),
+        ExecuteMsg::GrantAllowance {
+            payee: AndrAddr::from_string("payee"),
+            asset: "uusd".to_string(),
+            limit: Uint128::from(15u128),
+            period: Some(3600),
+        },
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_ACTION.to_string(),
+        value: None,
+    };
+
+    // First fee is fully sponsored
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    // Second fee exhausts the allowance, the payee pays the rest
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+
+    let balance_of = |deps: Deps, addr: &Addr| {
+        BALANCES
+            .load(deps.storage, (addr.clone(), "uusd".to_string()))
+            .unwrap()
+    };
+    assert_eq!(balance_of(deps.as_ref(), &sponsor), Uint128::from(85u128));
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::from(5u128));
+
+    // The allowance resets once the period has elapsed
+    env.block.time = env.block.time.plus_seconds(3600);
+    let allowances: Vec<Allowance> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Allowances {
+                payee: AndrAddr::from_string("payee"),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(allowances.len(), 1);
+    assert_eq!(allowances[0].spent, Uint128::zero());
+    assert_eq!(allowances[0].period_start, env.block.time);
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), &sponsor), Uint128::from(75u128));
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::from(5u128));
+
+    // Revoked allowances are no longer used
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sponsor.as_str(), &[]),
+        ExecuteMsg::RevokeAllowance {
+            payee: AndrAddr::from_string("payee"),
+            asset: "uusd".to_string(),
+        },
+    )
+    .unwrap();
+    let err = execute(deps.as_mut(), env, mock_info("creator", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+}
+
+#[test]
+fn test_app_allowance() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let sponsor = Addr::unchecked("sponsor");
+    let users = [Addr::unchecked("user1"), Addr::unchecked("user2")];
+
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (sponsor.clone(), "uusd".to_string()),
+            &Uint128::from(100u128),
+        )
+        .unwrap();
+    for user in users.iter() {
+        BALANCES
+            .save(
+                deps.as_mut().storage,
+                (user.clone(), "uusd".to_string()),
+                &Uint128::from(10u128),
+            )
+            .unwrap();
+    }
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sponsor.as_str(), &[]),
+        ExecuteMsg::GrantAppAllowance {
+            app: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            asset: "uusd".to_string(),
+            limit: Uint128::from(15u128),
+            period: Some(86400),
+        },
+    )
+    .unwrap();
+
+    // Every user of the App draws from the same allowance until the limit is reached
+    for user in users.iter() {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            ExecuteMsg::PayFee {
+                payee: user.clone(),
+                action: MOCK_ACTION.to_string(),
+                value: None,
+            },
+        )
+        .unwrap();
+    }
+
+    let balance_of = |deps: Deps, addr: &Addr| {
+        BALANCES
+            .load(deps.storage, (addr.clone(), "uusd".to_string()))
+            .unwrap()
+    };
+    assert_eq!(balance_of(deps.as_ref(), &sponsor), Uint128::from(85u128));
+    assert_eq!(balance_of(deps.as_ref(), &users[0]), Uint128::from(10u128));
+    assert_eq!(balance_of(deps.as_ref(), &users[1]), Uint128::from(5u128));
+
+    let allowances: Vec<Allowance> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::AppAllowances {
+                app: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(allowances.len(), 1);
+    assert_eq!(allowances[0].spent, Uint128::from(15u128));
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sponsor.as_str(), &[]),
+        ExecuteMsg::RevokeAppAllowance {
+            app: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            asset: "uusd".to_string(),
+        },
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info(sponsor.as_str(), &[]),
+        ExecuteMsg::RevokeAppAllowance {
+            app: AndrAddr::from_string(MOCK_APP_CONTRACT),
+            asset: "uusd".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoAllowance {});
+}
+
+#[test]
+fn test_fee_reporting() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
+
+    for (payee, action) in [
+        ("payee1", MOCK_ACTION),
+        ("payee2", MOCK_ACTION),
+        ("payee1", MOCK_MULTI_ASSET_ACTION),
+    ] {
+        let msg = ExecuteMsg::PayFee {
+            payee: Addr::unchecked(payee),
+            action: action.to_string(),
+            value: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[coin(10, "uusd")]),
+            msg,
+        )
+        .unwrap();
+    }
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (publisher.clone(), "uandr".to_string()),
+            &Uint128::zero(),
+        )
+        .unwrap();
+
+    // Empty balances are not listed
+    let balances: Vec<AssetBalance> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Balances {
+                address: AndrAddr::from_string(MOCK_ADO_PUBLISHER),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let expected_balances = vec![AssetBalance {
+        asset: "uusd".to_string(),
+        amount: Uint128::from(30u128),
+    }];
+    assert_eq!(balances, expected_balances);
+
+    let history = |deps: Deps, filter: Option<FeeHistoryFilter>, start_after: Option<u64>| {
+        let receipts: Vec<FeeReceipt> = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::FeeHistory {
+                    filter,
+                    start_after,
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.receipt_id)
+            .collect::<Vec<u64>>()
+    };
+    assert_eq!(history(deps.as_ref(), None, None), vec![1]);
+    assert_eq!(history(deps.as_ref(), None, Some(1)), vec![2]);
+    let payee1 = Some(FeeHistoryFilter::Payee(AndrAddr::from_string("payee1")));
+    assert_eq!(history(deps.as_ref(), payee1.clone(), None), vec![1]);
+    assert_eq!(history(deps.as_ref(), payee1.clone(), Some(1)), vec![3]);
+    assert!(history(deps.as_ref(), payee1, Some(3)).is_empty());
+    let receiver = Some(FeeHistoryFilter::Receiver(AndrAddr::from_string(
+        MOCK_ADO_PUBLISHER,
+    )));
+    assert_eq!(history(deps.as_ref(), receiver, Some(2)), vec![3]);
+    let ado = Some(FeeHistoryFilter::Ado(AndrAddr::from_string("other_ado")));
+    assert!(history(deps.as_ref(), ado, None).is_empty());
+
+    let totals: Vec<FeeTotal> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FeeTotals {
+                ado_type: "ADOType".to_string(),
+                action: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        totals,
+        vec![
+            FeeTotal {
+                ado_type: "ADOType".to_string(),
+                action: MOCK_ACTION.to_string(),
+                asset: "uusd".to_string(),
+                amount: Uint128::from(20u128),
+            },
+            FeeTotal {
+                ado_type: "ADOType".to_string(),
+                action: MOCK_MULTI_ASSET_ACTION.to_string(),
+                asset: "uusd".to_string(),
+                amount: Uint128::from(10u128),
+            },
+        ]
+    );
+    let totals: Vec<FeeTotal> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FeeTotals {
+                ado_type: "ADOType".to_string(),
+                action: Some(MOCK_MULTI_ASSET_ACTION.to_string()),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(totals.len(), 1);
+
+    let revenue: RevenueResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::Revenue {
+                publisher: AndrAddr::from_string(MOCK_ADO_PUBLISHER),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        revenue,
+        RevenueResponse {
+            publisher: publisher.clone(),
+            receiver: publisher,
+            balances: expected_balances,
+        }
+    );
+}
+
+#[test]
+fn test_access_passes() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let payee = Addr::unchecked("payee");
+    let publisher = Addr::unchecked(MOCK_ADO_PUBLISHER);
+    let duration = 30 * 24 * 60 * 60;
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    // Only the publisher or owner can offer passes
+    let msg = ExecuteMsg::SetPassOffering {
+        ado_type: "ADOType".to_string(),
+        action: None,
+        asset: "uusd".to_string(),
+        price: Uint128::from(100u128),
+        duration,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_ADO_PUBLISHER, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let balance_of = |deps: Deps, addr: &Addr| {
+        BALANCES
+            .load(deps.storage, (addr.clone(), "uusd".to_string()))
+            .unwrap_or_default()
+    };
+    let active_passes = |deps: Deps, env: &Env, holder: &str| -> Vec<AccessPass> {
+        from_json(
+            query(
+                deps,
+                env.clone(),
+                QueryMsg::ActivePasses {
+                    holder: AndrAddr::from_string(holder),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    // The pass is paid from attached funds first and then the buyer's balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(50u128),
+        )
+        .unwrap();
+    let msg = ExecuteMsg::BuyPass {
+        ado_type: "ADOType".to_string(),
+        action: None,
+        holder: None,
+        auto_renew: true,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(payee.as_str(), &[coin(60, "uusd")]),
+        msg,
+    )
+    .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::from(10u128));
+    assert_eq!(
+        balance_of(deps.as_ref(), &publisher),
+        Uint128::from(100u128)
+    );
+    assert_eq!(
+        active_passes(deps.as_ref(), &env, "payee"),
+        vec![AccessPass {
+            holder: payee.clone(),
+            buyer: payee.clone(),
+            ado_type: "ADOType".to_string(),
+            action: None,
+            expires: env.block.time.plus_seconds(duration),
+            auto_renew: true,
+        }]
+    );
+
+    // Fees are not charged while the pass is valid
+    let pay_fee = ExecuteMsg::PayFee {
+        payee: payee.clone(),
+        action: MOCK_ACTION.to_string(),
+        value: None,
+    };
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        pay_fee.clone(),
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("pass_holder", "payee")));
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::from(10u128));
+
+    // Expired passes that cannot be renewed no longer apply
+    env.block.time = env.block.time.plus_seconds(duration);
+    assert!(active_passes(deps.as_ref(), &env, "payee").is_empty());
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        pay_fee.clone(),
+    )
+    .unwrap();
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::zero());
+    assert_eq!(
+        balance_of(deps.as_ref(), &publisher),
+        Uint128::from(110u128)
+    );
+
+    // Expired passes are renewed from the buyer's balance
+    BALANCES
+        .save(
+            deps.as_mut().storage,
+            (payee.clone(), "uusd".to_string()),
+            &Uint128::from(100u128),
+        )
+        .unwrap();
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        pay_fee,
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("pass_holder", "payee")));
+    assert_eq!(balance_of(deps.as_ref(), &payee), Uint128::zero());
+    assert_eq!(
+        balance_of(deps.as_ref(), &publisher),
+        Uint128::from(210u128)
+    );
+    assert_eq!(
+        active_passes(deps.as_ref(), &env, "payee")[0].expires,
+        env.block.time.plus_seconds(duration)
+    );
+
+    // Only the buyer can change renewal
+    let msg = ExecuteMsg::SetPassAutoRenew {
+        ado_type: "ADOType".to_string(),
+        action: None,
+        holder: AndrAddr::from_string("payee"),
+        auto_renew: false,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(payee.as_str(), &[]),
+        msg,
+    )
+    .unwrap();
+
+    // Passes held by the App cover all of its payees
+    let msg = ExecuteMsg::BuyPass {
+        ado_type: "ADOType".to_string(),
+        action: None,
+        holder: Some(AndrAddr::from_string(MOCK_APP_CONTRACT)),
+        auto_renew: false,
+    };
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("app_buyer", &[coin(150, "uusd")]),
+        msg,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: "app_buyer".to_string(),
+            amount: vec![coin(50, "uusd")],
+        })]
+    );
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("creator", &[]),
+        ExecuteMsg::PayFee {
+            payee: Addr::unchecked("payee2"),
+            action: MOCK_ACTION.to_string(),
+            value: None,
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("pass_holder", MOCK_APP_CONTRACT)));
+}
+
 #[test]
 fn test_withdraw() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -342,6 +1289,7 @@ fn test_withdraw() {
     let msg = ExecuteMsg::Withdraw {
         amount: None,
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -377,6 +1325,7 @@ fn test_withdraw() {
     let msg = ExecuteMsg::Withdraw {
         amount: Some(Uint128::from(10u128)),
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -394,6 +1343,7 @@ fn test_withdraw() {
     let msg = ExecuteMsg::Withdraw {
         amount: Some(Uint128::from(5u128)),
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -420,6 +1370,119 @@ fn test_withdraw() {
     assert_eq!(balance, Uint128::from(5u128));
 }
 
+#[test]
+fn test_withdraw_to_recipient() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+            owner: None,
+        },
+    )
+    .unwrap();
+
+    for (asset, amount) in [("uusd", 10u128), ("uandr", 5u128), ("cw20asset", 20u128)] {
+        BALANCES
+            .save(
+                deps.as_mut().storage,
+                (info.sender.clone(), asset.to_string()),
+                &Uint128::from(amount),
+            )
+            .unwrap();
+    }
+
+    // CW20 tokens cannot be sent to another chain
+    let remote_recipient = Recipient::from_string("ibc://chain/recipient");
+    let msg = ExecuteMsg::WithdrawCW20 {
+        amount: None,
+        asset: "cw20asset".to_string(),
+        recipient: Some(remote_recipient.clone()),
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::UnsupportedProtocol {});
+
+    // Native funds are routed via the Kernel in a single message
+    let msg = ExecuteMsg::BatchWithdraw {
+        withdrawals: vec![
+            AssetWithdrawal {
+                asset: WithdrawAsset::Native("uusd".to_string()),
+                amount: None,
+            },
+            AssetWithdrawal {
+                asset: WithdrawAsset::Native("uandr".to_string()),
+                amount: Some(Uint128::from(2u128)),
+            },
+        ],
+        recipient: Some(remote_recipient.clone()),
+    };
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let funds = vec![coin(10, "uusd"), coin(2, "uandr")];
+    let pkt = AMPPkt::new(
+        env.contract.address.clone(),
+        env.contract.address.clone(),
+        vec![remote_recipient.generate_amp_msg(Some(funds.clone()))],
+    );
+    assert_eq!(
+        res.messages,
+        vec![pkt
+            .to_sub_msg(MOCK_KERNEL_CONTRACT, Some(funds), 1)
+            .unwrap()]
+    );
+    assert!(res
+        .attributes
+        .contains(&attr("recipient", "ibc://chain/recipient")));
+
+    // CW20 funds are sent directly when no recipient is provided
+    let msg = ExecuteMsg::BatchWithdraw {
+        withdrawals: vec![
+            AssetWithdrawal {
+                asset: WithdrawAsset::Native("uandr".to_string()),
+                amount: None,
+            },
+            AssetWithdrawal {
+                asset: WithdrawAsset::Cw20("cw20asset".to_string()),
+                amount: Some(Uint128::from(15u128)),
+            },
+        ],
+        recipient: None,
+    };
+    let res = execute(deps.as_mut(), env, info.clone(), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            cw20_withdraw_msg(Uint128::from(15u128), "cw20asset", info.sender.clone()),
+            SubMsg::new(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: vec![coin(3, "uandr")],
+            }),
+        ]
+    );
+
+    let balances: Vec<AssetBalance> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Balances {
+                address: AndrAddr::from_string("creator"),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        balances,
+        vec![AssetBalance {
+            asset: "cw20asset".to_string(),
+            amount: Uint128::from(5u128),
+        }]
+    );
+}
+
 fn cw20_deposit_msg(
     sender: impl Into<String>,
     amount: Uint128,
@@ -493,6 +1556,7 @@ fn test_withdraw_cw20() {
     let msg = ExecuteMsg::WithdrawCW20 {
         amount: None,
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -530,6 +1594,7 @@ fn test_withdraw_cw20() {
     let msg = ExecuteMsg::WithdrawCW20 {
         amount: Some(Uint128::from(10u128)),
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -547,6 +1612,7 @@ fn test_withdraw_cw20() {
     let msg = ExecuteMsg::WithdrawCW20 {
         amount: Some(Uint128::from(5u128)),
         asset: asset.to_string(),
+        recipient: None,
     };
 
     BALANCES
@@ -5,12 +5,15 @@ use andromeda_std::amp::{ADO_DB_KEY, VFS_KEY};
 
 use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::{
+    ADOVersion, MigrationPath, QueryMsg as ADODBQueryMsg, ResolvedVersion,
+};
 use andromeda_std::os::aos_querier::AOSQuerier;
 use andromeda_std::os::kernel::{ChannelInfo, IbcExecuteMsg, InternalMsg};
 
 use andromeda_std::os::vfs::vfs_resolve_symlink;
 use cosmwasm_std::{
-    attr, ensure, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, DepsMut, Env, IbcMsg,
+    attr, ensure, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, DepsMut, Empty, Env, IbcMsg,
     MessageInfo, Response, StdError, SubMsg, WasmMsg,
 };
 
@@ -161,8 +164,10 @@ pub fn create(
             ado_owner.get_raw_address_from_vfs(&execute_env.deps.as_ref(), vfs_addr)?;
         let code_id =
             AOSQuerier::code_id_getter(&execute_env.deps.querier, &adodb_addr, &ado_type)?;
+        AOSQuerier::verify_code_id_not_yanked(&execute_env.deps.querier, &adodb_addr, code_id)?;
+        // The kernel is the wasm admin so it can migrate the ADO on behalf of its owner
         let wasm_msg = WasmMsg::Instantiate {
-            admin: Some(owner_addr.to_string()),
+            admin: Some(execute_env.env.contract.address.to_string()),
             code_id,
             msg,
             funds: vec![],
@@ -186,6 +191,82 @@ pub fn create(
     }
 }
 
+pub fn migrate_ado(
+    execute_env: ExecuteContext,
+    address: AndrAddr,
+    target_version: String,
+) -> Result<Response, ContractError> {
+    let deps = execute_env.deps;
+    let vfs_addr = KERNEL_ADDRESSES.load(deps.storage, VFS_KEY)?;
+    let adodb_addr = KERNEL_ADDRESSES.load(deps.storage, ADO_DB_KEY)?;
+    let ado_addr = address.get_raw_address_from_vfs(&deps.as_ref(), vfs_addr)?;
+
+    // ADOs whose ownership has been renounced can no longer be migrated
+    let ado_owner =
+        AOSQuerier::ado_owner_getter(&deps.querier, &ado_addr)?.ok_or(ContractError::NoOwner {
+            address: ado_addr.to_string(),
+        })?;
+    ensure!(
+        ado_owner == execute_env.info.sender,
+        ContractError::Unauthorized {}
+    );
+
+    let contract_info = deps.querier.query_wasm_contract_info(&ado_addr)?;
+    ensure!(
+        contract_info.admin == Some(execute_env.env.contract.address.to_string()),
+        ContractError::KernelNotAdmin {
+            address: ado_addr.to_string()
+        }
+    );
+
+    let current_version =
+        AOSQuerier::ado_type_getter(&deps.querier, &adodb_addr, contract_info.code_id)?
+            .map(ADOVersion::from_string)
+            .ok_or(ContractError::InvalidADOVersion {
+                msg: Some("ADO code ID is not published in the ADODB".to_string()),
+            })?;
+
+    let target: ResolvedVersion = deps.querier.query_wasm_smart(
+        &adodb_addr,
+        &ADODBQueryMsg::ResolveVersion {
+            ado_version: current_version.with_version(target_version).into_string(),
+        },
+    )?;
+    AOSQuerier::verify_code_id_not_yanked(&deps.querier, &adodb_addr, target.code_id)?;
+    let target_version = ADOVersion::from_string(&target.ado_version);
+
+    let migration_path: Option<MigrationPath> = deps.querier.query_wasm_smart(
+        &adodb_addr,
+        &ADODBQueryMsg::MigrationPath {
+            ado_type: current_version.get_type(),
+            from_version: current_version.get_version(),
+            to_version: target_version.get_version(),
+        },
+    )?;
+    let migration_path = migration_path.ok_or(ContractError::NoMigrationPath {
+        from: current_version.clone().into_string(),
+        to: target.ado_version.clone(),
+    })?;
+
+    let migrate_msg = match migration_path.migrate_msg {
+        Some(msg) => msg,
+        None => to_json_binary(&Empty {})?,
+    };
+
+    Ok(Response::default()
+        .add_message(WasmMsg::Migrate {
+            contract_addr: ado_addr.to_string(),
+            new_code_id: target.code_id,
+            msg: migrate_msg,
+        })
+        .add_attributes(vec![
+            attr("action", "migrate_ado"),
+            attr("address", ado_addr),
+            attr("from", current_version.into_string()),
+            attr("to", target.ado_version),
+        ]))
+}
+
 pub fn internal(env: ExecuteContext, msg: InternalMsg) -> Result<Response, ContractError> {
     match msg {
         InternalMsg::RegisterUserCrossChain {
@@ -400,10 +481,10 @@ impl MsgHandler {
                     attr(format!("bank_send_amount:{sequence}"), funds[0].to_string()),
                 ]);
         } else {
-            let origin = if let Some(amp_ctx) = ctx {
-                amp_ctx.ctx.get_origin()
+            let (origin, merkle_proof) = if let Some(amp_ctx) = ctx {
+                (amp_ctx.ctx.get_origin(), amp_ctx.ctx.get_merkle_proof())
             } else {
-                info.sender.to_string()
+                (info.sender.to_string(), None)
             };
             let previous_sender = info.sender.to_string();
 
@@ -413,7 +494,11 @@ impl MsgHandler {
                 Some(vec![funds[0].clone()]),
             );
 
-            let new_packet = AMPPkt::new(origin, previous_sender, vec![amp_msg]);
+            let mut new_packet = AMPPkt::new(origin, previous_sender, vec![amp_msg]);
+            // Proofs of the origin's merkle membership are forwarded for permissioned actions
+            if let Some(proof) = merkle_proof {
+                new_packet.ctx = new_packet.ctx.with_merkle_proof(proof);
+            }
 
             let sub_msg = new_packet.to_sub_msg(
                 recipient_addr.clone(),
@@ -132,15 +132,14 @@ pub fn do_ibc_packet_receive(
         ContractError::Unauthorized {}
     );
     let msg: IbcExecuteMsg = from_json(&msg.packet.data)?;
-    let execute_env = ExecuteContext {
-        env,
+    let execute_env = ExecuteContext::new(
         deps,
-        info: MessageInfo {
+        MessageInfo {
             funds: vec![],
             sender: Addr::unchecked("foreign_kernel"),
         },
-        amp_ctx: None,
-    };
+        env,
+    );
     match msg {
         IbcExecuteMsg::SendMessage { recipient, message } => {
             let amp_msg = AMPMsg::new(recipient, message, None);
@@ -16,7 +16,7 @@ pub fn verify_address(deps: Deps, address: String) -> Result<bool, ContractError
     let contract_info_res = deps.querier.query_wasm_contract_info(address);
     if let Ok(contract_info) = contract_info_res {
         let ado_type =
-            AOSQuerier::ado_type_getter_smart(&deps.querier, &db_address, contract_info.code_id)?;
+            AOSQuerier::ado_type_getter(&deps.querier, &db_address, contract_info.code_id)?;
         Ok(ado_type.is_some())
     } else {
         Ok(false)
@@ -35,7 +35,7 @@ pub fn on_reply_create_ado(deps: DepsMut, env: Env, msg: Reply) -> Result<Respon
     let curr_owner =
         AOSQuerier::ado_owner_getter(&deps.querier, &Addr::unchecked(ado_addr.clone()))?;
     let mut res = Response::default();
-    if curr_owner == env.contract.address {
+    if curr_owner.as_ref() == Some(&env.contract.address) {
         let msg = AndromedaMsg::UpdateOwner {
             address: new_owner.to_string(),
         };
@@ -58,7 +58,9 @@ pub fn on_reply_ibc_hooks_packet_send(
     msg: Reply,
 ) -> Result<Response, ContractError> {
     let SubMsgResult::Ok(SubMsgResponse { data: Some(b), .. }) = msg.result else {
-        return Err(ContractError::InvalidPacket { error: Some(format!("ibc hooks: failed reply: {:?}", msg.result)) })
+        return Err(ContractError::InvalidPacket {
+            error: Some(format!("ibc hooks: failed reply: {:?}", msg.result)),
+        });
     };
 
     let MsgTransferResponse { sequence } =
@@ -8,12 +8,13 @@ use andromeda_std::os::{
     vfs::{validate_component_name, validate_username},
 };
 use cosmwasm_std::{
-    attr, ensure, to_json_binary, Addr, DepsMut, Env, MessageInfo, Response, SubMsg, WasmMsg,
+    attr, ensure, to_json_binary, Addr, Api, DepsMut, Env, MessageInfo, Response, Storage, SubMsg,
+    WasmMsg,
 };
 
 use crate::state::{
-    add_path_symlink, add_pathname, paths, resolve_pathname, ADDRESS_LIBRARY, ADDRESS_USERNAME,
-    LIBRARIES, USERS,
+    add_path_symlink, add_pathname, follow_path, paths, resolve_pathname, ResolutionContext,
+    ADDRESS_LIBRARY, ADDRESS_USERNAME, LIBRARIES, MAX_SYMLINK_DEPTH, USERS,
 };
 
 pub struct ExecuteEnv<'a> {
@@ -76,6 +77,12 @@ pub fn add_symlink(
         name.clone(),
         symlink.clone(),
     )?;
+    ensure_no_symlink_cycle(
+        env.deps.storage,
+        env.deps.api,
+        parent_addr.clone(),
+        name.clone(),
+    )?;
     Ok(Response::default().add_attributes(vec![
         attr("action", "add_symlink"),
         attr("symlink", symlink),
@@ -84,6 +91,24 @@ pub fn add_symlink(
     ]))
 }
 
+/// Resolves a newly added symlink to ensure it does not create a cycle or exceed the maximum depth.
+///
+/// Symlinks to paths that do not exist yet are still allowed.
+fn ensure_no_symlink_cycle(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    parent_addr: Addr,
+    name: String,
+) -> Result<(), ContractError> {
+    let info = paths().load(storage, &(parent_addr, name))?;
+    let mut ctx = ResolutionContext::load(storage)?;
+    match follow_path(storage, api, info, &mut ctx) {
+        Err(err @ ContractError::SymlinkCycle { .. })
+        | Err(err @ ContractError::SymlinkDepthExceeded { .. }) => Err(err),
+        _ => Ok(()),
+    }
+}
+
 pub fn add_parent_path(
     env: ExecuteEnv,
     name: String,
@@ -211,3 +236,17 @@ pub fn register_user_cross_chain(
         ])
         .add_submessage(sub_msg))
 }
+
+pub fn update_max_symlink_depth(env: ExecuteEnv, depth: u32) -> Result<Response, ContractError> {
+    ensure!(
+        ADOContract::default().is_contract_owner(env.deps.storage, env.info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(depth > 0, ContractError::InvalidSymlinkDepth {});
+    MAX_SYMLINK_DEPTH.save(env.deps.storage, &depth)?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_max_symlink_depth"),
+        attr("depth", depth.to_string()),
+    ]))
+}
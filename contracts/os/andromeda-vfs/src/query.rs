@@ -1,16 +1,37 @@
-use andromeda_std::os::vfs::validate_path_name;
+use andromeda_std::os::vfs::{validate_path_name, ResolvePathTraceResponse};
 use andromeda_std::{amp::AndrAddr, error::ContractError};
 use cosmwasm_std::{Addr, Deps};
 
 use crate::state::{
-    get_paths, get_subdir, resolve_pathname, resolve_symlink, PathInfo, ADDRESS_LIBRARY,
-    ADDRESS_USERNAME,
+    get_paths, get_subdir, resolve_pathname, resolve_pathname_with_context, resolve_symlink,
+    PathInfo, ResolutionContext, ADDRESS_LIBRARY, ADDRESS_USERNAME, DEFAULT_MAX_SYMLINK_DEPTH,
+    MAX_SYMLINK_DEPTH,
 };
 
 pub fn resolve_path(deps: Deps, path: AndrAddr) -> Result<Addr, ContractError> {
     validate_path_name(path.to_string())?;
     resolve_pathname(deps.storage, deps.api, path)
 }
+
+pub fn resolve_path_trace(
+    deps: Deps,
+    path: AndrAddr,
+) -> Result<ResolvePathTraceResponse, ContractError> {
+    validate_path_name(path.to_string())?;
+    let mut ctx = ResolutionContext::load(deps.storage)?;
+    let address = resolve_pathname_with_context(deps.storage, deps.api, path, &mut ctx)?;
+    Ok(ResolvePathTraceResponse {
+        address,
+        hops: ctx.hops,
+    })
+}
+
+pub fn max_symlink_depth(deps: Deps) -> Result<u32, ContractError> {
+    Ok(MAX_SYMLINK_DEPTH
+        .may_load(deps.storage)?
+        .unwrap_or(DEFAULT_MAX_SYMLINK_DEPTH))
+}
+
 pub fn subdir(deps: Deps, path: AndrAddr) -> Result<Vec<PathInfo>, ContractError> {
     validate_path_name(path.to_string())?;
     get_subdir(deps.storage, deps.api, path)
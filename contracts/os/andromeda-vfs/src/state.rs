@@ -1,6 +1,6 @@
-use andromeda_std::{amp::AndrAddr, error::ContractError};
+use andromeda_std::{amp::AndrAddr, error::ContractError, os::vfs::PathHop};
 use cosmwasm_std::{ensure, Addr, Api, StdError, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -47,6 +47,35 @@ pub const LIBRARIES: Map<&str, Addr> = Map::new("libraries");
 pub const ADDRESS_USERNAME: Map<&str, String> = Map::new("address_username");
 pub const ADDRESS_LIBRARY: Map<&str, String> = Map::new("address_library");
 
+/// The maximum number of nested symlinks followed when resolving a single path
+pub const DEFAULT_MAX_SYMLINK_DEPTH: u32 = 10;
+pub const MAX_SYMLINK_DEPTH: Item<u32> = Item::new("max_symlink_depth");
+
+/// Tracks the symlinks being followed while resolving a path, along with every visited component
+pub struct ResolutionContext {
+    max_depth: u32,
+    visiting: Vec<(Addr, String)>,
+    pub hops: Vec<PathHop>,
+}
+
+impl ResolutionContext {
+    pub fn new(max_depth: u32) -> ResolutionContext {
+        ResolutionContext {
+            max_depth,
+            visiting: vec![],
+            hops: vec![],
+        }
+    }
+
+    /// Creates a context using the maximum symlink depth stored in the contract
+    pub fn load(storage: &dyn Storage) -> Result<ResolutionContext, ContractError> {
+        let max_depth = MAX_SYMLINK_DEPTH
+            .may_load(storage)?
+            .unwrap_or(DEFAULT_MAX_SYMLINK_DEPTH);
+        Ok(ResolutionContext::new(max_depth))
+    }
+}
+
 pub fn split_pathname(path: String) -> Vec<String> {
     path.split('/')
         .filter(|string| !string.is_empty())
@@ -58,6 +87,16 @@ pub fn resolve_pathname(
     storage: &dyn Storage,
     api: &dyn Api,
     pathname: AndrAddr,
+) -> Result<Addr, ContractError> {
+    let mut ctx = ResolutionContext::load(storage)?;
+    resolve_pathname_with_context(storage, api, pathname, &mut ctx)
+}
+
+pub fn resolve_pathname_with_context(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    pathname: AndrAddr,
+    ctx: &mut ResolutionContext,
 ) -> Result<Addr, ContractError> {
     // As cross-chain queries are not currently possible we need to ensure the pathname being resolved is local
     ensure!(
@@ -67,8 +106,8 @@ pub fn resolve_pathname(
 
     if pathname.is_vfs_path() {
         match pathname.get_root_dir() {
-            "home" => resolve_home_path(storage, api, pathname),
-            "lib" => resolve_lib_path(storage, api, pathname),
+            "home" => resolve_home_path(storage, api, pathname, ctx),
+            "lib" => resolve_lib_path(storage, api, pathname, ctx),
             &_ => Err(ContractError::InvalidAddress {}),
         }
     } else {
@@ -80,6 +119,7 @@ fn resolve_home_path(
     storage: &dyn Storage,
     api: &dyn Api,
     pathname: AndrAddr,
+    ctx: &mut ResolutionContext,
 ) -> Result<Addr, ContractError> {
     let mut parts = split_pathname(pathname.to_string());
 
@@ -94,13 +134,14 @@ fn resolve_home_path(
         Err(_e) => USERS.load(storage, username_or_address)?,
     };
 
-    resolve_path(storage, api, parts, user_address)
+    resolve_path(storage, api, parts, user_address, ctx)
 }
 
 fn resolve_lib_path(
     storage: &dyn Storage,
     api: &dyn Api,
     pathname: AndrAddr,
+    ctx: &mut ResolutionContext,
 ) -> Result<Addr, ContractError> {
     let mut parts = split_pathname(pathname.to_string());
 
@@ -115,7 +156,7 @@ fn resolve_lib_path(
         Err(_e) => LIBRARIES.load(storage, username_or_address)?,
     };
 
-    resolve_path(storage, api, parts, user_address)
+    resolve_path(storage, api, parts, user_address, ctx)
 }
 
 fn resolve_path(
@@ -123,6 +164,7 @@ fn resolve_path(
     api: &dyn Api,
     parts: Vec<String>,
     user_address: Addr,
+    ctx: &mut ResolutionContext,
 ) -> Result<Addr, ContractError> {
     let mut address = user_address;
     for (idx, part) in parts.iter().enumerate() {
@@ -131,15 +173,57 @@ fn resolve_path(
             continue;
         }
         let info = paths().load(storage, &(address, part.clone()))?;
-        address = match info.symlink {
-            Some(symlink) => resolve_pathname(storage, api, symlink)?,
-            None => info.address,
-        };
+        address = follow_path(storage, api, info, ctx)?;
     }
 
     Ok(address)
 }
 
+/// Returns the address for the given path component, following its symlink if it has one.
+///
+/// Errors if the symlink is already being followed (a cycle) or if following it would exceed the maximum depth.
+pub fn follow_path(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    info: PathInfo,
+    ctx: &mut ResolutionContext,
+) -> Result<Addr, ContractError> {
+    let hop_idx = ctx.hops.len();
+    ctx.hops.push(PathHop {
+        depth: ctx.visiting.len() as u32,
+        parent_address: info.parent_address.clone(),
+        name: info.name.clone(),
+        address: info.address.clone(),
+        symlink: info.symlink.clone(),
+    });
+
+    match info.symlink {
+        None => Ok(info.address),
+        Some(symlink) => {
+            let key = (info.parent_address, info.name);
+            ensure!(
+                !ctx.visiting.contains(&key),
+                ContractError::SymlinkCycle {
+                    path: symlink.to_string()
+                }
+            );
+            ensure!(
+                (ctx.visiting.len() as u32) < ctx.max_depth,
+                ContractError::SymlinkDepthExceeded {
+                    max_depth: ctx.max_depth
+                }
+            );
+
+            ctx.visiting.push(key);
+            let address = resolve_pathname_with_context(storage, api, symlink, ctx)?;
+            ctx.visiting.pop();
+
+            ctx.hops[hop_idx].address = address.clone();
+            Ok(address)
+        }
+    }
+}
+
 pub fn get_subdir(
     storage: &dyn Storage,
     api: &dyn Api,
@@ -305,6 +389,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -313,6 +398,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("~{username}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -321,6 +407,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("~/{username}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -342,6 +429,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}/{first_directory}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -368,6 +456,7 @@ mod test {
             AndrAddr::from_string(format!(
                 "/home/{username}/{first_directory}/{second_directory}"
             )),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, second_directory_address);
@@ -391,6 +480,7 @@ mod test {
             AndrAddr::from_string(format!(
                 "/home/{username}/{first_directory}/{second_directory}/{file}"
             )),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, file_address)
@@ -417,6 +507,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/lib/{lib_name}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, username_address);
@@ -438,6 +529,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/lib/{lib_name}/{first_directory}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -464,6 +556,7 @@ mod test {
             AndrAddr::from_string(format!(
                 "/lib/{lib_name}/{first_directory}/{second_directory}"
             )),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, second_directory_address);
@@ -487,6 +580,7 @@ mod test {
             AndrAddr::from_string(format!(
                 "/lib/{lib_name}/{first_directory}/{second_directory}/{file}"
             )),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, file_address)
@@ -522,6 +616,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{username}/{first_directory}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -541,6 +636,7 @@ mod test {
             deps.as_ref().storage,
             deps.as_ref().api,
             AndrAddr::from_string(format!("/home/{symlink_parent}/{symlink_name}")),
+            &mut ResolutionContext::new(DEFAULT_MAX_SYMLINK_DEPTH),
         )
         .unwrap();
         assert_eq!(res, first_directory_address);
@@ -7,7 +7,7 @@ use andromeda_std::{
     amp::AndrAddr,
     os::{
         kernel::{ExecuteMsg as KernelExecuteMsg, InternalMsg},
-        vfs::{ExecuteMsg, InstantiateMsg},
+        vfs::{ExecuteMsg, InstantiateMsg, PathHop, ResolvePathTraceResponse},
     },
     testing::mock_querier::{
         mock_dependencies_custom, MOCK_FAKE_KERNEL_CONTRACT, MOCK_KERNEL_CONTRACT,
@@ -370,6 +370,183 @@ fn test_add_symlink() {
     assert_eq!(resolved_addr, component_addr);
 }
 
+#[test]
+fn test_add_symlink_cycle() {
+    let mut deps = mock_dependencies();
+    let username = "u1";
+    let sender = "sender";
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    USERS
+        .save(deps.as_mut().storage, username, &Addr::unchecked(sender))
+        .unwrap();
+
+    // Symlink pointing at itself
+    let msg = ExecuteMsg::AddSymlink {
+        name: "self".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/self")),
+        parent_address: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SymlinkCycle {
+            path: format!("/home/{username}/self")
+        }
+    );
+
+    // Dangling symlinks are allowed
+    let msg = ExecuteMsg::AddSymlink {
+        name: "first".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/second")),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // Completing the loop is not
+    let msg = ExecuteMsg::AddSymlink {
+        name: "second".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/first")),
+        parent_address: None,
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::SymlinkCycle { .. }));
+}
+
+#[test]
+fn test_symlink_max_depth() {
+    let mut deps = mock_dependencies();
+    let username = "u1";
+    let sender = "sender";
+    let component_addr = Addr::unchecked("f1addr");
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    USERS
+        .save(deps.as_mut().storage, username, &Addr::unchecked(sender))
+        .unwrap();
+
+    let msg = ExecuteMsg::AddPath {
+        name: "f1".to_string(),
+        address: component_addr.clone(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::AddSymlink {
+        name: "link1".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/f1")),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::AddSymlink {
+        name: "link2".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/link1")),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateMaxSymlinkDepth { depth: 1 };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not_owner", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateMaxSymlinkDepth { depth: 0 };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidSymlinkDepth {});
+
+    let resolved_addr = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("/home/{username}/link1")),
+    )
+    .unwrap();
+    assert_eq!(resolved_addr, component_addr);
+
+    let err = resolve_pathname(
+        deps.as_ref().storage,
+        deps.as_ref().api,
+        AndrAddr::from_string(format!("/home/{username}/link2")),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::SymlinkDepthExceeded { max_depth: 1 });
+
+    let msg = ExecuteMsg::AddSymlink {
+        name: "link3".to_string(),
+        symlink: AndrAddr::from_string(format!("/home/{username}/link2")),
+        parent_address: None,
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::SymlinkDepthExceeded { max_depth: 1 });
+}
+
+#[test]
+fn test_resolve_path_trace() {
+    let mut deps = mock_dependencies();
+    let username = "u1";
+    let sender = "sender";
+    let component_addr = Addr::unchecked("f1addr");
+    let info = mock_info(sender, &[]);
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+    USERS
+        .save(deps.as_mut().storage, username, &Addr::unchecked(sender))
+        .unwrap();
+
+    let msg = ExecuteMsg::AddPath {
+        name: "f1".to_string(),
+        address: component_addr.clone(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let symlink = AndrAddr::from_string(format!("/home/{username}/f1"));
+    let msg = ExecuteMsg::AddSymlink {
+        name: "link".to_string(),
+        symlink: symlink.clone(),
+        parent_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let query_msg = QueryMsg::ResolvePathTrace {
+        path: AndrAddr::from_string(format!("/home/{username}/link")),
+    };
+    let res: ResolvePathTraceResponse =
+        from_json(query(deps.as_ref(), env, query_msg).unwrap()).unwrap();
+
+    assert_eq!(res.address, component_addr);
+    assert_eq!(
+        res.hops,
+        vec![
+            PathHop {
+                depth: 0,
+                parent_address: Addr::unchecked(sender),
+                name: "link".to_string(),
+                address: component_addr.clone(),
+                symlink: Some(symlink),
+            },
+            PathHop {
+                depth: 1,
+                parent_address: Addr::unchecked(sender),
+                name: "f1".to_string(),
+                address: component_addr,
+                symlink: None,
+            },
+        ]
+    );
+}
+
 #[test]
 fn test_add_parent_path() {
     let mut deps = mock_dependencies();
@@ -1,4 +1,7 @@
-use andromeda_std::{amp::AndrAddr, andr_exec, andr_instantiate, andr_query, error::ContractError};
+use andromeda_std::{
+    ado_base::metadata::AdoMetadata, amp::AndrAddr, andr_exec, andr_instantiate, andr_query,
+    error::ContractError,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{to_json_binary, Binary, Deps};
 use serde::Serialize;
@@ -100,6 +103,9 @@ pub enum ExecuteMsg {
     AddAppComponent {
         component: AppComponent,
     },
+    /// Proposes the new owner, defaulting to the sender, as the owner of the App's components.
+    ///
+    /// The new owner must accept ownership of each component with `AcceptOwnership {}`.
     ClaimOwnership {
         name: Option<String>,
         new_owner: Option<AndrAddr>,
@@ -114,6 +120,14 @@ pub enum ExecuteMsg {
     },
     // Only available to the app contract itself
     AssignAppToComponents {},
+    /// Pauses the given actions on every component, pauses every action if none are provided
+    PauseComponents {
+        actions: Option<Vec<String>>,
+    },
+    /// Unpauses the given actions on every component, unpauses every action if none are provided
+    UnpauseComponents {
+        actions: Option<Vec<String>>,
+    },
 }
 
 #[cw_serde]
@@ -129,7 +143,7 @@ pub enum QueryMsg {
     GetComponents {},
     #[returns(bool)]
     ComponentExists { name: String },
-    #[returns(Vec<AppComponent>)]
+    #[returns(Vec<ComponentAddress>)]
     GetAddressesWithNames {},
     #[returns(ConfigResponse)]
     Config {},
@@ -145,6 +159,8 @@ pub struct ConfigResponse {
 pub struct ComponentAddress {
     pub name: String,
     pub address: String,
+    /// The component's metadata, `None` if the component could not be queried for it
+    pub metadata: Option<AdoMetadata>,
 }
 
 #[cfg(test)]
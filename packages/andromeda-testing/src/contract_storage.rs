@@ -0,0 +1,117 @@
+use cosmwasm_std::{storage_keys::to_length_prefixed_nested, Addr, Order, Record, Storage};
+
+/// Returns the namespace multi-test stores the given contract's state under
+fn contract_namespace(contract: &Addr) -> Vec<u8> {
+    let namespace = [b"contract_data/".as_slice(), contract.as_bytes()].concat();
+    to_length_prefixed_nested(&[b"wasm", &namespace])
+}
+
+fn range_with_namespace<'a>(
+    storage: &'a dyn Storage,
+    namespace: &[u8],
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+    order: Order,
+) -> Box<dyn Iterator<Item = Record> + 'a> {
+    let start = [namespace, start.unwrap_or_default()].concat();
+    let end = match end {
+        Some(end) => [namespace, end].concat(),
+        None => {
+            // Increment the last byte of the namespace to bound the range to the namespace
+            let mut end = namespace.to_vec();
+            for i in (0..end.len()).rev() {
+                if end[i] == u8::MAX {
+                    end[i] = 0;
+                } else {
+                    end[i] += 1;
+                    break;
+                }
+            }
+            end
+        }
+    };
+    let len = namespace.len();
+    Box::new(
+        storage
+            .range(Some(&start), Some(&end), order)
+            .map(move |(key, value)| (key[len..].to_vec(), value)),
+    )
+}
+
+/// A contract's storage within a multi-test app
+pub(crate) struct ContractStorage<'a> {
+    storage: &'a mut dyn Storage,
+    namespace: Vec<u8>,
+}
+
+impl<'a> ContractStorage<'a> {
+    pub(crate) fn new(storage: &'a mut dyn Storage, contract: &Addr) -> Self {
+        Self {
+            storage,
+            namespace: contract_namespace(contract),
+        }
+    }
+}
+
+impl<'a> Storage for ContractStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.storage.get(&[self.namespace.as_slice(), key].concat())
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        range_with_namespace(self.storage, &self.namespace, start, end, order)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.storage
+            .set(&[self.namespace.as_slice(), key].concat(), value)
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.storage
+            .remove(&[self.namespace.as_slice(), key].concat())
+    }
+}
+
+/// A contract's storage within a multi-test app that panics if written to
+pub(crate) struct ReadonlyContractStorage<'a> {
+    storage: &'a dyn Storage,
+    namespace: Vec<u8>,
+}
+
+impl<'a> ReadonlyContractStorage<'a> {
+    pub(crate) fn new(storage: &'a dyn Storage, contract: &Addr) -> Self {
+        Self {
+            storage,
+            namespace: contract_namespace(contract),
+        }
+    }
+}
+
+impl<'a> Storage for ReadonlyContractStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.storage.get(&[self.namespace.as_slice(), key].concat())
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        range_with_namespace(self.storage, &self.namespace, start, end, order)
+    }
+
+    fn set(&mut self, _key: &[u8], _value: &[u8]) {
+        panic!("Cannot write to read only contract storage")
+    }
+
+    fn remove(&mut self, _key: &[u8]) {
+        panic!("Cannot write to read only contract storage")
+    }
+}
@@ -1,4 +1,5 @@
 mod adodb;
+mod contract_storage;
 mod economics;
 mod kernel;
 mod vfs;
@@ -1,10 +1,15 @@
 use core::fmt;
 
-use andromeda_std::ado_base::{ownership::ContractOwnerResponse, AndromedaQuery};
-use cosmwasm_std::{Addr, Coin};
+use andromeda_std::ado_base::{
+    metadata::AdoMetadata, ownership::ContractOwnerResponse, version::MigrationInfo, AndromedaMsg,
+    AndromedaQuery,
+};
+use cosmwasm_std::{Addr, Coin, Storage};
 use cw_multi_test::{App, AppResponse, Executor};
 use serde::{de::DeserializeOwned, Serialize};
 
+use crate::contract_storage::{ContractStorage, ReadonlyContractStorage};
+
 pub use anyhow::Result as AnyResult;
 
 pub type ExecuteResult = AnyResult<AppResponse>;
@@ -27,6 +32,28 @@ pub trait MockContract<E: Serialize + fmt::Debug, Q: Serialize + fmt::Debug> {
             .query_wasm_smart::<T>(self.addr().clone(), &msg)
             .unwrap()
     }
+
+    /// Migrates the contract to the given code id, the sender must be the contract admin
+    fn migrate<M: Serialize>(
+        &self,
+        app: &mut App,
+        msg: &M,
+        sender: Addr,
+        code_id: u64,
+    ) -> AnyResult<AppResponse> {
+        app.migrate_contract(sender, self.addr().clone(), msg, code_id)
+    }
+
+    /// Writes directly to the contract's storage, used to seed state written by a previous version before migrating
+    fn seed_state(&self, app: &mut App, seed: impl FnOnce(&mut dyn Storage)) {
+        let addr = self.addr().clone();
+        seed(&mut ContractStorage::new(app.storage_mut(), &addr))
+    }
+
+    /// Reads directly from the contract's storage, used to assert state after migrating
+    fn read_state<T>(&self, app: &App, read: impl FnOnce(&dyn Storage) -> T) -> T {
+        read(&ReadonlyContractStorage::new(app.storage(), self.addr()))
+    }
 }
 
 pub trait MockADO<E: Serialize + fmt::Debug, Q: Serialize + fmt::Debug>:
@@ -38,6 +65,46 @@ pub trait MockADO<E: Serialize + fmt::Debug, Q: Serialize + fmt::Debug>:
             .unwrap()
             .owner
     }
+
+    /// Overwrites the stored contract version to simulate an ADO instantiated by a previous version
+    fn seed_contract_version(&self, app: &mut App, version: &str) {
+        self.seed_state(app, |storage| {
+            let contract = cw2::get_contract_version(storage).unwrap().contract;
+            cw2::set_contract_version(storage, contract, version).unwrap();
+        })
+    }
+
+    fn query_contract_version(&self, app: &App) -> String {
+        self.read_state(app, |storage| {
+            cw2::get_contract_version(storage).unwrap().version
+        })
+    }
+
+    fn query_migrations(&self, app: &App) -> Vec<MigrationInfo> {
+        app.wrap()
+            .query_wasm_smart::<Vec<MigrationInfo>>(self.addr(), &AndromedaQuery::Migrations {})
+            .unwrap()
+    }
+
+    fn execute_update_metadata(
+        &self,
+        app: &mut App,
+        sender: Addr,
+        metadata: AdoMetadata,
+    ) -> ExecuteResult {
+        app.execute_contract(
+            sender,
+            self.addr().clone(),
+            &AndromedaMsg::UpdateMetadata { metadata },
+            &[],
+        )
+    }
+
+    fn query_metadata(&self, app: &App) -> AdoMetadata {
+        app.wrap()
+            .query_wasm_smart::<AdoMetadata>(self.addr(), &AndromedaQuery::AdoMetadata {})
+            .unwrap()
+    }
 }
 
 #[macro_export]
@@ -37,16 +37,31 @@ fn merge_variants(left: TokenStream, right: TokenStream) -> TokenStream {
 ///
 /// **Must be placed before `#[cw_serde]`**
 pub fn andr_exec(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let enum_input = input.clone();
+    let ident = parse_macro_input!(enum_input as DeriveInput).ident;
     #[allow(unused_mut)]
     let mut merged = merge_variants(
         input,
         quote! {
             enum Right {
+                /// Executes each message in order with the same context, failed messages are skipped unless atomic
+                Batch {
+                    #[schemars(schema_with = "::andromeda_std::ado_contract::batch::batch_msgs_schema")]
+                    msgs: Vec<#ident>,
+                    atomic: bool,
+                },
                 #[serde(rename="amp_receive")]
                 AMPReceive(::andromeda_std::amp::messages::AMPPkt),
                 UpdateOwner {
                     address: String,
                 },
+                ProposeOwner {
+                    address: ::andromeda_std::amp::AndrAddr,
+                    expiration: Option<::andromeda_std::Expiration>,
+                },
+                AcceptOwnership {},
+                CancelOwnershipProposal {},
+                RenounceOwnership {},
                 UpdateOperators {
                     operators: Vec<String>,
                 },
@@ -65,6 +80,40 @@ pub fn andr_exec(_args: TokenStream, input: TokenStream) -> TokenStream {
                 PermissionAction {
                     action: String
                 },
+                SetActionAddressList {
+                    action: String,
+                    address_list: Option<::andromeda_std::amp::AndrAddr>,
+                },
+                SetActionMerkleRoot {
+                    action: String,
+                    merkle_root: Option<String>,
+                    expiration: Option<::andromeda_std::Expiration>,
+                },
+                ProveMembership {
+                    action: String,
+                    proof: Vec<String>,
+                },
+                GrantRole {
+                    role: String,
+                    actor: ::andromeda_std::amp::AndrAddr,
+                },
+                RevokeRole {
+                    role: String,
+                    actor: ::andromeda_std::amp::AndrAddr,
+                },
+                SetActionRole {
+                    action: String,
+                    role: Option<String>,
+                },
+                Pause {
+                    actions: Option<Vec<String>>,
+                },
+                Unpause {
+                    actions: Option<Vec<String>>,
+                },
+                UpdateMetadata {
+                    metadata: ::andromeda_std::ado_base::metadata::AdoMetadata,
+                },
             }
         }
         .into(),
@@ -85,6 +134,10 @@ pub fn andr_exec(_args: TokenStream, input: TokenStream) -> TokenStream {
                         module_idx: ::cosmwasm_std::Uint64,
                         module: ::andromeda_std::ado_base::Module,
                     },
+                    SetModuleEnabled {
+                        module_idx: ::cosmwasm_std::Uint64,
+                        enabled: bool,
+                    },
                 }
             }
             .into(),
@@ -150,6 +203,13 @@ pub fn andr_instantiate(_args: TokenStream, input: TokenStream) -> TokenStream {
                         .parse2(quote! { pub owner: Option<String> })
                         .unwrap(),
                 );
+                fields.named.push(
+                    syn::Field::parse_named
+                        .parse2(quote! {
+                            pub metadata: Option<::andromeda_std::ado_base::metadata::AdoMetadata>
+                        })
+                        .unwrap(),
+                );
             }
 
             quote! {
@@ -196,6 +256,8 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
             enum Right {
                 #[returns(andromeda_std::ado_base::ownership::ContractOwnerResponse)]
                 Owner {},
+                #[returns(andromeda_std::ado_base::ownership::PendingOwnerResponse)]
+                PendingOwner {},
                 #[returns(andromeda_std::ado_base::operators::OperatorsResponse)]
                 Operators {},
                 #[returns(andromeda_std::ado_base::ado_type::TypeResponse)]
@@ -218,6 +280,22 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
                 Permissions { actor: String, limit: Option<u32>, start_after: Option<String> },
                 #[returns(Vec<String>)]
                 PermissionedActions { },
+                #[returns(Option<::andromeda_std::amp::AndrAddr>)]
+                ActionAddressList { action: String },
+                #[returns(Option<::andromeda_std::ado_base::permissioning::MerkleRootInfo>)]
+                ActionMerkleRoot { action: String },
+                #[returns(::andromeda_std::ado_base::roles::HasRoleResponse)]
+                HasRole { role: String, address: ::andromeda_std::amp::AndrAddr },
+                #[returns(Vec<String>)]
+                RoleMembers { role: String, start_after: Option<String>, limit: Option<u32> },
+                #[returns(Option<String>)]
+                ActionRole { action: String },
+                #[returns(::andromeda_std::ado_base::pausing::IsPausedResponse)]
+                IsPaused { action: Option<String> },
+                #[returns(Vec<::andromeda_std::ado_base::version::MigrationInfo>)]
+                Migrations {},
+                #[returns(::andromeda_std::ado_base::metadata::AdoMetadata)]
+                AdoMetadata {},
             }
         }
         .into(),
@@ -1,6 +1,6 @@
 use crate::common::Funds;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Binary, Coin, Event, SubMsg};
+use cosmwasm_std::{Attribute, Binary, Coin, Event, SubMsg, Uint128};
 
 #[cw_serde]
 pub enum AndromedaHook {
@@ -18,6 +18,28 @@ pub enum AndromedaHook {
         sender: String,
         recipient: String,
     },
+    /// Sent once the ADO has been instantiated and its modules registered
+    OnInstantiate {
+        sender: String,
+    },
+    /// Sent when tokens are minted, with the `token_id` of a minted NFT or the `amount` of minted fungible tokens
+    OnMint {
+        token_id: Option<String>,
+        amount: Option<Uint128>,
+        recipient: String,
+    },
+    /// Sent when tokens are burned, with the `token_id` of a burned NFT or the `amount` of burned fungible tokens
+    OnBurn {
+        token_id: Option<String>,
+        amount: Option<Uint128>,
+        sender: String,
+    },
+    /// Sent after the ADO has handled an execute message, with the attributes of its response
+    PostExecute {
+        action: String,
+        sender: String,
+        attributes: Vec<Attribute>,
+    },
 }
 
 #[cw_serde]
@@ -0,0 +1,121 @@
+use crate::error::ContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::ensure;
+
+const MAX_DESCRIPTION_LENGTH: usize = 1024;
+const MAX_TAGS: usize = 10;
+const MAX_TAG_LENGTH: usize = 32;
+const MAX_LINKS: usize = 10;
+const MAX_LINK_NAME_LENGTH: usize = 32;
+const MAX_URL_LENGTH: usize = 256;
+
+/// Human readable details used to label an ADO
+#[cw_serde]
+#[derive(Default)]
+pub struct AdoMetadata {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub links: Vec<MetadataLink>,
+    /// The URL of an image representing the ADO
+    pub image: Option<String>,
+}
+
+#[cw_serde]
+pub struct MetadataLink {
+    /// The label of the link, e.g. `website` or `docs`
+    pub name: String,
+    pub url: String,
+}
+
+fn invalid_metadata(msg: impl Into<String>) -> ContractError {
+    ContractError::InvalidAdoMetadata { msg: msg.into() }
+}
+
+fn validate_length(field: &str, value: &str, max_length: usize) -> Result<(), ContractError> {
+    ensure!(
+        !value.is_empty(),
+        invalid_metadata(format!("{field} cannot be empty"))
+    );
+    ensure!(
+        value.len() <= max_length,
+        invalid_metadata(format!("{field} cannot exceed {max_length} characters"))
+    );
+    Ok(())
+}
+
+impl AdoMetadata {
+    /// Ensures the metadata is within the size limits stored metadata must respect
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let Some(description) = &self.description {
+            validate_length("Description", description, MAX_DESCRIPTION_LENGTH)?;
+        }
+        ensure!(
+            self.tags.len() <= MAX_TAGS,
+            invalid_metadata(format!("Cannot have more than {MAX_TAGS} tags"))
+        );
+        for tag in self.tags.iter() {
+            validate_length("Tag", tag, MAX_TAG_LENGTH)?;
+        }
+        ensure!(
+            self.links.len() <= MAX_LINKS,
+            invalid_metadata(format!("Cannot have more than {MAX_LINKS} links"))
+        );
+        for link in self.links.iter() {
+            validate_length("Link name", &link.name, MAX_LINK_NAME_LENGTH)?;
+            validate_length("Link URL", &link.url, MAX_URL_LENGTH)?;
+        }
+        if let Some(image) = &self.image {
+            validate_length("Image", image, MAX_URL_LENGTH)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let metadata = AdoMetadata {
+            description: Some("An auction for our collection".to_string()),
+            tags: vec!["auction".to_string(), "nft".to_string()],
+            links: vec![MetadataLink {
+                name: "website".to_string(),
+                url: "https://example.com".to_string(),
+            }],
+            image: Some("ipfs://image".to_string()),
+        };
+        metadata.validate().unwrap();
+        AdoMetadata::default().validate().unwrap();
+
+        let err = AdoMetadata {
+            tags: vec!["".to_string()],
+            ..AdoMetadata::default()
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err, invalid_metadata("Tag cannot be empty"));
+
+        let err = AdoMetadata {
+            tags: vec!["tag".to_string(); MAX_TAGS + 1],
+            ..AdoMetadata::default()
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err, invalid_metadata("Cannot have more than 10 tags"));
+
+        let err = AdoMetadata {
+            description: Some("a".repeat(MAX_DESCRIPTION_LENGTH + 1)),
+            ..AdoMetadata::default()
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(
+            err,
+            invalid_metadata("Description cannot exceed 1024 characters")
+        );
+    }
+}
@@ -3,10 +3,13 @@ pub mod block_height;
 #[cfg(any(feature = "module_hooks", feature = "modules"))]
 pub mod hooks;
 pub mod kernel_address;
+pub mod metadata;
 pub mod modules;
 pub mod operators;
 pub mod ownership;
+pub mod pausing;
 pub mod permissioning;
+pub mod roles;
 pub mod version;
 
 pub mod withdraw;
@@ -20,6 +23,7 @@ use crate::{
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Binary;
+use cw_utils::Expiration;
 pub use modules::Module;
 
 #[cfg(feature = "modules")]
@@ -32,6 +36,7 @@ pub struct InstantiateMsg {
     pub operators: Option<Vec<String>>,
     pub kernel_address: String,
     pub owner: Option<String>,
+    pub metadata: Option<self::metadata::AdoMetadata>,
 }
 
 #[cw_serde]
@@ -39,6 +44,15 @@ pub enum AndromedaMsg {
     UpdateOwner {
         address: String,
     },
+    /// Proposes a new owner, ownership is transferred once the proposed owner accepts it
+    ProposeOwner {
+        address: AndrAddr,
+        expiration: Option<Expiration>,
+    },
+    AcceptOwnership {},
+    CancelOwnershipProposal {},
+    /// Removes the owner, leaving the ADO without an owner permanently
+    RenounceOwnership {},
     UpdateOperators {
         operators: Vec<String>,
     },
@@ -63,6 +77,11 @@ pub enum AndromedaMsg {
         module_idx: Uint64,
         module: Module,
     },
+    #[cfg(feature = "modules")]
+    SetModuleEnabled {
+        module_idx: Uint64,
+        enabled: bool,
+    },
     Deposit {
         recipient: Option<AndrAddr>,
         msg: Option<Binary>,
@@ -81,6 +100,47 @@ pub enum AndromedaMsg {
     PermissionAction {
         action: String,
     },
+    /// Grants permissions for an action using an address list contract, removes the list if none is provided
+    SetActionAddressList {
+        action: String,
+        address_list: Option<AndrAddr>,
+    },
+    /// Grants permissions for an action to members of a merkle tree of addresses, removes the root if none is provided
+    SetActionMerkleRoot {
+        action: String,
+        merkle_root: Option<String>,
+        expiration: Option<Expiration>,
+    },
+    /// Proves the sender is a member of the merkle tree bound to an action, the result is cached for the sender
+    ProveMembership {
+        action: String,
+        proof: Vec<String>,
+    },
+    GrantRole {
+        role: String,
+        actor: AndrAddr,
+    },
+    RevokeRole {
+        role: String,
+        actor: AndrAddr,
+    },
+    /// Restricts an action to holders of the given role, removes the restriction if no role is provided
+    SetActionRole {
+        action: String,
+        role: Option<String>,
+    },
+    /// Pauses the given actions, pauses every action if none are provided
+    Pause {
+        actions: Option<Vec<String>>,
+    },
+    /// Unpauses the given actions, unpauses every action if none are provided
+    Unpause {
+        actions: Option<Vec<String>>,
+    },
+    /// Replaces the ADO's metadata
+    UpdateMetadata {
+        metadata: self::metadata::AdoMetadata,
+    },
 }
 
 #[cw_serde]
@@ -88,6 +148,8 @@ pub enum AndromedaMsg {
 pub enum AndromedaQuery {
     #[returns(self::ownership::ContractOwnerResponse)]
     Owner {},
+    #[returns(self::ownership::PendingOwnerResponse)]
+    PendingOwner {},
     #[returns(self::operators::OperatorsResponse)]
     Operators {},
     #[returns(self::ado_type::TypeResponse)]
@@ -121,4 +183,24 @@ pub enum AndromedaQuery {
     },
     #[returns(Vec<String>)]
     PermissionedActions {},
+    #[returns(Option<AndrAddr>)]
+    ActionAddressList { action: String },
+    #[returns(Option<self::permissioning::MerkleRootInfo>)]
+    ActionMerkleRoot { action: String },
+    #[returns(self::roles::HasRoleResponse)]
+    HasRole { role: String, address: AndrAddr },
+    #[returns(Vec<String>)]
+    RoleMembers {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<String>)]
+    ActionRole { action: String },
+    #[returns(self::pausing::IsPausedResponse)]
+    IsPaused { action: Option<String> },
+    #[returns(Vec<self::version::MigrationInfo>)]
+    Migrations {},
+    #[returns(self::metadata::AdoMetadata)]
+    AdoMetadata {},
 }
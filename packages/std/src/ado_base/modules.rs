@@ -6,12 +6,35 @@ use cosmwasm_schema::cw_serde;
 #[cfg(feature = "modules")]
 use cosmwasm_std::ensure;
 
+/// How a failure of a module's hook is handled
+#[cw_serde]
+#[derive(Default)]
+pub enum FailurePolicy {
+    /// A failure of the module fails the transaction
+    #[default]
+    Required,
+    /// A failure of the module is reported in a `module_failure` event and otherwise ignored
+    BestEffort,
+}
+
 /// A struct describing a token module, provided with the instantiation message this struct is used to record the info about the module and how/if it should be instantiated
 #[cw_serde]
 pub struct Module {
     pub name: Option<String>,
     pub address: AndrAddr,
     pub is_mutable: bool,
+    /// Hooks are sent to modules with a higher priority first, modules with equal priorities are sent hooks in the order they were registered
+    #[serde(default)]
+    pub priority: u64,
+    /// Disabled modules are not sent hooks
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub failure_policy: FailurePolicy,
+}
+
+fn default_enabled() -> bool {
+    true
 }
 
 #[cfg(feature = "modules")]
@@ -21,9 +44,22 @@ impl Module {
             name: Some(name.into()),
             address: AndrAddr::from_string(address.into()),
             is_mutable,
+            priority: 0,
+            enabled: true,
+            failure_policy: FailurePolicy::Required,
         }
     }
 
+    pub fn with_priority(mut self, priority: u64) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_failure_policy(mut self, failure_policy: FailurePolicy) -> Self {
+        self.failure_policy = failure_policy;
+        self
+    }
+
     /// Validates `self` by checking that it is unique, does not conflict with any other module,
     /// and does not conflict with the creating ADO.
     pub fn validate(&self, modules: &[Module]) -> Result<(), ContractError> {
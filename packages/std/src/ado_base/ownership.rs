@@ -1,11 +1,34 @@
 use cosmwasm_schema::cw_serde;
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub struct ContractOwnerResponse {
+    /// The current owner, empty if ownership has been renounced
     pub owner: String,
 }
 
+#[cw_serde]
+pub struct PendingOwnerResponse {
+    pub pending_owner: Option<String>,
+    /// When the ownership proposal expires, `None` if it does not expire
+    pub expiration: Option<Expiration>,
+}
+
 #[cw_serde]
 pub struct PublisherResponse {
     pub original_publisher: String,
 }
+
+/// Messages that transfer or renounce ownership of an ADO
+pub const OWNERSHIP_ACTIONS: &[&str] = &[
+    "UpdateOwner",
+    "ProposeOwner",
+    "AcceptOwnership",
+    "CancelOwnershipProposal",
+    "RenounceOwnership",
+];
+
+/// Returns whether the message is one of the ownership messages, which are never passed to module hooks
+pub fn is_ownership_msg(msg: &impl AsRef<str>) -> bool {
+    OWNERSHIP_ACTIONS.contains(&msg.as_ref())
+}
@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct IsPausedResponse {
+    /// Whether the queried action, or every action if none was provided, is paused
+    pub is_paused: bool,
+    /// Whether every action is paused
+    pub all_paused: bool,
+    /// Individually paused actions
+    pub paused_actions: Vec<String>,
+}
@@ -1,7 +1,7 @@
 use core::fmt;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Env;
+use cosmwasm_std::{Env, Timestamp};
 use cw_utils::Expiration;
 
 #[cw_serde]
@@ -11,11 +11,23 @@ pub struct PermissionInfo {
     pub actor: String,
 }
 
+/// A merkle root of addresses granted permission for an action
+///
+/// Leaves are the sha256 hash of each address, proven members are whitelisted until the expiration
+#[cw_serde]
+pub struct MerkleRootInfo {
+    /// Hex encoded sha256 merkle root
+    pub root: String,
+    pub expiration: Option<Expiration>,
+}
+
 /// An enum to represent a user's permission for an action
 ///
 /// - **Blacklisted** - The user cannot perform the action until after the provided expiration
 /// - **Limited** - The user can perform the action while uses are remaining and before the provided expiration **for a permissioned action**
 /// - **Whitelisted** - The user can perform the action until the provided expiration **for a permissioned action**
+/// - **RateLimited** - The user can perform the action `uses` times per `window` seconds before the provided expiration **for a permissioned action**,
+///   the window starts on the first use and uses are restored once it has elapsed
 ///
 /// Expiration defaults to `Never` if not provided
 #[cw_serde]
@@ -26,6 +38,18 @@ pub enum Permission {
         uses: u32,
     },
     Whitelisted(Option<Expiration>),
+    RateLimited {
+        expiration: Option<Expiration>,
+        uses: u32,
+        /// Length of the window in seconds
+        window: u64,
+        /// Uses remaining in the current window, only valid while `resets_at` has not passed
+        #[serde(default)]
+        remaining: u32,
+        /// When the current window ends, `None` if no window is in progress
+        #[serde(default)]
+        resets_at: Option<Timestamp>,
+    },
 }
 
 impl std::default::Default for Permission {
@@ -47,6 +71,16 @@ impl Permission {
         Self::Limited { expiration, uses }
     }
 
+    pub fn rate_limited(expiration: Option<Expiration>, uses: u32, window: u64) -> Self {
+        Self::RateLimited {
+            expiration,
+            uses,
+            window,
+            remaining: uses,
+            resets_at: None,
+        }
+    }
+
     pub fn is_permissioned(&self, env: &Env, strict: bool) -> bool {
         match self {
             Self::Blacklisted(expiration) => {
@@ -76,6 +110,49 @@ impl Permission {
                 }
                 true
             }
+            Self::RateLimited { expiration, .. } => {
+                if let Some(expiration) = expiration {
+                    if expiration.is_expired(&env.block) {
+                        return !strict;
+                    }
+                }
+                if self.current_window(env).0 == 0 {
+                    return !strict;
+                }
+                true
+            }
+        }
+    }
+
+    /// Returns the uses remaining in the current window and when it resets for a rate limited permission
+    ///
+    /// Uses are lazily restored, an elapsed window is treated as if no window is in progress
+    pub fn current_window(&self, env: &Env) -> (u32, Option<Timestamp>) {
+        match self {
+            Self::RateLimited {
+                uses,
+                remaining,
+                resets_at,
+                ..
+            } => match resets_at {
+                Some(resets_at) if env.block.time < *resets_at => (*remaining, Some(*resets_at)),
+                _ => (*uses, None),
+            },
+            _ => (0, None),
+        }
+    }
+
+    /// Updates a rate limited permission to reflect the current window, restoring uses if the window has elapsed
+    pub fn refresh_window(&mut self, env: &Env) {
+        let (current_remaining, current_resets_at) = self.current_window(env);
+        if let Self::RateLimited {
+            remaining,
+            resets_at,
+            ..
+        } = self
+        {
+            *remaining = current_remaining;
+            *resets_at = current_resets_at;
         }
     }
 
@@ -84,12 +161,27 @@ impl Permission {
             Self::Blacklisted(expiration) => expiration.unwrap_or_default(),
             Self::Limited { expiration, .. } => expiration.unwrap_or_default(),
             Self::Whitelisted(expiration) => expiration.unwrap_or_default(),
+            Self::RateLimited { expiration, .. } => expiration.unwrap_or_default(),
         }
     }
 
-    pub fn consume_use(&mut self) {
-        if let Self::Limited { uses, .. } = self {
-            *uses -= 1
+    /// Consumes a use for limited and rate limited permissions, starting a new window if none is in progress
+    pub fn consume_use(&mut self, env: &Env) {
+        self.refresh_window(env);
+        match self {
+            Self::Limited { uses, .. } => *uses -= 1,
+            Self::RateLimited {
+                window,
+                remaining,
+                resets_at,
+                ..
+            } => {
+                *remaining = remaining.saturating_sub(1);
+                if resets_at.is_none() {
+                    *resets_at = Some(env.block.time.plus_seconds(*window));
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -118,6 +210,18 @@ impl fmt::Display for Permission {
                     "whitelisted".to_string()
                 }
             }
+            Self::RateLimited {
+                expiration,
+                uses,
+                window,
+                ..
+            } => {
+                if let Some(expiration) = expiration {
+                    format!("rate_limited:{expiration}:{uses}/{window}s")
+                } else {
+                    format!("rate_limited:{uses}/{window}s")
+                }
+            }
         };
         write!(f, "{self_as_string}")
     }
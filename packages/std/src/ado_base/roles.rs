@@ -0,0 +1,6 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct HasRoleResponse {
+    pub has_role: bool,
+}
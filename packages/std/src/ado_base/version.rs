@@ -4,3 +4,11 @@ use cosmwasm_schema::cw_serde;
 pub struct VersionResponse {
     pub version: String,
 }
+
+/// A migration step that has been applied to the contract
+#[cw_serde]
+pub struct MigrationInfo {
+    pub version: String,
+    /// Block height at which the step was applied
+    pub height: u64,
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+
+use crate::{common::context::ExecuteContext, error::ContractError};
+use cosmwasm_schema::schemars::{
+    gen::SchemaGenerator,
+    schema::{ArrayValidation, InstanceType, Schema, SchemaObject},
+};
+use cosmwasm_std::{attr, ensure, DepsMut, Order, Record, Response, Storage};
+
+use super::{execute::ExecuteContextFunction, ADOContract};
+
+/// Schema of the messages carried by a `Batch`, referencing the root message schema rather than
+/// repeating the whole execute message as a definition of itself
+pub fn batch_msgs_schema(_gen: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(Schema::new_ref("#".to_string()).into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Storage recording writes over a base store so they can be discarded if a message fails
+struct BatchStorage<'a> {
+    base: &'a dyn Storage,
+    /// Pending writes, `None` for removed keys
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> BatchStorage<'a> {
+    fn new(base: &'a dyn Storage) -> Self {
+        Self {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    fn into_writes(self) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+        self.writes
+    }
+}
+
+/// Applies writes recorded by a `BatchStorage` to the given store
+fn commit(store: &mut dyn Storage, writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>) {
+    for (key, value) in writes {
+        match value {
+            Some(value) => store.set(&key, &value),
+            None => store.remove(&key),
+        }
+    }
+}
+
+impl<'a> Storage for BatchStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let mut records: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.base.range(start, end, Order::Ascending).collect();
+        let in_range = |key: &[u8]| {
+            start.map_or(true, |start| key >= start) && end.map_or(true, |end| key < end)
+        };
+        for (key, value) in self.writes.iter().filter(|(key, _)| in_range(key)) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+impl<'a> ADOContract<'a> {
+    /// Handles a batch of messages, calling the provided handler for each message with the same context
+    ///
+    /// Atomic batches fail if any message fails. Otherwise the state changes of failed messages are discarded
+    /// and their errors are added to the response as attributes.
+    ///
+    /// Batches cannot be sent with funds as each message would be handled with the same funds attached.
+    pub fn execute_batch<E: AsRef<str>>(
+        &self,
+        ctx: ExecuteContext,
+        msgs: Vec<E>,
+        atomic: bool,
+        handler: ExecuteContextFunction<E>,
+    ) -> Result<Response, ContractError> {
+        let ExecuteContext {
+            mut deps,
+            info,
+            env,
+            amp_ctx,
+            resolved,
+        } = ctx;
+        ensure!(
+            info.funds.is_empty(),
+            ContractError::InvalidFunds {
+                msg: "Batches cannot be sent with funds".to_string()
+            }
+        );
+        ensure!(
+            !msgs.is_empty(),
+            ContractError::InvalidBatch {
+                msg: "Batches must contain at least one message".to_string()
+            }
+        );
+
+        let mut res = Response::default().add_attributes(vec![
+            attr("action", "batch"),
+            attr("atomic", atomic.to_string()),
+        ]);
+        for (idx, msg) in msgs.into_iter().enumerate() {
+            let action = msg.as_ref().to_string();
+            ensure!(
+                action != "AMPReceive",
+                ContractError::InvalidBatch {
+                    msg: "AMP packets cannot be batched".to_string()
+                }
+            );
+
+            let msg_res = if atomic {
+                self.ensure_not_paused(deps.storage, &action)?;
+                let msg_ctx = ExecuteContext {
+                    deps: deps.branch(),
+                    info: info.clone(),
+                    env: env.clone(),
+                    amp_ctx: amp_ctx.clone(),
+                    resolved: resolved.clone(),
+                };
+                handler(msg_ctx, msg)?
+            } else {
+                let paused = self.ensure_not_paused(deps.storage, &action);
+                let mut storage = BatchStorage::new(deps.storage);
+                let msg_ctx = ExecuteContext {
+                    deps: DepsMut {
+                        storage: &mut storage,
+                        api: deps.api,
+                        querier: deps.querier,
+                    },
+                    info: info.clone(),
+                    env: env.clone(),
+                    amp_ctx: amp_ctx.clone(),
+                    resolved: resolved.clone(),
+                };
+                match paused.and_then(|_| handler(msg_ctx, msg)) {
+                    Ok(msg_res) => {
+                        let writes = storage.into_writes();
+                        commit(deps.storage, writes);
+                        msg_res
+                    }
+                    Err(err) => {
+                        res = res.add_attribute(format!("batch_error:{idx}"), err.to_string());
+                        continue;
+                    }
+                }
+            };
+            if msg_res.data.is_some() {
+                res.data = msg_res.data;
+            }
+            res = res
+                .add_submessages(msg_res.messages)
+                .add_attributes(msg_res.attributes)
+                .add_events(msg_res.events);
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_batch_storage() {
+        let mut base = MockStorage::new();
+        base.set(b"a", b"1");
+        base.set(b"b", b"2");
+        base.set(b"c", b"3");
+
+        let mut storage = BatchStorage::new(&base);
+        storage.set(b"b", b"4");
+        storage.remove(b"c");
+        storage.set(b"d", b"5");
+        assert_eq!(storage.get(b"b"), Some(b"4".to_vec()));
+        assert_eq!(storage.get(b"c"), None);
+        // Writes are not applied to the base store until committed
+        assert_eq!(base.get(b"b"), Some(b"2".to_vec()));
+
+        let records: Vec<Record> = storage.range(Some(b"b"), None, Order::Descending).collect();
+        assert_eq!(
+            records,
+            vec![
+                (b"d".to_vec(), b"5".to_vec()),
+                (b"b".to_vec(), b"4".to_vec())
+            ]
+        );
+
+        let writes = storage.into_writes();
+        commit(&mut base, writes);
+        assert_eq!(base.get(b"b"), Some(b"4".to_vec()));
+        assert_eq!(base.get(b"c"), None);
+        assert_eq!(base.get(b"d"), Some(b"5".to_vec()));
+    }
+}
@@ -1,20 +1,21 @@
 use crate::ado_contract::ADOContract;
 use crate::amp::addresses::AndrAddr;
 use crate::amp::messages::AMPPkt;
-use crate::common::context::ExecuteContext;
+use crate::common::{context::ExecuteContext, Funds};
 use crate::os::{aos_querier::AOSQuerier, economics::ExecuteMsg as EconomicsExecuteMsg};
 use crate::{
     ado_base::{AndromedaMsg, InstantiateMsg},
     error::ContractError,
 };
 use cosmwasm_std::{
-    attr, from_json, to_json_binary, Addr, Api, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    attr, from_json, to_json_binary, Addr, Api, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
     QuerierWrapper, Response, Storage, SubMsg, WasmMsg,
 };
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-type ExecuteContextFunction<E> = fn(ExecuteContext, E) -> Result<Response, ContractError>;
+pub(crate) type ExecuteContextFunction<E> =
+    fn(ExecuteContext, E) -> Result<Response, ContractError>;
 
 impl<'a> ADOContract<'a> {
     pub fn instantiate(
@@ -35,6 +36,10 @@ impl<'a> ADOContract<'a> {
         self.version.save(storage, &msg.ado_version)?;
         self.kernel_address
             .save(storage, &api.addr_validate(&msg.kernel_address)?)?;
+        if let Some(metadata) = msg.metadata {
+            metadata.validate()?;
+            self.metadata.save(storage, &metadata)?;
+        }
         let attributes = [attr("method", "instantiate"), attr("type", &msg.ado_type)];
         Ok(Response::new().add_attributes(attributes))
     }
@@ -51,6 +56,17 @@ impl<'a> ADOContract<'a> {
                 AndromedaMsg::UpdateOwner { address } => {
                     self.execute_update_owner(ctx.deps, ctx.info, address)
                 }
+                AndromedaMsg::ProposeOwner {
+                    address,
+                    expiration,
+                } => self.execute_propose_owner(ctx, address, expiration),
+                AndromedaMsg::AcceptOwnership {} => self.execute_accept_ownership(ctx),
+                AndromedaMsg::CancelOwnershipProposal {} => {
+                    self.execute_cancel_ownership_proposal(ctx.deps, ctx.info)
+                }
+                AndromedaMsg::RenounceOwnership {} => {
+                    self.execute_renounce_ownership(ctx.deps, ctx.info)
+                }
                 AndromedaMsg::UpdateOperators { operators } => {
                     self.execute_update_operators(ctx.deps, ctx.info, operators)
                 }
@@ -81,6 +97,11 @@ impl<'a> ADOContract<'a> {
                     self.validate_module_address(&ctx.deps.as_ref(), &module)?;
                     self.execute_alter_module(ctx.deps, ctx.info, module_idx, module)
                 }
+                #[cfg(feature = "modules")]
+                AndromedaMsg::SetModuleEnabled {
+                    module_idx,
+                    enabled,
+                } => self.execute_set_module_enabled(ctx.deps, ctx.info, module_idx, enabled),
                 AndromedaMsg::SetPermission {
                     actor,
                     action,
@@ -92,6 +113,32 @@ impl<'a> ADOContract<'a> {
                 AndromedaMsg::PermissionAction { action } => {
                     self.execute_permission_action(ctx, action)
                 }
+                AndromedaMsg::SetActionAddressList {
+                    action,
+                    address_list,
+                } => self.execute_set_action_address_list(ctx, action, address_list),
+                AndromedaMsg::SetActionMerkleRoot {
+                    action,
+                    merkle_root,
+                    expiration,
+                } => self.execute_set_action_merkle_root(ctx, action, merkle_root, expiration),
+                AndromedaMsg::ProveMembership { action, proof } => {
+                    self.execute_prove_membership(ctx, action, proof)
+                }
+                AndromedaMsg::GrantRole { role, actor } => {
+                    self.execute_grant_role(ctx, role, actor)
+                }
+                AndromedaMsg::RevokeRole { role, actor } => {
+                    self.execute_revoke_role(ctx, role, actor)
+                }
+                AndromedaMsg::SetActionRole { action, role } => {
+                    self.execute_set_action_role(ctx, action, role)
+                }
+                AndromedaMsg::Pause { actions } => self.execute_pause(ctx, actions),
+                AndromedaMsg::Unpause { actions } => self.execute_unpause(ctx, actions),
+                AndromedaMsg::UpdateMetadata { metadata } => {
+                    self.execute_update_metadata(ctx, metadata)
+                }
                 AndromedaMsg::AMPReceive(_) => panic!("AMP Receive should be handled separately"),
                 AndromedaMsg::Deposit { .. } => Err(ContractError::NotImplemented { msg: None }),
             },
@@ -189,10 +236,24 @@ impl<'a> ADOContract<'a> {
             .add_attribute("version", env!("CARGO_PKG_VERSION").to_string()))
     }
 
+    /// Calls the provided handler with the given message.
+    ///
+    /// Returns an error if the message is paused.
+    pub fn execute_msg<E: AsRef<str>>(
+        &self,
+        ctx: ExecuteContext,
+        msg: E,
+        handler: ExecuteContextFunction<E>,
+    ) -> Result<Response, ContractError> {
+        self.ensure_not_paused(ctx.deps.storage, msg.as_ref())?;
+        handler(ctx, msg)
+    }
+
     /// Handles receiving and verifies an AMPPkt from the Kernel before executing the appropriate messages.
     ///
     /// Calls the provided handler with the AMP packet attached within the context.
-    pub fn execute_amp_receive<E: DeserializeOwned>(
+    /// Returns an error if the received message is paused.
+    pub fn execute_amp_receive<E: DeserializeOwned + AsRef<str>>(
         &self,
         ctx: ExecuteContext,
         mut packet: AMPPkt,
@@ -203,8 +264,7 @@ impl<'a> ADOContract<'a> {
         let msg_opt = packet.messages.pop();
         if let Some(msg_opt) = msg_opt {
             let msg: E = from_json(msg_opt.message)?;
-            let response = handler(ctx, msg)?;
-            Ok(response)
+            self.execute_msg(ctx, msg, handler)
         } else {
             Err(ContractError::InvalidPacket {
                 error: Some("AMP Packet received with no messages".to_string()),
@@ -221,22 +281,45 @@ impl<'a> ADOContract<'a> {
     ///
     /// If any of the above cannot pay the fee the remainder is paid by the next in the list until no remainder remains.
     /// If there is still a remainder after all 3 payments then the fee cannot be paid and the message will error.
+    ///
+    /// The value moved by the action should be provided for actions with percentage or tiered fees.
     pub fn pay_fee(
         &self,
         storage: &dyn Storage,
         querier: &QuerierWrapper,
         action: String,
         payee: Addr,
+        value: Option<Funds>,
+    ) -> Result<SubMsg, ContractError> {
+        self.pay_fee_with_funds(storage, querier, action, payee, value, vec![])
+    }
+
+    /// Generates a message to pay a fee for a given action by the given payee, forwarding the provided funds.
+    ///
+    /// The forwarded funds are used before any deposited balances and any excess is returned to the payee,
+    /// allowing payees that have not deposited with the economics contract to pay fees directly.
+    pub fn pay_fee_with_funds(
+        &self,
+        storage: &dyn Storage,
+        querier: &QuerierWrapper,
+        action: String,
+        payee: Addr,
+        value: Option<Funds>,
+        funds: Vec<Coin>,
     ) -> Result<SubMsg, ContractError> {
         let kernel_address = self.get_kernel_address(storage)?;
         let economics_contract_address =
             AOSQuerier::kernel_address_getter(querier, &kernel_address, "economics")?;
-        let economics_msg = EconomicsExecuteMsg::PayFee { action, payee };
+        let economics_msg = EconomicsExecuteMsg::PayFee {
+            action,
+            payee,
+            value,
+        };
         let msg = SubMsg::reply_on_error(
             CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: economics_contract_address.to_string(),
                 msg: to_json_binary(&economics_msg)?,
-                funds: vec![],
+                funds,
             }),
             9999,
         );
@@ -277,6 +360,7 @@ mod tests {
                     ado_version: "version".to_string(),
                     kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                     owner: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -313,6 +397,7 @@ mod tests {
                     operators: None,
                     kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                     owner: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -359,6 +444,7 @@ mod tests {
                     operators: None,
                     kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                     owner: None,
+                    metadata: None,
                 },
             )
             .unwrap();
@@ -399,6 +485,7 @@ mod tests {
                     ado_type: "type".to_string(),
                     ado_version: "version".to_string(),
                     owner: None,
+                    metadata: None,
                     operators: None,
                     kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
                 },
@@ -60,6 +60,7 @@ impl<'a> ADOContract<'a> {
         // Do we want to cache the factory address?
         let adodb_addr = self.get_adodb_address(storage, querier)?;
         let code_id: u64 = AOSQuerier::code_id_getter(querier, &adodb_addr, name)?;
+        AOSQuerier::verify_code_id_not_yanked(querier, &adodb_addr, code_id)?;
         Ok(code_id)
     }
 }
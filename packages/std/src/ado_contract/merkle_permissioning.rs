@@ -0,0 +1,373 @@
+use crate::{
+    ado_base::permissioning::{MerkleRootInfo, Permission},
+    common::context::ExecuteContext,
+    error::ContractError,
+};
+use cosmwasm_std::{ensure, Addr, Deps, Response, Storage};
+use cw_utils::Expiration;
+use sha2::Digest;
+
+use super::ADOContract;
+
+/// Verifies that the given leaf belongs to the given hex encoded merkle root
+///
+/// Leaves are the sha256 hash of the leaf data and each pair of hashes is sorted before being hashed.
+/// Permissioning uses the member's address as the leaf, the merkle airdrop ADO the address followed
+/// by the claimed amount.
+pub fn verify_merkle_proof(
+    merkle_root: &str,
+    leaf: &str,
+    proof: Vec<String>,
+) -> Result<(), ContractError> {
+    let hash: [u8; 32] = sha2::Sha256::digest(leaf.as_bytes())
+        .as_slice()
+        .try_into()
+        .map_err(|_| ContractError::WrongLength {})?;
+
+    let hash = proof.into_iter().try_fold(hash, |hash, p| {
+        let mut proof_buf = [0; 32];
+        hex::decode_to_slice(p, &mut proof_buf)?;
+        let mut hashes = [hash, proof_buf];
+        hashes.sort_unstable();
+        sha2::Sha256::digest(hashes.concat())
+            .as_slice()
+            .try_into()
+            .map_err(|_| ContractError::WrongLength {})
+    })?;
+
+    let mut root_buf: [u8; 32] = [0; 32];
+    hex::decode_to_slice(merkle_root, &mut root_buf)?;
+    ensure!(root_buf == hash, ContractError::VerificationFailed {});
+    Ok(())
+}
+
+impl<'a> ADOContract<'a> {
+    /// Gets the permission granted to the given actor by the merkle root bound to the action
+    ///
+    /// Only actors who have proven membership of the current root are whitelisted
+    pub(crate) fn get_merkle_permission(
+        &self,
+        storage: &dyn Storage,
+        action: &str,
+        actor: &str,
+    ) -> Result<Option<Permission>, ContractError> {
+        let root_info = match self.action_merkle_roots.may_load(storage, action)? {
+            Some(root_info) => root_info,
+            None => return Ok(None),
+        };
+        let proven_root = self
+            .merkle_members
+            .may_load(storage, (action, &Addr::unchecked(actor)))?;
+        if proven_root.as_ref() == Some(&root_info.root) {
+            Ok(Some(Permission::whitelisted(root_info.expiration)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Verifies the actor's membership of the merkle root bound to the action and caches the result
+    ///
+    /// Cached memberships are invalidated when the root is changed
+    pub fn prove_membership(
+        &self,
+        storage: &mut dyn Storage,
+        action: &str,
+        actor: &Addr,
+        proof: Vec<String>,
+    ) -> Result<(), ContractError> {
+        let root_info = self
+            .action_merkle_roots
+            .may_load(storage, action)?
+            .ok_or(ContractError::VerificationFailed {})?;
+        verify_merkle_proof(&root_info.root, actor.as_str(), proof)?;
+        self.merkle_members
+            .save(storage, (action, actor), &root_info.root)?;
+        Ok(())
+    }
+
+    /// Execute handler for binding a merkle root to an action, only available to the contract owner
+    pub fn execute_set_action_merkle_root(
+        &self,
+        ctx: ExecuteContext,
+        action: String,
+        merkle_root: Option<String>,
+        expiration: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let root_attr = match merkle_root {
+            Some(root) => {
+                // Ensure the root is a valid sha256 hash
+                let mut root_buf: [u8; 32] = [0; 32];
+                hex::decode_to_slice(&root, &mut root_buf)?;
+                self.action_merkle_roots.save(
+                    ctx.deps.storage,
+                    &action,
+                    &MerkleRootInfo {
+                        root: root.clone(),
+                        expiration,
+                    },
+                )?;
+                root
+            }
+            None => {
+                self.action_merkle_roots.remove(ctx.deps.storage, &action);
+                "none".to_string()
+            }
+        };
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "set_action_merkle_root"),
+            ("target_action", action.as_str()),
+            ("merkle_root", root_attr.as_str()),
+        ]))
+    }
+
+    /// Execute handler for proving membership of the merkle root bound to an action
+    ///
+    /// Within an AMP packet the origin is the member being proven
+    pub fn execute_prove_membership(
+        &self,
+        ctx: ExecuteContext,
+        action: String,
+        proof: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        let member = match &ctx.amp_ctx {
+            Some(pkt) => Addr::unchecked(pkt.ctx.get_origin()),
+            None => ctx.info.sender.clone(),
+        };
+        self.prove_membership(ctx.deps.storage, &action, &member, proof)?;
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "prove_membership"),
+            ("target_action", action.as_str()),
+            ("member", member.as_str()),
+        ]))
+    }
+
+    pub fn query_action_merkle_root(
+        &self,
+        deps: Deps,
+        action: String,
+    ) -> Result<Option<MerkleRootInfo>, ContractError> {
+        Ok(self.action_merkle_roots.may_load(deps.storage, &action)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        attr,
+        testing::{mock_env, mock_info},
+        DepsMut,
+    };
+
+    use crate::{
+        ado_contract::permissioning::is_context_permissioned,
+        amp::messages::{AMPCtx, AMPPkt},
+        testing::mock_querier::mock_dependencies_custom,
+    };
+
+    use super::*;
+
+    const ACTION: &str = "Mint";
+
+    fn hash(bytes: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(bytes).into()
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let mut hashes = [a, b];
+        hashes.sort_unstable();
+        hash(&hashes.concat())
+    }
+
+    /// Builds a tree of three members returning the root and a proof for each member
+    fn tree(members: [&str; 3]) -> (String, Vec<Vec<String>>) {
+        let leaves = members.map(|member| hash(member.as_bytes()));
+        let branch = hash_pair(leaves[0], leaves[1]);
+        let root = hash_pair(branch, leaves[2]);
+        let proofs = vec![
+            vec![hex::encode(leaves[1]), hex::encode(leaves[2])],
+            vec![hex::encode(leaves[0]), hex::encode(leaves[2])],
+            vec![hex::encode(branch)],
+        ];
+        (hex::encode(root), proofs)
+    }
+
+    fn init(deps: DepsMut) {
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract.permission_action(ACTION, deps.storage).unwrap();
+    }
+
+    #[test]
+    fn test_merkle_permission() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        init(deps.as_mut());
+        let (root, proofs) = tree(["member_one", "member_two", "member_three"]);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), env.clone());
+        let err = contract
+            .execute_set_action_merkle_root(ctx, ACTION.to_string(), Some(root.clone()), None)
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        let res = contract.execute_set_action_merkle_root(
+            ctx,
+            ACTION.to_string(),
+            Some("invalid".to_string()),
+            None,
+        );
+        assert!(res.is_err());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_merkle_root(ctx, ACTION.to_string(), Some(root.clone()), None)
+            .unwrap();
+        assert_eq!(
+            contract
+                .query_action_merkle_root(deps.as_ref(), ACTION.to_string())
+                .unwrap(),
+            Some(MerkleRootInfo {
+                root,
+                expiration: None
+            })
+        );
+
+        // Members are not permissioned until they have proven membership
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), ACTION, "member_one");
+        assert!(res.is_err());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("member_one", &[]), env.clone());
+        let res = contract
+            .execute_prove_membership(ctx, ACTION.to_string(), proofs[0].clone())
+            .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "prove_membership"),
+                attr("target_action", ACTION),
+                attr("member", "member_one"),
+            ]
+        );
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), ACTION, "member_one")
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), ACTION, "member_one")
+            .unwrap();
+
+        // Proofs for other members are rejected
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), env.clone());
+        let err = contract
+            .execute_prove_membership(ctx, ACTION.to_string(), proofs[0].clone())
+            .unwrap_err();
+        assert_eq!(err, ContractError::VerificationFailed {});
+
+        // Changing the root invalidates cached memberships
+        let (root, _) = tree(["member_two", "member_three", "member_four"]);
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_merkle_root(
+                ctx,
+                ACTION.to_string(),
+                Some(root),
+                Some(Expiration::AtHeight(env.block.height + 1)),
+            )
+            .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), ACTION, "member_one");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_merkle_permission_expiration() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        let mut env = mock_env();
+        init(deps.as_mut());
+        let (root, proofs) = tree(["member_one", "member_two", "member_three"]);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_merkle_root(
+                ctx,
+                ACTION.to_string(),
+                Some(root),
+                Some(Expiration::AtHeight(env.block.height + 1)),
+            )
+            .unwrap();
+        contract
+            .prove_membership(
+                deps.as_mut().storage,
+                ACTION,
+                &Addr::unchecked("member_three"),
+                proofs[2].clone(),
+            )
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), ACTION, "member_three")
+            .unwrap();
+
+        env.block.height += 1;
+        let res = contract.is_permissioned(deps.as_mut(), env, ACTION, "member_three");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_merkle_permission_context_proof() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        init(deps.as_mut());
+        let (root, proofs) = tree(["member_one", "member_two", "member_three"]);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_merkle_root(ctx, ACTION.to_string(), Some(root), None)
+            .unwrap();
+
+        let info = mock_info("kernel", &[]);
+        let mut pkt = AMPPkt::new("member_two", "previous_sender", vec![]);
+        assert!(!is_context_permissioned(
+            &mut deps.as_mut(),
+            &info,
+            &env,
+            &Some(pkt.clone()),
+            ACTION
+        )
+        .unwrap());
+
+        // Invalid proofs are ignored
+        pkt.ctx = AMPCtx::new("member_two", "previous_sender", 0, None)
+            .with_merkle_proof(proofs[0].clone());
+        assert!(!is_context_permissioned(
+            &mut deps.as_mut(),
+            &info,
+            &env,
+            &Some(pkt.clone()),
+            ACTION
+        )
+        .unwrap());
+
+        pkt.ctx = AMPCtx::new("member_two", "previous_sender", 0, None)
+            .with_merkle_proof(proofs[1].clone());
+        assert!(
+            is_context_permissioned(&mut deps.as_mut(), &info, &env, &Some(pkt), ACTION).unwrap()
+        );
+
+        // The membership is cached for later messages without a proof
+        let pkt = AMPPkt::new("member_two", "previous_sender", vec![]);
+        assert!(
+            is_context_permissioned(&mut deps.as_mut(), &info, &env, &Some(pkt), ACTION).unwrap()
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    ado_base::metadata::AdoMetadata, common::context::ExecuteContext, error::ContractError,
+};
+use cosmwasm_std::{ensure, Deps, Response};
+
+use super::ADOContract;
+
+impl<'a> ADOContract<'a> {
+    /// Execute handler for replacing the ADO's metadata, only available to the owner
+    pub fn execute_update_metadata(
+        &self,
+        ctx: ExecuteContext,
+        metadata: AdoMetadata,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        metadata.validate()?;
+        self.metadata.save(ctx.deps.storage, &metadata)?;
+
+        Ok(Response::default().add_attribute("action", "update_metadata"))
+    }
+
+    /// Queries the ADO's metadata, empty if none has been set
+    pub fn query_metadata(&self, deps: Deps) -> Result<AdoMetadata, ContractError> {
+        Ok(self.metadata.may_load(deps.storage)?.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_update_metadata() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        assert_eq!(
+            contract.query_metadata(deps.as_ref()).unwrap(),
+            AdoMetadata::default()
+        );
+
+        let metadata = AdoMetadata {
+            description: Some("description".to_string()),
+            tags: vec!["tag".to_string()],
+            ..AdoMetadata::default()
+        };
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), mock_env());
+        let err = contract
+            .execute_update_metadata(ctx, metadata.clone())
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        let err = contract
+            .execute_update_metadata(
+                ctx,
+                AdoMetadata {
+                    image: Some("".to_string()),
+                    ..AdoMetadata::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidAdoMetadata {
+                msg: "Image cannot be empty".to_string()
+            }
+        );
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_update_metadata(ctx, metadata.clone())
+            .unwrap();
+        assert_eq!(contract.query_metadata(deps.as_ref()).unwrap(), metadata);
+    }
+}
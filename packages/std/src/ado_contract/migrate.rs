@@ -0,0 +1,220 @@
+use crate::{
+    ado_base::version::MigrationInfo,
+    error::{from_semver, ContractError},
+};
+use cosmwasm_std::{attr, ensure, Deps, DepsMut, Env, Order, Response};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use super::ADOContract;
+
+/// A step transforming stored state when migrating to the version it is registered for
+pub type MigrationStep = fn(DepsMut) -> Result<(), ContractError>;
+
+/// Migrates an ADO, running each registered migration step between the stored and new contract versions.
+///
+/// Steps are keyed by the version that introduced them and must be registered in ascending order.
+///
+/// e.g.
+/// ```ignore
+/// andr_migrate!(deps, env, CONTRACT_NAME, CONTRACT_VERSION, {
+///     "1.1.0" => migrate_config,
+///     "1.2.0" => migrate_recipients,
+/// })
+/// ```
+#[macro_export]
+macro_rules! andr_migrate {
+    ($deps:expr, $env:expr, $name:expr, $version:expr $(, { $($step_version:literal => $step:expr),* $(,)? })? $(,)?) => {
+        $crate::ado_contract::ADOContract::default().migrate(
+            $deps,
+            &$env,
+            $name,
+            $version,
+            &[$($(($step_version, $step as $crate::ado_contract::migrate::MigrationStep)),*)?],
+        )
+    };
+}
+
+impl<'a> ADOContract<'a> {
+    /// Migrates the contract to the given version, applying every step newer than the stored version
+    /// up to and including the new version
+    ///
+    /// Returns an error if the stored contract differs, the new version is not newer than the stored version
+    /// or the steps are not in ascending version order
+    pub fn migrate(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        contract_name: &str,
+        contract_version: &str,
+        steps: &[(&str, MigrationStep)],
+    ) -> Result<Response, ContractError> {
+        // New version
+        let version: Version = contract_version.parse().map_err(from_semver)?;
+
+        // Old version
+        let stored = get_contract_version(deps.storage)?;
+        let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+
+        ensure!(
+            stored.contract == contract_name,
+            ContractError::CannotMigrate {
+                previous_contract: stored.contract,
+            }
+        );
+
+        // New version has to be newer/greater than the old version
+        ensure!(
+            storage_version < version,
+            ContractError::CannotMigrate {
+                previous_contract: stored.version,
+            }
+        );
+
+        // Steps are validated before any are applied
+        let mut versions: Vec<Version> = vec![];
+        for (step_version, _) in steps {
+            let parsed: Version = step_version.parse().map_err(from_semver)?;
+            ensure!(
+                versions.last().map_or(true, |previous| previous < &parsed),
+                ContractError::InvalidMigrationStep {
+                    version: step_version.to_string(),
+                }
+            );
+            versions.push(parsed);
+        }
+
+        let mut applied: Vec<&str> = vec![];
+        for ((step_version, step), parsed) in steps.iter().zip(versions) {
+            if parsed > storage_version && parsed <= version {
+                step(deps.branch())?;
+                self.migrations
+                    .save(deps.storage, step_version, &env.block.height)?;
+                applied.push(step_version);
+            }
+        }
+
+        set_contract_version(deps.storage, contract_name, contract_version)?;
+        // Update the ADOContract's version
+        self.version
+            .save(deps.storage, &contract_version.to_string())?;
+
+        let migrations = if applied.is_empty() {
+            "none".to_string()
+        } else {
+            applied.join(",")
+        };
+        Ok(Response::default().add_attributes(vec![
+            attr("action", "migrate"),
+            attr("previous_version", stored.version),
+            attr("version", contract_version),
+            attr("migrations", migrations),
+        ]))
+    }
+
+    /// Queries the applied migration steps in ascending version order
+    pub fn query_migrations(&self, deps: Deps) -> Result<Vec<MigrationInfo>, ContractError> {
+        let mut migrations = self
+            .migrations
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|migration| {
+                let (version, height) = migration?;
+                Ok(MigrationInfo { version, height })
+            })
+            .collect::<Result<Vec<MigrationInfo>, ContractError>>()?;
+        // Versions are stored as strings so are sorted by their semantic version
+        migrations.sort_by_cached_key(|migration| Version::parse(&migration.version).ok());
+        Ok(migrations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cw_storage_plus::Item;
+
+    use super::*;
+
+    const CONTRACT_NAME: &str = "crates.io:andromeda-test";
+    const COUNT: Item<u64> = Item::new("count");
+
+    fn double_count(deps: DepsMut) -> Result<(), ContractError> {
+        let count = COUNT.may_load(deps.storage)?.unwrap_or(1);
+        COUNT.save(deps.storage, &(count * 2))?;
+        Ok(())
+    }
+
+    fn add_count(deps: DepsMut) -> Result<(), ContractError> {
+        COUNT.update(deps.storage, |count| Ok::<u64, ContractError>(count + 1))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.0.0").unwrap();
+        COUNT.save(deps.as_mut().storage, &5).unwrap();
+
+        let err = andr_migrate!(deps.as_mut(), env, "crates.io:other", "1.1.0").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CannotMigrate {
+                previous_contract: CONTRACT_NAME.to_string()
+            }
+        );
+        let err = andr_migrate!(deps.as_mut(), env, CONTRACT_NAME, "1.0.0").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CannotMigrate {
+                previous_contract: "1.0.0".to_string()
+            }
+        );
+        let err = andr_migrate!(deps.as_mut(), env, CONTRACT_NAME, "1.2.0", {
+            "1.2.0" => add_count,
+            "1.1.0" => double_count,
+        })
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidMigrationStep {
+                version: "1.1.0".to_string()
+            }
+        );
+
+        // Steps up to and including the new version are applied in order
+        let res = andr_migrate!(deps.as_mut(), env, CONTRACT_NAME, "1.2.0", {
+            "1.0.0" => add_count,
+            "1.1.0" => double_count,
+            "1.2.0" => add_count,
+            "1.10.0" => double_count,
+        })
+        .unwrap();
+        assert!(res.attributes.contains(&attr("migrations", "1.1.0,1.2.0")));
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 11);
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            "1.2.0"
+        );
+
+        // Only steps newer than the stored version are applied
+        andr_migrate!(deps.as_mut(), env, CONTRACT_NAME, "1.10.0", {
+            "1.1.0" => double_count,
+            "1.2.0" => add_count,
+            "1.10.0" => double_count,
+        })
+        .unwrap();
+        assert_eq!(COUNT.load(deps.as_ref().storage).unwrap(), 22);
+
+        let migrations = ADOContract::default()
+            .query_migrations(deps.as_ref())
+            .unwrap();
+        assert_eq!(
+            migrations
+                .iter()
+                .map(|migration| migration.version.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["1.1.0", "1.2.0", "1.10.0"]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod batch;
 mod execute;
 #[cfg(feature = "instantiate")]
 mod instantiate;
@@ -6,10 +7,19 @@ mod instantiate;
 #[cfg(feature = "modules")]
 pub mod modules;
 
+pub mod merkle_permissioning;
+
+mod metadata;
+
+pub mod migrate;
+
 mod ownership;
 
+pub mod pausing;
+
 pub mod permissioning;
 mod query;
+pub mod roles;
 pub mod state;
 #[cfg(feature = "withdraw")]
 pub mod withdraw;
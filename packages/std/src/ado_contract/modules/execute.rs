@@ -60,6 +60,34 @@ impl<'a> ADOContract<'a> {
             .add_attribute("module_idx", module_idx))
     }
 
+    /// Enables or disables a module, immutable modules can also be disabled so that a misbehaving module
+    /// can be taken offline
+    pub(crate) fn execute_set_module_enabled(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        module_idx: Uint64,
+        enabled: bool,
+    ) -> Result<Response, ContractError> {
+        let addr = info.sender.as_str();
+        ensure!(
+            self.is_owner_or_operator(deps.storage, addr)?,
+            ContractError::Unauthorized {}
+        );
+        self.module_info.update(
+            deps.storage,
+            &module_idx.to_string(),
+            |module| match module {
+                Some(module) => Ok(Module { enabled, ..module }),
+                None => Err(ContractError::ModuleDoesNotExist {}),
+            },
+        )?;
+        Ok(Response::default()
+            .add_attribute("action", "set_module_enabled")
+            .add_attribute("module_idx", module_idx)
+            .add_attribute("enabled", enabled.to_string()))
+    }
+
     /// A wrapper for `fn deregister_module`.
     pub(crate) fn execute_deregister_module(
         &self,
@@ -1,9 +1,10 @@
-use std::convert::TryInto;
+use std::{cmp::Reverse, convert::TryInto};
 
 use crate::{
     ado_base::hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse},
     ado_contract::state::ADOContract,
     common::Funds,
+    os::aos_querier::ResolvedAddresses,
 };
 use cosmwasm_std::{
     Binary, Deps, Event, Order, QuerierWrapper, Response, StdError, Storage, SubMsg, Uint64,
@@ -11,29 +12,100 @@ use cosmwasm_std::{
 use cw_storage_plus::Bound;
 use serde::de::DeserializeOwned;
 
-use crate::{ado_base::modules::Module, error::ContractError};
+use crate::{
+    ado_base::modules::{FailurePolicy, Module},
+    error::ContractError,
+};
 
 pub mod execute;
 pub mod query;
 
 impl<'a> ADOContract<'a> {
-    /// Sends the provided hook message to all registered modules
+    /// Sends the provided hook message to all enabled modules in priority order
+    ///
+    /// Returns the responses of the modules along with events reporting any failed best effort modules
+    ///
+    /// Module addresses are resolved through the execution's `resolved` addresses
     pub fn module_hook<T: DeserializeOwned>(
         &self,
         deps: &Deps,
+        resolved: &mut ResolvedAddresses,
         hook_msg: AndromedaHook,
-    ) -> Result<Vec<T>, ContractError> {
-        let addresses: Vec<String> = self.load_module_addresses(deps)?;
+    ) -> Result<(Vec<T>, Vec<Event>), ContractError> {
+        let modules = self.load_hooked_modules(deps.storage)?;
         let mut resp: Vec<T> = Vec::new();
-        for addr in addresses {
-            let mod_resp = hook_query::<T>(&deps.querier, hook_msg.clone(), addr)?;
+        let mut events: Vec<Event> = Vec::new();
+        if modules.is_empty() {
+            return Ok((resp, events));
+        }
+        for module in modules {
+            let mod_resp =
+                module_hook_query::<T>(deps, resolved, &module, hook_msg.clone(), &mut events)?;
 
             if let Some(mod_resp) = mod_resp {
                 resp.push(mod_resp);
             }
         }
 
-        Ok(resp)
+        Ok((resp, events))
+    }
+
+    /// Sends the provided hook message to all registered modules and adds their responses to the given response
+    pub fn merge_module_hook(
+        &self,
+        deps: &Deps,
+        resolved: &mut ResolvedAddresses,
+        hook_msg: AndromedaHook,
+        resp: Response,
+    ) -> Result<Response, ContractError> {
+        let (responses, events) = self.module_hook::<Response>(deps, resolved, hook_msg)?;
+        Ok(responses
+            .into_iter()
+            .fold(resp.add_events(events), |resp, r| {
+                resp.add_submessages(r.messages)
+                    .add_events(r.events)
+                    .add_attributes(r.attributes)
+            }))
+    }
+
+    /// Sends an `OnInstantiate` hook message to all registered modules, adding their responses to the given response
+    ///
+    /// Modules referenced by local paths cannot be resolved before the ADO is added to an app and are skipped
+    pub fn on_instantiate(
+        &self,
+        deps: &Deps,
+        sender: String,
+        resp: Response,
+    ) -> Result<Response, ContractError> {
+        let modules: Vec<Module> = self
+            .load_hooked_modules(deps.storage)?
+            .into_iter()
+            .filter(|module| !module.address.is_local_path())
+            .collect();
+        if modules.is_empty() {
+            return Ok(resp);
+        }
+        let mut resolved = ResolvedAddresses::default();
+        let mut resp = resp;
+        let mut events: Vec<Event> = Vec::new();
+        for module in modules {
+            let mod_resp: Option<Response> = module_hook_query(
+                deps,
+                &mut resolved,
+                &module,
+                AndromedaHook::OnInstantiate {
+                    sender: sender.clone(),
+                },
+                &mut events,
+            )?;
+            if let Some(mod_resp) = mod_resp {
+                resp = resp
+                    .add_submessages(mod_resp.messages)
+                    .add_events(mod_resp.events)
+                    .add_attributes(mod_resp.attributes);
+            }
+        }
+        Ok(resp.add_events(events))
     }
 
     /// Validates the given address for a module.
@@ -145,21 +217,17 @@ impl<'a> ADOContract<'a> {
         Ok(modules)
     }
 
-    /// Loads all registered module addresses in Vector form
-    fn load_module_addresses(&self, deps: &Deps) -> Result<Vec<String>, ContractError> {
-        let vfs_address = self.get_vfs_address(deps.storage, &deps.querier)?;
-        let module_addresses: Vec<String> = self
-            .load_modules(deps.storage)?
+    /// Loads all enabled modules in the order they are sent hooks
+    fn load_hooked_modules(&self, storage: &dyn Storage) -> Result<Vec<Module>, ContractError> {
+        let mut modules: Vec<Module> = self
+            .load_modules(storage)?
             .into_iter()
-            .map(|m| {
-                m.address
-                    .get_raw_address_from_vfs(deps, vfs_address.clone())
-                    .unwrap()
-                    .to_string()
-            })
+            .filter(|module| module.enabled)
             .collect();
+        // The sort is stable so modules with equal priorities remain in registration order
+        modules.sort_by_key(|module| Reverse(module.priority));
 
-        Ok(module_addresses)
+        Ok(modules)
     }
 
     /// Validates all modules.
@@ -174,9 +242,12 @@ impl<'a> ADOContract<'a> {
     /// Sends a `OnFundsTransfer` hook message to all registered modules.
     ///
     /// Returns a vector of all required sub messages from each of the registered modules.
+    /// `Funds::Multi` amounts are passed to modules as is, leaving each module to handle every
+    /// asset transferred.
     pub fn on_funds_transfer(
         &self,
         deps: &Deps,
+        resolved: &mut ResolvedAddresses,
         sender: String,
         amount: Funds,
         msg: Binary,
@@ -185,20 +256,18 @@ impl<'a> ADOContract<'a> {
         let mut msgs: Vec<SubMsg> = Vec::new();
         let mut events: Vec<Event> = Vec::new();
 
-        let vfs_address = self.get_vfs_address(deps.storage, &deps.querier)?;
-        let modules: Vec<Module> = self.load_modules(deps.storage)?;
+        let modules: Vec<Module> = self.load_hooked_modules(deps.storage)?;
         for module in modules {
-            let module_address = module
-                .address
-                .get_raw_address_from_vfs(deps, vfs_address.clone())?;
-            let mod_resp: Option<OnFundsTransferResponse> = hook_query(
-                &deps.querier,
+            let mod_resp: Option<OnFundsTransferResponse> = module_hook_query(
+                deps,
+                resolved,
+                &module,
                 AndromedaHook::OnFundsTransfer {
                     payload: msg.clone(),
                     sender: sender.clone(),
                     amount: remainder.clone(),
                 },
-                module_address,
+                &mut events,
             )?;
 
             if let Some(mod_resp) = mod_resp {
@@ -212,14 +281,34 @@ impl<'a> ADOContract<'a> {
     }
 }
 
-/// Processes the given module response by hiding the error if it is `UnsupportedOperation` and
-/// bubbling up any other one. A return value of Ok(None) signifies that the operation was not
-/// supported.
+/// Returns whether the hook was added after modules could already be deployed, in which case older
+/// modules may not be able to parse it.
+fn is_new_hook(hook_msg: &AndromedaHook) -> bool {
+    matches!(
+        hook_msg,
+        AndromedaHook::OnInstantiate { .. }
+            | AndromedaHook::OnMint { .. }
+            | AndromedaHook::OnBurn { .. }
+            | AndromedaHook::PostExecute { .. }
+    )
+}
+
+/// Returns whether the error was raised by a module unable to parse the hook message, as happens when
+/// a module deployed before the hook was added receives it.
+fn is_unknown_hook(err: &StdError) -> bool {
+    err.to_string().contains("unknown variant")
+}
+
+/// Processes the given module response to the given hook by hiding the error if it is
+/// `UnsupportedOperation` or the module does not know a newly added hook and bubbling up any other
+/// one. A return value of Ok(None) signifies that the operation was not supported.
 fn process_module_response<T>(
+    hook_msg: &AndromedaHook,
     mod_resp: Result<Option<T>, StdError>,
 ) -> Result<Option<T>, ContractError> {
     match mod_resp {
         Ok(mod_resp) => Ok(mod_resp),
+        Err(err) if is_new_hook(hook_msg) && is_unknown_hook(&err) => Ok(None),
         Err(StdError::NotFound { kind }) => {
             if kind.contains("operation") {
                 Ok(None)
@@ -231,6 +320,39 @@ fn process_module_response<T>(
     }
 }
 
+/// Sends the given hook message to the given module and returns the processed result.
+///
+/// Failures of best effort modules, including failures to resolve their address, are added to the
+/// given events as `module_failure` events and are otherwise treated as unsupported operations.
+fn module_hook_query<T: DeserializeOwned>(
+    deps: &Deps,
+    resolved: &mut ResolvedAddresses,
+    module: &Module,
+    hook_msg: AndromedaHook,
+    events: &mut Vec<Event>,
+) -> Result<Option<T>, ContractError> {
+    let mod_resp = resolved
+        .resolve(deps, &module.address)
+        .and_then(|module_address| hook_query(&deps.querier, hook_msg, module_address));
+    match mod_resp {
+        Err(err) if module.failure_policy == FailurePolicy::BestEffort => {
+            events.push(
+                Event::new("module_failure")
+                    .add_attribute(
+                        "module",
+                        module
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| module.address.to_string()),
+                    )
+                    .add_attribute("error", err.to_string()),
+            );
+            Ok(None)
+        }
+        mod_resp => mod_resp,
+    }
+}
+
 /// Queries the given address with the given hook message and returns the processed result.
 fn hook_query<T: DeserializeOwned>(
     querier: &QuerierWrapper,
@@ -239,13 +361,14 @@ fn hook_query<T: DeserializeOwned>(
 ) -> Result<Option<T>, ContractError> {
     let msg = HookMsg::AndrHook(hook_msg);
     let mod_resp: Result<Option<T>, StdError> = querier.query_wasm_smart(addr, &msg);
-    process_module_response(mod_resp)
+    let HookMsg::AndrHook(hook_msg) = &msg;
+    process_module_response(hook_msg, mod_resp)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testing::mock_querier::{mock_dependencies_custom, MOCK_APP_CONTRACT};
+    use crate::testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT};
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_info},
         Addr,
@@ -508,51 +631,154 @@ mod tests {
     }
 
     #[test]
-    fn test_load_module_addresses() {
+    fn test_load_hooked_modules() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let contract = ADOContract::default();
+        contract.module_idx.save(deps.as_mut().storage, &4).unwrap();
+        let modules = [
+            Module::new("first", "address", true),
+            Module::new("second", "address2", true).with_priority(1),
+            Module::new("third", "address3", true),
+            Module {
+                enabled: false,
+                ..Module::new("disabled", "address4", true).with_priority(2)
+            },
+        ];
+        for (idx, module) in modules.iter().enumerate() {
+            contract
+                .module_info
+                .save(deps.as_mut().storage, &(idx + 1).to_string(), module)
+                .unwrap();
+        }
+
+        // Disabled modules are skipped and modules of equal priority remain in registration order
+        let hooked_modules = contract.load_hooked_modules(deps.as_ref().storage).unwrap();
+        assert_eq!(
+            vec![modules[1].clone(), modules[0].clone(), modules[2].clone()],
+            hooked_modules
+        );
+    }
+
+    #[test]
+    fn test_module_hook_failure_policy() {
         let mut deps = mock_dependencies_custom(&[]);
         let contract = ADOContract::default();
         contract
-            .app_contract
-            .save(deps.as_mut().storage, &Addr::unchecked(MOCK_APP_CONTRACT))
+            .kernel_address
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(MOCK_KERNEL_CONTRACT),
+            )
             .unwrap();
-        contract.module_idx.save(deps.as_mut().storage, &2).unwrap();
+        // A local path cannot be resolved without an app contract
         contract
             .module_info
             .save(
                 deps.as_mut().storage,
                 "1",
-                &Module::new("address_list", "address", true),
+                &Module::new("missing", "./missing", true),
             )
             .unwrap();
+        let hook = AndromedaHook::OnExecute {
+            sender: "sender".to_string(),
+            payload: Binary::default(),
+        };
+
+        let mut resolved = ResolvedAddresses::default();
+        let res = contract.module_hook::<Response>(&deps.as_ref(), &mut resolved, hook.clone());
+        assert_eq!(ContractError::AppContractNotSpecified {}, res.unwrap_err());
 
         contract
             .module_info
             .save(
                 deps.as_mut().storage,
-                "2",
-                &Module::new("address_list", "address2", true),
+                "1",
+                &Module::new("missing", "./missing", true)
+                    .with_failure_policy(FailurePolicy::BestEffort),
             )
             .unwrap();
-        let module_addresses = contract.load_module_addresses(&deps.as_ref()).unwrap();
-
+        let (responses, events) = contract
+            .module_hook::<Response>(&deps.as_ref(), &mut resolved, hook.clone())
+            .unwrap();
+        assert!(responses.is_empty());
         assert_eq!(
-            vec![String::from("address"), String::from("address2")],
-            module_addresses
+            vec![Event::new("module_failure")
+                .add_attribute("module", "missing")
+                .add_attribute(
+                    "error",
+                    ContractError::AppContractNotSpecified {}.to_string()
+                )],
+            events
         );
+
+        // Disabled modules are not sent hooks
+        let info = mock_info("owner", &[]);
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .module_info
+            .save(
+                deps.as_mut().storage,
+                "1",
+                &Module::new("missing", "./missing", false),
+            )
+            .unwrap();
+        let err = contract
+            .execute_set_module_enabled(deps.as_mut(), mock_info("anyone", &[]), 1u64.into(), false)
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err);
+        contract
+            .execute_set_module_enabled(deps.as_mut(), info, 1u64.into(), false)
+            .unwrap();
+        let (responses, events) = contract
+            .module_hook::<Response>(&deps.as_ref(), &mut resolved, hook)
+            .unwrap();
+        assert!(responses.is_empty());
+        assert!(events.is_empty());
     }
 
     #[test]
     fn test_process_module_response() {
-        let res: Option<Response> = process_module_response(Ok(Some(Response::new()))).unwrap();
+        let on_execute = AndromedaHook::OnExecute {
+            sender: "sender".to_string(),
+            payload: Binary::default(),
+        };
+        let res: Option<Response> =
+            process_module_response(&on_execute, Ok(Some(Response::new()))).unwrap();
         assert_eq!(Some(Response::new()), res);
 
+        let res: Option<Response> = process_module_response(
+            &on_execute,
+            Err(StdError::not_found("operation".to_string())),
+        )
+        .unwrap();
+        assert_eq!(None, res);
+
+        // Modules deployed before a hook was added cannot parse it
+        let on_mint = AndromedaHook::OnMint {
+            token_id: Some("token_id".to_string()),
+            amount: None,
+            recipient: "recipient".to_string(),
+        };
+        let unknown_variant = || {
+            StdError::generic_err("Querier contract error: Error parsing into type andromeda_modules::address_list::QueryMsg: unknown variant `on_mint`")
+        };
         let res: Option<Response> =
-            process_module_response(Err(StdError::not_found("operation".to_string()))).unwrap();
+            process_module_response(&on_mint, Err(unknown_variant())).unwrap();
         assert_eq!(None, res);
 
+        // Hooks that every module is expected to parse still fail
         let res: ContractError =
-            process_module_response::<Response>(Err(StdError::generic_err("AnotherError")))
-                .unwrap_err();
+            process_module_response::<Response>(&on_execute, Err(unknown_variant())).unwrap_err();
+        assert_eq!(ContractError::Std(unknown_variant()), res);
+
+        let res: ContractError = process_module_response::<Response>(
+            &on_execute,
+            Err(StdError::generic_err("AnotherError")),
+        )
+        .unwrap_err();
         assert_eq!(
             ContractError::Std(StdError::generic_err("AnotherError")),
             res
@@ -1,6 +1,9 @@
 use crate::ado_contract::ADOContract;
+use crate::amp::AndrAddr;
+use crate::common::context::ExecuteContext;
 use crate::error::ContractError;
 use cosmwasm_std::{attr, ensure, DepsMut, MessageInfo, Response, Storage};
+use cw_utils::Expiration;
 
 impl<'a> ADOContract<'a> {
     /// Updates the current contract owner. **Only executable by the current contract owner.**
@@ -16,6 +19,7 @@ impl<'a> ADOContract<'a> {
         );
         let new_owner_addr = deps.api.addr_validate(&new_owner)?;
         self.owner.save(deps.storage, &new_owner_addr)?;
+        self.clear_pending_owner(deps.storage);
 
         Ok(Response::new().add_attributes(vec![
             attr("action", "update_owner"),
@@ -23,6 +27,108 @@ impl<'a> ADOContract<'a> {
         ]))
     }
 
+    /// Proposes a new contract owner, replacing any existing proposal. **Only executable by the current contract owner.**
+    ///
+    /// Ownership is only transferred once the proposed owner accepts it.
+    pub fn execute_propose_owner(
+        &self,
+        ctx: ExecuteContext,
+        new_owner: AndrAddr,
+        expiration: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let ExecuteContext {
+            deps, info, env, ..
+        } = ctx;
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let new_owner_addr = new_owner.get_raw_address(&deps.as_ref())?;
+        self.pending_owner.save(deps.storage, &new_owner_addr)?;
+        match expiration {
+            Some(expiration) => {
+                ensure!(
+                    !expiration.is_expired(&env.block),
+                    ContractError::InvalidExpiration {}
+                );
+                self.pending_owner_expiration
+                    .save(deps.storage, &expiration)?;
+            }
+            None => self.pending_owner_expiration.remove(deps.storage),
+        }
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "propose_owner"),
+            attr("value", new_owner_addr),
+        ]))
+    }
+
+    /// Transfers ownership to the proposed owner. **Only executable by the proposed owner before the proposal expires.**
+    pub fn execute_accept_ownership(&self, ctx: ExecuteContext) -> Result<Response, ContractError> {
+        let ExecuteContext {
+            deps, info, env, ..
+        } = ctx;
+        let pending_owner = self
+            .pending_owner
+            .may_load(deps.storage)?
+            .ok_or(ContractError::NoPendingOwner {})?;
+        ensure!(pending_owner == info.sender, ContractError::Unauthorized {});
+        if let Some(expiration) = self.pending_owner_expiration.may_load(deps.storage)? {
+            ensure!(
+                !expiration.is_expired(&env.block),
+                ContractError::Expired {}
+            );
+        }
+
+        self.owner.save(deps.storage, &pending_owner)?;
+        self.clear_pending_owner(deps.storage);
+
+        Ok(Response::new().add_attributes(vec![
+            attr("action", "accept_ownership"),
+            attr("value", pending_owner),
+        ]))
+    }
+
+    /// Cancels the current ownership proposal. **Only executable by the current contract owner.**
+    pub fn execute_cancel_ownership_proposal(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        ensure!(
+            self.pending_owner.exists(deps.storage),
+            ContractError::NoPendingOwner {}
+        );
+        self.clear_pending_owner(deps.storage);
+
+        Ok(Response::new().add_attributes(vec![attr("action", "cancel_ownership_proposal")]))
+    }
+
+    /// Removes the contract owner permanently. **Only executable by the current contract owner.**
+    pub fn execute_renounce_ownership(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        self.owner.remove(deps.storage);
+        self.clear_pending_owner(deps.storage);
+
+        Ok(Response::new().add_attributes(vec![attr("action", "renounce_ownership")]))
+    }
+
+    fn clear_pending_owner(&self, storage: &mut dyn Storage) {
+        self.pending_owner.remove(storage);
+        self.pending_owner_expiration.remove(storage);
+    }
+
     /// Updates the current contract operators. **Only executable by the current contract owner.**
     pub fn execute_update_operators(
         &self,
@@ -57,8 +163,8 @@ impl<'a> ADOContract<'a> {
         storage: &dyn Storage,
         addr: &str,
     ) -> Result<bool, ContractError> {
-        let owner = self.owner.load(storage)?;
-        Ok(addr == owner)
+        let owner = self.owner.may_load(storage)?;
+        Ok(owner.map_or(false, |owner| addr == owner))
     }
 
     /// Helper function to query if a given address is the current contract owner or operator.
@@ -72,3 +178,130 @@ impl<'a> ADOContract<'a> {
         Ok(self.is_contract_owner(storage, addr)? || self.is_operator(storage, addr))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ado_base::InstantiateMsg;
+    use crate::testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Deps;
+
+    fn init(deps: DepsMut) {
+        ADOContract::default()
+            .instantiate(
+                deps.storage,
+                mock_env(),
+                deps.api,
+                mock_info("owner", &[]),
+                InstantiateMsg {
+                    ado_type: "type".to_string(),
+                    ado_version: "version".to_string(),
+                    operators: None,
+                    kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+                    owner: None,
+                    metadata: None,
+                },
+            )
+            .unwrap();
+    }
+
+    fn owner(deps: Deps) -> String {
+        ADOContract::default()
+            .query_contract_owner(deps)
+            .unwrap()
+            .owner
+    }
+
+    #[test]
+    fn test_propose_and_accept_ownership() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+
+        let new_owner = AndrAddr::from_string("new_owner");
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), mock_env());
+        let err = contract
+            .execute_propose_owner(ctx, new_owner.clone(), None)
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        let expiration = Expiration::AtHeight(mock_env().block.height + 10);
+        contract
+            .execute_propose_owner(ctx, new_owner, Some(expiration))
+            .unwrap();
+        let pending = contract.query_pending_owner(deps.as_ref()).unwrap();
+        assert_eq!(pending.pending_owner, Some("new_owner".to_string()));
+        assert_eq!(pending.expiration, Some(expiration));
+        // Ownership is not transferred until accepted
+        assert_eq!(owner(deps.as_ref()), "owner");
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), mock_env());
+        let err = contract.execute_accept_ownership(ctx).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let mut env = mock_env();
+        env.block.height += 10;
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("new_owner", &[]), env);
+        let err = contract.execute_accept_ownership(ctx).unwrap_err();
+        assert_eq!(err, ContractError::Expired {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("new_owner", &[]), mock_env());
+        contract.execute_accept_ownership(ctx).unwrap();
+        assert_eq!(owner(deps.as_ref()), "new_owner");
+        let pending = contract.query_pending_owner(deps.as_ref()).unwrap();
+        assert_eq!(pending.pending_owner, None);
+        assert_eq!(pending.expiration, None);
+    }
+
+    #[test]
+    fn test_cancel_ownership_proposal() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+
+        let err = contract
+            .execute_cancel_ownership_proposal(deps.as_mut(), mock_info("owner", &[]))
+            .unwrap_err();
+        assert_eq!(err, ContractError::NoPendingOwner {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_propose_owner(ctx, AndrAddr::from_string("new_owner"), None)
+            .unwrap();
+        contract
+            .execute_cancel_ownership_proposal(deps.as_mut(), mock_info("owner", &[]))
+            .unwrap();
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("new_owner", &[]), mock_env());
+        let err = contract.execute_accept_ownership(ctx).unwrap_err();
+        assert_eq!(err, ContractError::NoPendingOwner {});
+        assert_eq!(owner(deps.as_ref()), "owner");
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+
+        let err = contract
+            .execute_renounce_ownership(deps.as_mut(), mock_info("anyone", &[]))
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        contract
+            .execute_renounce_ownership(deps.as_mut(), mock_info("owner", &[]))
+            .unwrap();
+        assert_eq!(owner(deps.as_ref()), "");
+        assert!(!contract
+            .is_contract_owner(deps.as_ref().storage, "owner")
+            .unwrap());
+
+        let err = contract
+            .execute_update_owner(deps.as_mut(), mock_info("owner", &[]), "owner".to_string())
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    ado_base::pausing::IsPausedResponse, common::context::ExecuteContext, error::ContractError,
+};
+use cosmwasm_std::{ensure, Deps, Order, Response, Storage};
+
+use super::ADOContract;
+
+/// Role granting permission to pause and unpause the ADO alongside the owner and app contract
+pub const PAUSER_ROLE: &str = "pauser";
+
+/// Messages that remain available while paused so the ADO can always be administered and recovered
+///
+/// AMP packets and batches are checked against the messages they carry once unpacked
+const UNPAUSABLE_ACTIONS: &[&str] = &[
+    "AMPReceive",
+    "Batch",
+    "Pause",
+    "Unpause",
+    "PauseComponents",
+    "UnpauseComponents",
+    "UpdateOwner",
+    "ProposeOwner",
+    "AcceptOwnership",
+    "CancelOwnershipProposal",
+    "RenounceOwnership",
+    "UpdateOperators",
+    "UpdateAppContract",
+    "SetPermission",
+    "RemovePermission",
+    "PermissionAction",
+    "SetActionAddressList",
+    "SetActionMerkleRoot",
+    "GrantRole",
+    "RevokeRole",
+    "SetActionRole",
+    "RegisterModule",
+    "DeregisterModule",
+    "AlterModule",
+    "SetModuleEnabled",
+    "UpdateMetadata",
+];
+
+impl<'a> ADOContract<'a> {
+    /// Determines if the given action is paused, either individually or by pausing every action
+    pub fn is_paused(&self, storage: &dyn Storage, action: &str) -> Result<bool, ContractError> {
+        if UNPAUSABLE_ACTIONS.contains(&action) {
+            return Ok(false);
+        }
+        Ok(self.paused.may_load(storage)?.unwrap_or(false)
+            || self.paused_actions.has(storage, action))
+    }
+
+    /// Returns an error if the given action is paused
+    pub fn ensure_not_paused(
+        &self,
+        storage: &dyn Storage,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            !self.is_paused(storage, action)?,
+            ContractError::ActionPaused {
+                action: action.to_string()
+            }
+        );
+        Ok(())
+    }
+
+    fn can_pause(&self, storage: &dyn Storage, sender: &str) -> Result<bool, ContractError> {
+        let is_app_contract = self
+            .get_app_contract(storage)?
+            .map_or(false, |app_contract| app_contract == sender);
+        Ok(is_app_contract || self.is_owner_or_role(storage, PAUSER_ROLE, sender)?)
+    }
+
+    /// Execute handler for pausing actions, pauses every action if none are provided
+    ///
+    /// Available to the owner, holders of the pauser role and the app contract
+    pub fn execute_pause(
+        &self,
+        ctx: ExecuteContext,
+        actions: Option<Vec<String>>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.can_pause(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let paused = match actions {
+            Some(actions) => {
+                for action in actions.iter() {
+                    self.paused_actions.save(ctx.deps.storage, action, &true)?;
+                }
+                actions.join(",")
+            }
+            None => {
+                self.paused.save(ctx.deps.storage, &true)?;
+                "all".to_string()
+            }
+        };
+
+        Ok(Response::default()
+            .add_attributes(vec![("action", "pause"), ("paused", paused.as_str())]))
+    }
+
+    /// Execute handler for unpausing actions, unpauses every action if none are provided
+    ///
+    /// Available to the owner, holders of the pauser role and the app contract
+    pub fn execute_unpause(
+        &self,
+        ctx: ExecuteContext,
+        actions: Option<Vec<String>>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.can_pause(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let unpaused = match actions {
+            Some(actions) => {
+                for action in actions.iter() {
+                    self.paused_actions.remove(ctx.deps.storage, action);
+                }
+                actions.join(",")
+            }
+            None => {
+                let paused_actions = self.load_paused_actions(ctx.deps.storage)?;
+                for action in paused_actions {
+                    self.paused_actions.remove(ctx.deps.storage, &action);
+                }
+                self.paused.remove(ctx.deps.storage);
+                "all".to_string()
+            }
+        };
+
+        Ok(Response::default()
+            .add_attributes(vec![("action", "unpause"), ("unpaused", unpaused.as_str())]))
+    }
+
+    fn load_paused_actions(&self, storage: &dyn Storage) -> Result<Vec<String>, ContractError> {
+        let actions = self
+            .paused_actions
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(actions)
+    }
+
+    pub fn query_is_paused(
+        &self,
+        deps: Deps,
+        action: Option<String>,
+    ) -> Result<IsPausedResponse, ContractError> {
+        let all_paused = self.paused.may_load(deps.storage)?.unwrap_or(false);
+        let is_paused = match action {
+            Some(action) => self.is_paused(deps.storage, &action)?,
+            None => all_paused,
+        };
+        Ok(IsPausedResponse {
+            is_paused,
+            all_paused,
+            paused_actions: self.load_paused_actions(deps.storage)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, DepsMut,
+    };
+
+    use crate::testing::mock_querier::{mock_dependencies_custom, MOCK_APP_CONTRACT};
+
+    use super::*;
+
+    fn init(deps: DepsMut) {
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .app_contract
+            .save(deps.storage, &Addr::unchecked(MOCK_APP_CONTRACT))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pause_all() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), mock_env());
+        let err = contract.execute_pause(ctx, None).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info(MOCK_APP_CONTRACT, &[]), mock_env());
+        contract.execute_pause(ctx, None).unwrap();
+
+        let err = contract
+            .ensure_not_paused(deps.as_ref().storage, "Send")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ActionPaused {
+                action: "Send".to_string()
+            }
+        );
+        // Administrative messages remain available
+        contract
+            .ensure_not_paused(deps.as_ref().storage, "UpdateOwner")
+            .unwrap();
+        contract
+            .ensure_not_paused(deps.as_ref().storage, "Unpause")
+            .unwrap();
+
+        let res = contract.query_is_paused(deps.as_ref(), None).unwrap();
+        assert!(res.is_paused);
+        assert!(res.all_paused);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract.execute_unpause(ctx, None).unwrap();
+        contract
+            .ensure_not_paused(deps.as_ref().storage, "Send")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pause_actions() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+        contract
+            .grant_role(
+                deps.as_mut().storage,
+                PAUSER_ROLE,
+                &Addr::unchecked("pauser"),
+            )
+            .unwrap();
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("pauser", &[]), mock_env());
+        contract
+            .execute_pause(ctx, Some(vec!["Send".to_string(), "Mint".to_string()]))
+            .unwrap();
+        assert!(contract.is_paused(deps.as_ref().storage, "Send").unwrap());
+        assert!(!contract.is_paused(deps.as_ref().storage, "Burn").unwrap());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("pauser", &[]), mock_env());
+        contract
+            .execute_unpause(ctx, Some(vec!["Send".to_string()]))
+            .unwrap();
+
+        let res = contract
+            .query_is_paused(deps.as_ref(), Some("Mint".to_string()))
+            .unwrap();
+        assert_eq!(
+            res,
+            IsPausedResponse {
+                is_paused: true,
+                all_paused: false,
+                paused_actions: vec!["Mint".to_string()],
+            }
+        );
+
+        // Unpausing everything clears individually paused actions
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract.execute_unpause(ctx, None).unwrap();
+        let res = contract.query_is_paused(deps.as_ref(), None).unwrap();
+        assert!(res.paused_actions.is_empty());
+    }
+}
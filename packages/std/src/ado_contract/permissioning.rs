@@ -3,12 +3,26 @@ use crate::{
     amp::{messages::AMPPkt, AndrAddr},
     common::context::ExecuteContext,
     error::ContractError,
+    os::aos_querier::{AOSQuerier, ResolvedAddresses},
 };
-use cosmwasm_std::{ensure, Deps, Env, MessageInfo, Order, Response, Storage};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, Storage};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 
 use super::ADOContract;
 
+/// Address list queries used to resolve permissions for actions bound to an address list
+#[cw_serde]
+pub(crate) enum AddressListQueryMsg {
+    IncludesAddress { address: String },
+    IsInclusive {},
+}
+
+#[cw_serde]
+pub(crate) struct IncludesAddressResponse {
+    pub included: bool,
+}
+
 const MAX_QUERY_LIMIT: u32 = 50;
 const DEFAULT_QUERY_LIMIT: u32 = 25;
 
@@ -39,10 +53,13 @@ pub fn permissions<'a>() -> IndexedMap<'a, &'a str, PermissionInfo, PermissionsI
 impl<'a> ADOContract<'a> {
     /// Determines if the provided actor is authorised to perform the given action
     ///
+    /// If the action is bound to a role, actors holding the role are permitted and any other actor
+    /// needs a permission for the action
+    ///
     /// Returns an error if the given action is not permissioned for the given actor
     pub fn is_permissioned(
         &self,
-        store: &mut dyn Storage,
+        deps: DepsMut,
         env: Env,
         action: impl Into<String>,
         actor: impl Into<String>,
@@ -51,11 +68,19 @@ impl<'a> ADOContract<'a> {
         let action_string: String = action.into();
         let actor_string: String = actor.into();
 
-        if self.is_contract_owner(store, actor_string.as_str())? {
+        if self.is_contract_owner(deps.storage, actor_string.as_str())? {
             return Ok(());
         }
 
-        let permission = Self::get_permission(store, action_string.clone(), actor_string.clone())?;
+        let has_action_role =
+            self.check_action_role(deps.storage, action_string.as_str(), actor_string.as_str())?;
+        let is_role_bound = self.action_roles.has(deps.storage, action_string.as_str());
+        let permission = self.get_actor_permission(
+            deps.as_ref(),
+            action_string.as_str(),
+            actor_string.as_str(),
+        )?;
+        let store = deps.storage;
         let permissioned_action = self
             .permissioned_actions
             .may_load(store, action_string.clone())?
@@ -67,9 +92,12 @@ impl<'a> ADOContract<'a> {
                     ContractError::Unauthorized {}
                 );
 
-                // Consume a use for a limited permission
-                if let Permission::Limited { .. } = permission {
-                    permission.consume_use();
+                // Consume a use for a limited or rate limited permission
+                if matches!(
+                    permission,
+                    Permission::Limited { .. } | Permission::RateLimited { .. }
+                ) {
+                    permission.consume_use(&env);
                     permissions().save(
                         store,
                         (action_string.clone() + actor_string.as_str()).as_str(),
@@ -84,7 +112,10 @@ impl<'a> ADOContract<'a> {
                 Ok(())
             }
             None => {
-                ensure!(!permissioned_action, ContractError::Unauthorized {});
+                ensure!(
+                    has_action_role || !(permissioned_action || is_role_bound),
+                    ContractError::Unauthorized {}
+                );
                 Ok(())
             }
         }
@@ -94,10 +125,11 @@ impl<'a> ADOContract<'a> {
     ///
     /// **Ignores the `PERMISSIONED_ACTIONS` map**
     ///
-    /// Returns an error if the permission has expired or if no permission exists for a restricted ADO
+    /// Returns an error if the permission has expired or if no permission exists for a restricted ADO,
+    /// holding the role bound to the action counts as a permission
     pub fn is_permissioned_strict(
         &self,
-        store: &mut dyn Storage,
+        deps: DepsMut,
         env: Env,
         action: impl Into<String>,
         actor: impl Into<String>,
@@ -106,11 +138,18 @@ impl<'a> ADOContract<'a> {
         let action_string: String = action.into();
         let actor_string: String = actor.into();
 
-        if self.is_contract_owner(store, actor_string.as_str())? {
+        if self.is_contract_owner(deps.storage, actor_string.as_str())? {
             return Ok(());
         }
 
-        let permission = Self::get_permission(store, action_string.clone(), actor_string.clone())?;
+        let has_action_role =
+            self.check_action_role(deps.storage, action_string.as_str(), actor_string.as_str())?;
+        let permission = self.get_actor_permission(
+            deps.as_ref(),
+            action_string.as_str(),
+            actor_string.as_str(),
+        )?;
+        let store = deps.storage;
         match permission {
             Some(mut permission) => {
                 ensure!(
@@ -118,9 +157,12 @@ impl<'a> ADOContract<'a> {
                     ContractError::Unauthorized {}
                 );
 
-                // Consume a use for a limited permission
-                if let Permission::Limited { .. } = permission {
-                    permission.consume_use();
+                // Consume a use for a limited or rate limited permission
+                if matches!(
+                    permission,
+                    Permission::Limited { .. } | Permission::RateLimited { .. }
+                ) {
+                    permission.consume_use(&env);
                     permissions().save(
                         store,
                         (action_string.clone() + actor_string.as_str()).as_str(),
@@ -134,10 +176,59 @@ impl<'a> ADOContract<'a> {
 
                 Ok(())
             }
-            None => Err(ContractError::Unauthorized {}),
+            None => {
+                ensure!(has_action_role, ContractError::Unauthorized {});
+                Ok(())
+            }
         }
     }
 
+    /// Gets the permission for the given action and actor, falling back to the permission implied by a
+    /// proven membership of the merkle root or the address list bound to the action if no permission has been set for the actor
+    ///
+    /// An inclusive list whitelists included addresses while an exclusive list blacklists them and
+    /// whitelists every other address
+    pub fn get_actor_permission(
+        &self,
+        deps: Deps,
+        action: &str,
+        actor: &str,
+    ) -> Result<Option<Permission>, ContractError> {
+        let permission = Self::get_permission(deps.storage, action, actor)?;
+        if permission.is_some() {
+            return Ok(permission);
+        }
+        let permission = self.get_merkle_permission(deps.storage, action, actor)?;
+        if permission.is_some() {
+            return Ok(permission);
+        }
+        let address_list = match self.action_address_lists.may_load(deps.storage, action)? {
+            Some(address_list) => address_list,
+            None => return Ok(None),
+        };
+        let mut resolved = ResolvedAddresses::default();
+        let IncludesAddressResponse { included } = AOSQuerier::query_ado(
+            &deps,
+            &mut resolved,
+            &address_list,
+            &AddressListQueryMsg::IncludesAddress {
+                address: actor.to_string(),
+            },
+        )?;
+        let is_inclusive: bool = AOSQuerier::query_ado(
+            &deps,
+            &mut resolved,
+            &address_list,
+            &AddressListQueryMsg::IsInclusive {},
+        )?;
+        let permission = match (is_inclusive, included) {
+            (true, true) | (false, false) => Some(Permission::whitelisted(None)),
+            (true, false) => None,
+            (false, true) => Some(Permission::blacklisted(None)),
+        };
+        Ok(permission)
+    }
+
     /// Gets the permission for the given action and actor
     pub fn get_permission(
         store: &dyn Storage,
@@ -204,6 +295,19 @@ impl<'a> ADOContract<'a> {
         Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?;
         let actor_addr = actor.get_raw_address(&ctx.deps.as_ref())?;
         let action = action.into();
+        // Any provided window state is discarded so rate limited permissions start with a full window
+        let permission = match permission {
+            Permission::RateLimited {
+                expiration,
+                uses,
+                window,
+                ..
+            } => {
+                ensure!(uses > 0 && window > 0, ContractError::InvalidRateLimit {});
+                Permission::rate_limited(expiration, uses, window)
+            }
+            permission => permission,
+        };
         Self::set_permission(
             ctx.deps.storage,
             action.clone(),
@@ -283,10 +387,55 @@ impl<'a> ADOContract<'a> {
         ]))
     }
 
+    /// Execute handler for binding an address list to an action, only available to the contract owner
+    ///
+    /// Changes to the list apply immediately to every ADO using it
+    pub fn execute_set_action_address_list(
+        &self,
+        ctx: ExecuteContext,
+        action: String,
+        address_list: Option<AndrAddr>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let list_attr = match &address_list {
+            Some(address_list) => {
+                // Ensure the list can be resolved
+                address_list.get_raw_address(&ctx.deps.as_ref())?;
+                self.action_address_lists
+                    .save(ctx.deps.storage, &action, address_list)?;
+                address_list.to_string()
+            }
+            None => {
+                self.action_address_lists.remove(ctx.deps.storage, &action);
+                "none".to_string()
+            }
+        };
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "set_action_address_list"),
+            ("target_action", action.as_str()),
+            ("address_list", list_attr.as_str()),
+        ]))
+    }
+
+    pub fn query_action_address_list(
+        &self,
+        deps: Deps,
+        action: String,
+    ) -> Result<Option<AndrAddr>, ContractError> {
+        Ok(self.action_address_lists.may_load(deps.storage, &action)?)
+    }
+
     /// Queries all permissions for a given actor
+    ///
+    /// Rate limited permissions reflect the uses remaining in the current window
     pub fn query_permissions(
         &self,
         deps: Deps,
+        env: &Env,
         actor: impl Into<String>,
         limit: Option<u32>,
         start_after: Option<String>,
@@ -300,7 +449,11 @@ impl<'a> ADOContract<'a> {
             .prefix(actor)
             .range(deps.storage, min, None, Order::Ascending)
             .take(limit)
-            .map(|p| p.unwrap().1)
+            .map(|p| {
+                let mut info = p.unwrap().1;
+                info.permission.refresh_window(env);
+                info
+            })
             .collect::<Vec<PermissionInfo>>();
         Ok(permissions)
     }
@@ -315,13 +468,30 @@ impl<'a> ADOContract<'a> {
     }
 }
 
+/// Caches the origin's membership of the merkle root bound to the action if the context contains a proof
+///
+/// Invalid proofs are ignored and leave the origin's permissions unchanged
+fn prove_context_membership(
+    deps: &mut DepsMut,
+    contract: &ADOContract,
+    amp_ctx: &AMPPkt,
+    action: &str,
+) {
+    if let Some(proof) = amp_ctx.ctx.get_merkle_proof() {
+        let origin = Addr::unchecked(amp_ctx.ctx.get_origin());
+        contract
+            .prove_membership(deps.storage, action, &origin, proof)
+            .ok();
+    }
+}
+
 /// Checks if the provided context is authorised to perform the provided action.
 ///
 /// Two scenarios exist:
 /// - The context does not contain any AMP context and the **sender** is the actor
 /// - The context contains AMP context and the **previous sender** or **origin** are considered the actor
 pub fn is_context_permissioned(
-    storage: &mut dyn Storage,
+    deps: &mut DepsMut,
     info: &MessageInfo,
     env: &Env,
     ctx: &Option<AMPPkt>,
@@ -332,14 +502,15 @@ pub fn is_context_permissioned(
     match ctx {
         Some(amp_ctx) => {
             let action: String = action.into();
+            prove_context_membership(deps, &contract, amp_ctx, &action);
             let is_origin_permissioned = contract.is_permissioned(
-                storage,
+                deps.branch(),
                 env.clone(),
                 action.clone(),
                 amp_ctx.ctx.get_origin().as_str(),
             );
             let is_previous_sender_permissioned = contract.is_permissioned(
-                storage,
+                deps.branch(),
                 env.clone(),
                 action,
                 amp_ctx.ctx.get_previous_sender().as_str(),
@@ -347,7 +518,7 @@ pub fn is_context_permissioned(
             Ok(is_origin_permissioned.is_ok() || is_previous_sender_permissioned.is_ok())
         }
         None => Ok(contract
-            .is_permissioned(storage, env.clone(), action, info.sender.to_string())
+            .is_permissioned(deps.branch(), env.clone(), action, info.sender.to_string())
             .is_ok()),
     }
 }
@@ -358,7 +529,7 @@ pub fn is_context_permissioned(
 /// - The context does not contain any AMP context and the **sender** is the actor
 /// - The context contains AMP context and the **previous sender** or **origin** are considered the actor
 pub fn is_context_permissioned_strict(
-    storage: &mut dyn Storage,
+    deps: &mut DepsMut,
     info: &MessageInfo,
     env: &Env,
     ctx: &Option<AMPPkt>,
@@ -369,14 +540,15 @@ pub fn is_context_permissioned_strict(
     match ctx {
         Some(amp_ctx) => {
             let action: String = action.into();
+            prove_context_membership(deps, &contract, amp_ctx, &action);
             let is_origin_permissioned = contract.is_permissioned_strict(
-                storage,
+                deps.branch(),
                 env.clone(),
                 action.clone(),
                 amp_ctx.ctx.get_origin().as_str(),
             );
             let is_previous_sender_permissioned = contract.is_permissioned_strict(
-                storage,
+                deps.branch(),
                 env.clone(),
                 action,
                 amp_ctx.ctx.get_previous_sender().as_str(),
@@ -384,7 +556,7 @@ pub fn is_context_permissioned_strict(
             Ok(is_origin_permissioned.is_ok() || is_previous_sender_permissioned.is_ok())
         }
         None => Ok(contract
-            .is_permissioned_strict(storage, env.clone(), action, info.sender.to_string())
+            .is_permissioned_strict(deps.branch(), env.clone(), action, info.sender.to_string())
             .is_ok()),
     }
 }
@@ -397,7 +569,12 @@ mod tests {
     };
     use cw_utils::Expiration;
 
-    use crate::amp::messages::AMPPkt;
+    use crate::{
+        amp::messages::AMPPkt,
+        testing::mock_querier::{
+            mock_dependencies_custom, MOCK_ADDRESS_LIST_CONTRACT, UNWHITELISTED_ADDRESS,
+        },
+    };
 
     use super::*;
 
@@ -418,31 +595,31 @@ mod tests {
             .unwrap();
 
         // Test Whitelisting
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
 
         assert!(res.is_err());
         let permission = Permission::whitelisted(None);
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
 
         assert!(res.is_ok());
 
         ADOContract::remove_permission(deps.as_mut().storage, action, actor).unwrap();
 
         // Test Limited
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
 
         assert!(res.is_err());
         let permission = Permission::limited(None, 1);
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
 
         assert!(res.is_ok());
 
         // Ensure use is consumed
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_err());
 
         ADOContract::remove_permission(deps.as_mut().storage, action, actor).unwrap();
@@ -451,7 +628,7 @@ mod tests {
         let permission = Permission::blacklisted(None);
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env, action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env, action, actor);
 
         assert!(res.is_err());
     }
@@ -476,7 +653,7 @@ mod tests {
         let permission = Permission::blacklisted(None);
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env, action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env, action, actor);
 
         assert!(res.is_err());
     }
@@ -493,14 +670,13 @@ mod tests {
             .save(deps.as_mut().storage, &Addr::unchecked("owner"))
             .unwrap();
 
-        let res =
-            contract.is_permissioned_strict(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned_strict(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_err());
 
         let permission = Permission::whitelisted(None);
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned_strict(deps.as_mut().storage, env, action, actor);
+        let res = contract.is_permissioned_strict(deps.as_mut(), env, action, actor);
         assert!(res.is_ok());
     }
 
@@ -516,11 +692,10 @@ mod tests {
             .save(deps.as_mut().storage, &Addr::unchecked(actor))
             .unwrap();
 
-        let res =
-            contract.is_permissioned_strict(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned_strict(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_ok());
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env, action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env, action, actor);
         assert!(res.is_ok());
     }
 
@@ -543,7 +718,7 @@ mod tests {
             .permission_action(action, deps.as_mut().storage)
             .unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
 
         assert!(res.is_err());
 
@@ -551,12 +726,12 @@ mod tests {
         let permission = Permission::Whitelisted(Some(expiration));
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_ok());
 
         env.block.height = block + 1;
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_err());
 
         env.block.height = 0;
@@ -564,15 +739,157 @@ mod tests {
         let permission = Permission::Blacklisted(Some(expiration));
         ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env.clone(), action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
         assert!(res.is_err());
 
         env.block.height = block + 1;
 
-        let res = contract.is_permissioned(deps.as_mut().storage, env, action, actor);
+        let res = contract.is_permissioned(deps.as_mut(), env, action, actor);
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_rate_limited_permission() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let action = "action";
+        let actor = "actor";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(action, deps.as_mut().storage)
+            .unwrap();
+
+        // A rate limit needs uses to grant and a window to grant them in
+        for (uses, window) in [(0, 86400), (2, 0)] {
+            let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+            let err = contract
+                .execute_set_permission(
+                    ctx,
+                    AndrAddr::from_string(actor),
+                    action,
+                    Permission::rate_limited(None, uses, window),
+                )
+                .unwrap_err();
+            assert_eq!(err, ContractError::InvalidRateLimit {});
+        }
+
+        let window = 86400;
+        let permission = Permission::rate_limited(None, 2, window);
+        ADOContract::set_permission(deps.as_mut().storage, action, actor, permission).unwrap();
+
+        let window_start = env.block.time;
+        for _ in 0..2 {
+            contract
+                .is_permissioned(deps.as_mut(), env.clone(), action, actor)
+                .unwrap();
+        }
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+        assert!(res.is_err());
+
+        let permissions = contract
+            .query_permissions(deps.as_ref(), &env, actor, None, None)
+            .unwrap();
+        assert_eq!(
+            permissions[0].permission.current_window(&env),
+            (0, Some(window_start.plus_seconds(window)))
+        );
+
+        // Uses are restored once the window has elapsed
+        env.block.time = window_start.plus_seconds(window);
+        let permissions = contract
+            .query_permissions(deps.as_ref(), &env, actor, None, None)
+            .unwrap();
+        assert_eq!(
+            permissions[0].permission,
+            Permission::RateLimited {
+                expiration: None,
+                uses: 2,
+                window,
+                remaining: 2,
+                resets_at: None,
+            }
+        );
+
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, actor)
+            .unwrap();
+        let permission = ADOContract::get_permission(deps.as_ref().storage, action, actor)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            permission.current_window(&env),
+            (1, Some(env.block.time.plus_seconds(window)))
+        );
+    }
+
+    #[test]
+    fn test_address_list_permission() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        let action = "action";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(action, deps.as_mut().storage)
+            .unwrap();
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), env.clone());
+        let err = contract
+            .execute_set_action_address_list(
+                ctx,
+                action.to_string(),
+                Some(AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT)),
+            )
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_address_list(
+                ctx,
+                action.to_string(),
+                Some(AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT)),
+            )
+            .unwrap();
+
+        // Addresses included in the list are whitelisted
+        contract
+            .is_permissioned_strict(deps.as_mut(), env.clone(), action, "actor")
+            .unwrap();
+        let res =
+            contract.is_permissioned(deps.as_mut(), env.clone(), action, UNWHITELISTED_ADDRESS);
+        assert!(res.is_err());
+
+        // Permissions set for an actor take precedence over the list
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            action,
+            "actor",
+            Permission::blacklisted(None),
+        )
+        .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, "actor");
+        assert!(res.is_err());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_action_address_list(ctx, action.to_string(), None)
+            .unwrap();
+        assert!(contract
+            .query_action_address_list(deps.as_ref(), action.to_string())
+            .unwrap()
+            .is_none());
+        let res = contract.is_permissioned(deps.as_mut(), env, action, "other_actor");
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_context_permissions() {
         let mut deps = mock_dependencies();
@@ -581,7 +898,7 @@ mod tests {
         let info = mock_info(actor, &[]);
         let action = "action";
 
-        let context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
         let contract = ADOContract::default();
 
         contract
@@ -590,7 +907,7 @@ mod tests {
             .unwrap();
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -598,13 +915,13 @@ mod tests {
         )
         .unwrap());
 
-        let context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
         ADOContract::default()
             .permission_action(action, context.deps.storage)
             .unwrap();
 
         assert!(!is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -612,12 +929,12 @@ mod tests {
         )
         .unwrap());
 
-        let context = ExecuteContext::new(deps.as_mut(), info, env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), info, env.clone());
         let permission = Permission::whitelisted(None);
         ADOContract::set_permission(context.deps.storage, action, actor, permission).unwrap();
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -626,10 +943,10 @@ mod tests {
         .unwrap());
 
         let unauth_info = mock_info("mock_actor", &[]);
-        let context = ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone());
 
         assert!(!is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -638,11 +955,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("mock_actor", actor, vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -651,11 +968,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new(actor, "mock_actor", vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -664,11 +981,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("mock_actor", "mock_actor", vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(!is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -677,11 +994,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("owner", "mock_actor", vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -690,10 +1007,10 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("mock_actor", "owner", vec![]);
-        let context = ExecuteContext::new(deps.as_mut(), unauth_info, env).with_ctx(amp_ctx);
+        let mut context = ExecuteContext::new(deps.as_mut(), unauth_info, env).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -710,7 +1027,7 @@ mod tests {
         let info = mock_info(actor, &[]);
         let action = "action";
 
-        let context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), info.clone(), env.clone());
         let contract = ADOContract::default();
 
         contract
@@ -719,7 +1036,7 @@ mod tests {
             .unwrap();
 
         assert!(!is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -727,12 +1044,12 @@ mod tests {
         )
         .unwrap());
 
-        let context = ExecuteContext::new(deps.as_mut(), info, env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), info, env.clone());
         let permission = Permission::whitelisted(None);
         ADOContract::set_permission(context.deps.storage, action, actor, permission).unwrap();
 
         assert!(is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -741,10 +1058,10 @@ mod tests {
         .unwrap());
 
         let unauth_info = mock_info("mock_actor", &[]);
-        let context = ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone());
+        let mut context = ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone());
 
         assert!(!is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -753,11 +1070,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("mock_actor", actor, vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -766,11 +1083,11 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new(actor, "mock_actor", vec![]);
-        let context =
+        let mut context =
             ExecuteContext::new(deps.as_mut(), unauth_info.clone(), env.clone()).with_ctx(amp_ctx);
 
         assert!(is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -779,10 +1096,10 @@ mod tests {
         .unwrap());
 
         let amp_ctx = AMPPkt::new("mock_actor", "mock_actor", vec![]);
-        let context = ExecuteContext::new(deps.as_mut(), unauth_info, env).with_ctx(amp_ctx);
+        let mut context = ExecuteContext::new(deps.as_mut(), unauth_info, env).with_ctx(amp_ctx);
 
         assert!(!is_context_permissioned_strict(
-            context.deps.storage,
+            &mut context.deps,
             &context.info,
             &context.env,
             &context.amp_ctx,
@@ -797,7 +1114,7 @@ mod tests {
         let mut deps = mock_dependencies();
 
         let permissions = ADOContract::default()
-            .query_permissions(deps.as_ref(), actor, None, None)
+            .query_permissions(deps.as_ref(), &mock_env(), actor, None, None)
             .unwrap();
 
         assert!(permissions.is_empty());
@@ -809,7 +1126,7 @@ mod tests {
             .unwrap();
 
         let permissions = ADOContract::default()
-            .query_permissions(deps.as_ref(), actor, None, None)
+            .query_permissions(deps.as_ref(), &mock_env(), actor, None, None)
             .unwrap();
 
         assert_eq!(permissions.len(), 1);
@@ -828,7 +1145,7 @@ mod tests {
         }
 
         let permissions = ADOContract::default()
-            .query_permissions(deps.as_ref(), actor, None, None)
+            .query_permissions(deps.as_ref(), &mock_env(), actor, None, None)
             .unwrap();
 
         assert_eq!(permissions.len(), 5);
@@ -839,12 +1156,7 @@ mod tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
         let info = mock_info("owner", &[]);
-        let ctx = ExecuteContext {
-            deps: deps.as_mut(),
-            env,
-            info: info.clone(),
-            amp_ctx: None,
-        };
+        let ctx = ExecuteContext::new(deps.as_mut(), info.clone(), env);
 
         let contract = ADOContract::default();
 
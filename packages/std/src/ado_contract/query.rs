@@ -5,7 +5,7 @@ use crate::{
         block_height::BlockHeightResponse,
         kernel_address::KernelAddressResponse,
         operators::{IsOperatorResponse, OperatorsResponse},
-        ownership::{ContractOwnerResponse, PublisherResponse},
+        ownership::{ContractOwnerResponse, PendingOwnerResponse, PublisherResponse},
         version::VersionResponse,
         AndromedaQuery,
     },
@@ -20,7 +20,7 @@ impl<'a> ADOContract<'a> {
     pub fn query(
         &self,
         deps: Deps,
-        _env: Env,
+        env: Env,
         msg: impl Serialize,
     ) -> Result<Binary, ContractError> {
         let msg = to_json_binary(&msg)?;
@@ -28,6 +28,7 @@ impl<'a> ADOContract<'a> {
         match from_json::<AndromedaQuery>(&msg) {
             Ok(msg) => match msg {
                 AndromedaQuery::Owner {} => encode_binary(&self.query_contract_owner(deps)?),
+                AndromedaQuery::PendingOwner {} => encode_binary(&self.query_pending_owner(deps)?),
                 AndromedaQuery::Operators {} => encode_binary(&self.query_operators(deps)?),
                 AndromedaQuery::OriginalPublisher {} => {
                     encode_binary(&self.query_original_publisher(deps)?)
@@ -54,10 +55,34 @@ impl<'a> ADOContract<'a> {
                     actor,
                     limit,
                     start_after,
-                } => encode_binary(&self.query_permissions(deps, actor, limit, start_after)?),
+                } => {
+                    encode_binary(&self.query_permissions(deps, &env, actor, limit, start_after)?)
+                }
                 AndromedaQuery::PermissionedActions {} => {
                     encode_binary(&self.query_permissioned_actions(deps)?)
                 }
+                AndromedaQuery::ActionAddressList { action } => {
+                    encode_binary(&self.query_action_address_list(deps, action)?)
+                }
+                AndromedaQuery::ActionMerkleRoot { action } => {
+                    encode_binary(&self.query_action_merkle_root(deps, action)?)
+                }
+                AndromedaQuery::HasRole { role, address } => {
+                    encode_binary(&self.query_has_role(deps, role, address)?)
+                }
+                AndromedaQuery::RoleMembers {
+                    role,
+                    start_after,
+                    limit,
+                } => encode_binary(&self.query_role_members(deps, role, start_after, limit)?),
+                AndromedaQuery::ActionRole { action } => {
+                    encode_binary(&self.query_action_role(deps, action)?)
+                }
+                AndromedaQuery::IsPaused { action } => {
+                    encode_binary(&self.query_is_paused(deps, action)?)
+                }
+                AndromedaQuery::Migrations {} => encode_binary(&self.query_migrations(deps)?),
+                AndromedaQuery::AdoMetadata {} => encode_binary(&self.query_metadata(deps)?),
                 _ => Err(ContractError::UnsupportedOperation {}),
             },
             Err(_) => Err(ContractError::UnsupportedOperation {}),
@@ -68,10 +93,21 @@ impl<'a> ADOContract<'a> {
 impl<'a> ADOContract<'a> {
     #[inline]
     pub fn query_contract_owner(&self, deps: Deps) -> Result<ContractOwnerResponse, ContractError> {
-        let owner = self.owner.load(deps.storage)?;
+        let owner = self.owner.may_load(deps.storage)?;
 
         Ok(ContractOwnerResponse {
-            owner: owner.to_string(),
+            owner: owner.map(|owner| owner.to_string()).unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn query_pending_owner(&self, deps: Deps) -> Result<PendingOwnerResponse, ContractError> {
+        let pending_owner = self.pending_owner.may_load(deps.storage)?;
+        let expiration = self.pending_owner_expiration.may_load(deps.storage)?;
+
+        Ok(PendingOwnerResponse {
+            pending_owner: pending_owner.map(|owner| owner.to_string()),
+            expiration,
         })
     }
 
@@ -0,0 +1,373 @@
+use crate::{
+    ado_base::roles::HasRoleResponse,
+    amp::{messages::AMPPkt, AndrAddr},
+    common::context::ExecuteContext,
+    error::ContractError,
+};
+use cosmwasm_std::{ensure, Addr, Deps, MessageInfo, Order, Response, Storage};
+use cw_storage_plus::Bound;
+
+use super::ADOContract;
+
+const MAX_QUERY_LIMIT: u32 = 50;
+const DEFAULT_QUERY_LIMIT: u32 = 25;
+
+impl<'a> ADOContract<'a> {
+    /// Determines if the given address holds the given role
+    pub fn has_role(
+        &self,
+        storage: &dyn Storage,
+        role: &str,
+        address: &str,
+    ) -> Result<bool, ContractError> {
+        Ok(self.roles.has(storage, (role, &Addr::unchecked(address))))
+    }
+
+    /// Determines if the given address is the contract owner or holds the given role
+    pub fn is_owner_or_role(
+        &self,
+        storage: &dyn Storage,
+        role: &str,
+        address: &str,
+    ) -> Result<bool, ContractError> {
+        Ok(self.is_contract_owner(storage, address)? || self.has_role(storage, role, address)?)
+    }
+
+    /// Grants the given role to the given address
+    pub fn grant_role(
+        &self,
+        storage: &mut dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> Result<(), ContractError> {
+        ensure!(!role.is_empty(), ContractError::InvalidRole {});
+        self.roles.save(storage, (role, address), &true)?;
+        Ok(())
+    }
+
+    /// Revokes the given role from the given address
+    pub fn revoke_role(&self, storage: &mut dyn Storage, role: &str, address: &Addr) {
+        self.roles.remove(storage, (role, address));
+    }
+
+    /// Determines if the actor holds the role bound to the given action, `false` if no role is bound
+    ///
+    /// Holding the role is one way of being permitted the action, callers combine it with the actor's
+    /// other permissions
+    pub(crate) fn check_action_role(
+        &self,
+        storage: &dyn Storage,
+        action: &str,
+        actor: &str,
+    ) -> Result<bool, ContractError> {
+        match self.action_roles.may_load(storage, action)? {
+            Some(role) => self.has_role(storage, &role, actor),
+            None => Ok(false),
+        }
+    }
+
+    /// Execute handler for granting a role, only available to the contract owner
+    pub fn execute_grant_role(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        actor: AndrAddr,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let actor_addr = actor.get_raw_address(&ctx.deps.as_ref())?;
+        self.grant_role(ctx.deps.storage, &role, &actor_addr)?;
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "grant_role"),
+            ("role", role.as_str()),
+            ("actor", actor_addr.as_str()),
+        ]))
+    }
+
+    /// Execute handler for revoking a role, only available to the contract owner
+    pub fn execute_revoke_role(
+        &self,
+        ctx: ExecuteContext,
+        role: String,
+        actor: AndrAddr,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let actor_addr = actor.get_raw_address(&ctx.deps.as_ref())?;
+        self.revoke_role(ctx.deps.storage, &role, &actor_addr);
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "revoke_role"),
+            ("role", role.as_str()),
+            ("actor", actor_addr.as_str()),
+        ]))
+    }
+
+    /// Execute handler for binding an action to a role, only available to the contract owner
+    pub fn execute_set_action_role(
+        &self,
+        ctx: ExecuteContext,
+        action: String,
+        role: Option<String>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        match &role {
+            Some(role) => {
+                ensure!(!role.is_empty(), ContractError::InvalidRole {});
+                self.action_roles.save(ctx.deps.storage, &action, role)?;
+            }
+            None => self.action_roles.remove(ctx.deps.storage, &action),
+        }
+
+        Ok(Response::default().add_attributes(vec![
+            ("action", "set_action_role"),
+            ("target_action", action.as_str()),
+            ("role", role.as_deref().unwrap_or("none")),
+        ]))
+    }
+
+    pub fn query_has_role(
+        &self,
+        deps: Deps,
+        role: String,
+        address: AndrAddr,
+    ) -> Result<HasRoleResponse, ContractError> {
+        let address = address.get_raw_address(&deps)?;
+        Ok(HasRoleResponse {
+            has_role: self.has_role(deps.storage, &role, address.as_str())?,
+        })
+    }
+
+    pub fn query_role_members(
+        &self,
+        deps: Deps,
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Vec<String>, ContractError> {
+        let start_after = start_after.map(Addr::unchecked);
+        let min = start_after.as_ref().map(Bound::exclusive);
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+        let members = self
+            .roles
+            .prefix(&role)
+            .keys(deps.storage, min, None, Order::Ascending)
+            .take(limit)
+            .map(|member| Ok(member?.to_string()))
+            .collect::<Result<Vec<String>, ContractError>>()?;
+        Ok(members)
+    }
+
+    pub fn query_action_role(
+        &self,
+        deps: Deps,
+        action: String,
+    ) -> Result<Option<String>, ContractError> {
+        Ok(self.action_roles.may_load(deps.storage, &action)?)
+    }
+}
+
+/// Checks if the provided context holds the given role.
+///
+/// Two scenarios exist:
+/// - The context does not contain any AMP context and the **sender** is the actor
+/// - The context contains AMP context and the **previous sender** or **origin** are considered the actor
+pub fn has_context_role(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+    ctx: &Option<AMPPkt>,
+    role: &str,
+) -> Result<bool, ContractError> {
+    let contract = ADOContract::default();
+
+    match ctx {
+        Some(amp_ctx) => Ok(
+            contract.has_role(storage, role, amp_ctx.ctx.get_origin().as_str())?
+                || contract.has_role(storage, role, amp_ctx.ctx.get_previous_sender().as_str())?,
+        ),
+        None => contract.has_role(storage, role, info.sender.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, DepsMut,
+    };
+
+    use crate::{
+        ado_base::permissioning::Permission, testing::mock_querier::mock_dependencies_custom,
+    };
+
+    use super::*;
+
+    fn init(deps: DepsMut) {
+        ADOContract::default()
+            .owner
+            .save(deps.storage, &Addr::unchecked("owner"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        init(deps.as_mut());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("anyone", &[]), mock_env());
+        let err = contract
+            .execute_grant_role(ctx, "minter".to_string(), AndrAddr::from_string("minter"))
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        let err = contract
+            .execute_grant_role(ctx, "".to_string(), AndrAddr::from_string("minter"))
+            .unwrap_err();
+        assert_eq!(err, ContractError::InvalidRole {});
+
+        for actor in ["minter_one", "minter_two"] {
+            let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+            contract
+                .execute_grant_role(ctx, "minter".to_string(), AndrAddr::from_string(actor))
+                .unwrap();
+        }
+        assert!(contract
+            .has_role(deps.as_ref().storage, "minter", "minter_one")
+            .unwrap());
+        assert!(!contract
+            .has_role(deps.as_ref().storage, "pauser", "minter_one")
+            .unwrap());
+        assert!(contract
+            .is_owner_or_role(deps.as_ref().storage, "minter", "owner")
+            .unwrap());
+
+        let members = contract
+            .query_role_members(deps.as_ref(), "minter".to_string(), None, None)
+            .unwrap();
+        assert_eq!(members, vec!["minter_one", "minter_two"]);
+        let members = contract
+            .query_role_members(
+                deps.as_ref(),
+                "minter".to_string(),
+                Some("minter_one".to_string()),
+                None,
+            )
+            .unwrap();
+        assert_eq!(members, vec!["minter_two"]);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_revoke_role(
+                ctx,
+                "minter".to_string(),
+                AndrAddr::from_string("minter_one"),
+            )
+            .unwrap();
+        let res = contract
+            .query_has_role(
+                deps.as_ref(),
+                "minter".to_string(),
+                AndrAddr::from_string("minter_one"),
+            )
+            .unwrap();
+        assert!(!res.has_role);
+    }
+
+    #[test]
+    fn test_action_role_permissioning() {
+        let contract = ADOContract::default();
+        let mut deps = mock_dependencies_custom(&[]);
+        let env = mock_env();
+        init(deps.as_mut());
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_set_action_role(ctx, "Mint".to_string(), Some("minter".to_string()))
+            .unwrap();
+        assert_eq!(
+            contract
+                .query_action_role(deps.as_ref(), "Mint".to_string())
+                .unwrap(),
+            Some("minter".to_string())
+        );
+        contract
+            .grant_role(deps.as_mut().storage, "minter", &Addr::unchecked("minter"))
+            .unwrap();
+
+        // Owner is always permitted, role holders are permitted and others are not
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "owner")
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "minter")
+            .unwrap();
+        let err = contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "anyone")
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // Role holders are permitted for permissioned actions without an explicit permission
+        contract
+            .permission_action("Mint", deps.as_mut().storage)
+            .unwrap();
+        contract
+            .is_permissioned_strict(deps.as_mut(), env.clone(), "Mint", "minter")
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "minter")
+            .unwrap();
+
+        // Explicit permissions still apply to role holders
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "Mint",
+            "minter",
+            Permission::blacklisted(None),
+        )
+        .unwrap();
+        let err = contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "minter")
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // Actors without the role can be permitted explicitly
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "Mint",
+            "anyone",
+            Permission::whitelisted(None),
+        )
+        .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "anyone")
+            .unwrap();
+        contract
+            .is_permissioned_strict(deps.as_mut(), env.clone(), "Mint", "anyone")
+            .unwrap();
+
+        // A bound role restricts the action even if it is not otherwise permissioned
+        contract.disable_action_permission("Mint", deps.as_mut().storage);
+        let err = contract
+            .is_permissioned(deps.as_mut(), env.clone(), "Mint", "someone")
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_set_action_role(ctx, "Mint".to_string(), None)
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env, "Mint", "someone")
+            .unwrap();
+    }
+}
@@ -1,12 +1,18 @@
 #[cfg(feature = "modules")]
 use crate::ado_base::modules::Module;
+use crate::ado_base::metadata::AdoMetadata;
+use crate::ado_base::permissioning::MerkleRootInfo;
+use crate::amp::AndrAddr;
 use cosmwasm_std::Addr;
 #[cfg(feature = "withdraw")]
 use cw_asset::AssetInfo;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
 pub struct ADOContract<'a> {
     pub(crate) owner: Item<'a, Addr>,
+    pub(crate) pending_owner: Item<'a, Addr>,
+    pub(crate) pending_owner_expiration: Item<'a, Expiration>,
     pub(crate) original_publisher: Item<'a, Addr>,
     pub(crate) block_height: Item<'a, u64>,
     pub(crate) operators: Map<'a, &'a str, bool>,
@@ -15,6 +21,17 @@ pub struct ADOContract<'a> {
     pub(crate) app_contract: Item<'a, Addr>,
     pub(crate) kernel_address: Item<'a, Addr>,
     pub(crate) permissioned_actions: Map<'a, String, bool>,
+    pub(crate) roles: Map<'a, (&'a str, &'a Addr), bool>,
+    pub(crate) action_roles: Map<'a, &'a str, String>,
+    pub(crate) action_address_lists: Map<'a, &'a str, AndrAddr>,
+    pub(crate) action_merkle_roots: Map<'a, &'a str, MerkleRootInfo>,
+    /// The merkle root each address has proven membership of for an action
+    pub(crate) merkle_members: Map<'a, (&'a str, &'a Addr), String>,
+    pub(crate) paused: Item<'a, bool>,
+    pub(crate) paused_actions: Map<'a, &'a str, bool>,
+    /// Applied migration steps and the block height they were applied at
+    pub(crate) migrations: Map<'a, &'a str, u64>,
+    pub(crate) metadata: Item<'a, AdoMetadata>,
     #[cfg(feature = "modules")]
     pub(crate) module_info: Map<'a, &'a str, Module>,
     #[cfg(feature = "modules")]
@@ -27,6 +44,8 @@ impl<'a> Default for ADOContract<'a> {
     fn default() -> Self {
         ADOContract {
             owner: Item::new("owner"),
+            pending_owner: Item::new("andr_pending_owner"),
+            pending_owner_expiration: Item::new("andr_pending_owner_expiration"),
             original_publisher: Item::new("original_publisher"),
             block_height: Item::new("block_height"),
             operators: Map::new("operators"),
@@ -35,6 +54,15 @@ impl<'a> Default for ADOContract<'a> {
             app_contract: Item::new("app_contract"),
             kernel_address: Item::new("kernel_address"),
             permissioned_actions: Map::new("andr_permissioned_actions"),
+            roles: Map::new("andr_roles"),
+            action_roles: Map::new("andr_action_roles"),
+            action_address_lists: Map::new("andr_action_address_lists"),
+            action_merkle_roots: Map::new("andr_action_merkle_roots"),
+            merkle_members: Map::new("andr_merkle_members"),
+            paused: Item::new("andr_paused"),
+            paused_actions: Map::new("andr_paused_actions"),
+            migrations: Map::new("andr_migrations"),
+            metadata: Item::new("andr_metadata"),
             #[cfg(feature = "modules")]
             module_info: Map::new("andr_modules"),
             #[cfg(feature = "modules")]
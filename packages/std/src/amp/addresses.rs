@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter, Result as FMTResult};
 
 use crate::error::ContractError;
-use crate::os::vfs::vfs_resolve_symlink;
+use crate::os::{aos_querier::ResolvedAddresses, vfs::vfs_resolve_symlink};
 use crate::{ado_contract::ADOContract, os::vfs::vfs_resolve_path};
 use cosmwasm_std::{Addr, Api, Deps, QuerierWrapper, Storage};
 use schemars::JsonSchema;
@@ -60,14 +60,10 @@ impl AndrAddr {
     /// If the address is a valid human readable address then that is returned, otherwise it is assumed to be a Andromeda VFS path and is resolved accordingly.
     ///
     /// If the address is assumed to be a VFS path and no VFS contract address is provided then an appropriate error is returned.
+    ///
+    /// Resolving several addresses in one execution should share a `ResolvedAddresses` instead.
     pub fn get_raw_address(&self, deps: &Deps) -> Result<Addr, ContractError> {
-        if !self.is_vfs_path() {
-            return Ok(deps.api.addr_validate(&self.0)?);
-        }
-
-        let contract = ADOContract::default();
-        let vfs_contract = contract.get_vfs_address(deps.storage, &deps.querier)?;
-        self.get_raw_address_from_vfs(deps, vfs_contract)
+        ResolvedAddresses::default().resolve(deps, self)
     }
 
     /// Retrieves the raw address represented by the AndrAddr from the given VFS contract.
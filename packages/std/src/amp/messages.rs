@@ -202,6 +202,9 @@ pub struct AMPCtx {
     origin_username: Option<AndrAddr>,
     pub previous_sender: String,
     pub id: u64,
+    /// Proof of the origin's membership of a merkle root permissioning the receiving ADO's action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merkle_proof: Option<Vec<String>>,
 }
 
 impl AMPCtx {
@@ -217,9 +220,21 @@ impl AMPCtx {
             origin_username,
             previous_sender: previous_sender.into(),
             id,
+            merkle_proof: None,
         }
     }
 
+    /// Attaches a proof of the origin's merkle membership for permissioned actions
+    pub fn with_merkle_proof(mut self, proof: Vec<String>) -> AMPCtx {
+        self.merkle_proof = Some(proof);
+        self
+    }
+
+    /// Gets the proof of the origin's merkle membership, if provided
+    pub fn get_merkle_proof(&self) -> Option<Vec<String>> {
+        self.merkle_proof.clone()
+    }
+
     /// Gets the original sender of a message
     pub fn get_origin(&self) -> String {
         self.origin.clone()
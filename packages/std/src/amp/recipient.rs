@@ -1,5 +1,5 @@
 use super::{addresses::AndrAddr, messages::AMPMsg};
-use crate::{common::encode_binary, error::ContractError};
+use crate::{common::encode_binary, error::ContractError, os::aos_querier::ResolvedAddresses};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, SubMsg, WasmMsg};
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
@@ -50,7 +50,17 @@ impl Recipient {
         deps: &Deps,
         funds: Vec<Coin>,
     ) -> Result<SubMsg, ContractError> {
-        let resolved_addr = self.address.get_raw_address(deps)?;
+        self.generate_direct_msg_cached(deps, &mut ResolvedAddresses::default(), funds)
+    }
+
+    /// Generates a direct sub message for the given recipient, reusing any resolution of its address cached in `resolved`.
+    pub fn generate_direct_msg_cached(
+        &self,
+        deps: &Deps,
+        resolved: &mut ResolvedAddresses,
+        funds: Vec<Coin>,
+    ) -> Result<SubMsg, ContractError> {
+        let resolved_addr = resolved.resolve(deps, &self.address)?;
         Ok(match &self.msg {
             Some(message) => SubMsg::new(WasmMsg::Execute {
                 contract_addr: resolved_addr.to_string(),
@@ -73,7 +83,17 @@ impl Recipient {
         deps: &Deps,
         cw20_coin: Cw20Coin,
     ) -> Result<SubMsg, ContractError> {
-        let resolved_addr = self.address.get_raw_address(deps)?;
+        self.generate_msg_cw20_cached(deps, &mut ResolvedAddresses::default(), cw20_coin)
+    }
+
+    /// Generates a message to send a CW20 token to the recipient, reusing any resolution of its address cached in `resolved`.
+    pub fn generate_msg_cw20_cached(
+        &self,
+        deps: &Deps,
+        resolved: &mut ResolvedAddresses,
+        cw20_coin: Cw20Coin,
+    ) -> Result<SubMsg, ContractError> {
+        let resolved_addr = resolved.resolve(deps, &self.address)?;
         Ok(match &self.msg {
             Some(msg) => SubMsg::new(WasmMsg::Execute {
                 contract_addr: cw20_coin.address,
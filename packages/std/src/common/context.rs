@@ -1,11 +1,13 @@
-use crate::amp::messages::AMPPkt;
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use crate::{amp::messages::AMPPkt, os::aos_querier::ResolvedAddresses};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, StdResult};
 
 pub struct ExecuteContext<'a> {
     pub deps: DepsMut<'a>,
     pub info: MessageInfo,
     pub env: Env,
     pub amp_ctx: Option<AMPPkt>,
+    /// The addresses resolved so far in this execution, shared by the module hooks and ADO queries
+    pub resolved: ResolvedAddresses,
 }
 
 impl<'a> ExecuteContext<'a> {
@@ -16,6 +18,7 @@ impl<'a> ExecuteContext<'a> {
             info,
             env,
             amp_ctx: None,
+            resolved: ResolvedAddresses::default(),
         }
     }
 
@@ -34,4 +37,27 @@ impl<'a> ExecuteContext<'a> {
             Some(ctx) => ctx.ctx.get_origin() == addr || ctx.ctx.get_previous_sender() == addr,
         }
     }
+
+    /// Returns a context over a branch of the dependencies, leaving them available once the returned
+    /// context has been consumed, e.g. to run the post execute hook
+    pub fn branch(&mut self) -> ExecuteContext<'_> {
+        ExecuteContext {
+            deps: self.deps.branch(),
+            info: self.info.clone(),
+            env: self.env.clone(),
+            amp_ctx: self.amp_ctx.clone(),
+            resolved: self.resolved.clone(),
+        }
+    }
+
+    /// The address that pays action fees for this execution, the packet origin for AMP messages or the sender otherwise
+    pub fn fee_payee(&self) -> StdResult<Addr> {
+        match &self.amp_ctx {
+            Some(amp_ctx) => self
+                .deps
+                .api
+                .addr_validate(amp_ctx.ctx.get_origin().as_str()),
+            None => Ok(self.info.sender.clone()),
+        }
+    }
 }
@@ -71,18 +71,42 @@ where
 pub enum Funds {
     Native(Coin),
     Cw20(Cw20Coin),
+    /// Several assets transferred together
+    Multi(Vec<Funds>),
 }
 
 impl Funds {
+    /// Creates funds holding each of the given native coins
+    pub fn from_coins(coins: Vec<Coin>) -> Funds {
+        Funds::Multi(coins.into_iter().map(Funds::Native).collect())
+    }
+
     // There is probably a more idiomatic way of doing this with From and Into...
     pub fn try_get_coin(&self) -> Result<Coin, ContractError> {
         match self {
             Funds::Native(coin) => Ok(coin.clone()),
-            Funds::Cw20(_) => Err(ContractError::ParsingError {
+            Funds::Cw20(_) | Funds::Multi(_) => Err(ContractError::ParsingError {
                 err: "Funds is not of type Native".to_string(),
             }),
         }
     }
+
+    /// Returns the native coins held by the funds, errors if the funds hold any CW20 tokens
+    pub fn try_get_coins(&self) -> Result<Vec<Coin>, ContractError> {
+        self.clone()
+            .into_assets()
+            .iter()
+            .map(Funds::try_get_coin)
+            .collect()
+    }
+
+    /// Returns each single asset held by the funds, flattening any nested `Multi` funds
+    pub fn into_assets(self) -> Vec<Funds> {
+        match self {
+            Funds::Multi(funds) => funds.into_iter().flat_map(Funds::into_assets).collect(),
+            funds => vec![funds],
+        }
+    }
 }
 
 /// Merges bank messages to the same recipient to a single bank message. Any sub messages
@@ -262,6 +286,38 @@ mod test {
         assert_eq!(3, merged_msgs.len());
     }
 
+    #[test]
+    fn test_funds_assets() {
+        let cw20_coin = Cw20Coin {
+            address: "cw20".to_string(),
+            amount: Uint128::new(10),
+        };
+        let funds = Funds::Multi(vec![
+            Funds::from_coins(vec![coin(100, "uluna"), coin(50, "uusd")]),
+            Funds::Cw20(cw20_coin.clone()),
+        ]);
+        assert_eq!(
+            vec![
+                Funds::Native(coin(100, "uluna")),
+                Funds::Native(coin(50, "uusd")),
+                Funds::Cw20(cw20_coin),
+            ],
+            funds.clone().into_assets()
+        );
+        assert!(funds.try_get_coins().is_err());
+        assert!(funds.try_get_coin().is_err());
+
+        let funds = Funds::from_coins(vec![coin(100, "uluna"), coin(50, "uusd")]);
+        assert_eq!(
+            vec![coin(100, "uluna"), coin(50, "uusd")],
+            funds.try_get_coins().unwrap()
+        );
+        assert_eq!(
+            vec![coin(100, "uluna")],
+            Funds::Native(coin(100, "uluna")).try_get_coins().unwrap()
+        );
+    }
+
     #[test]
     fn test_deduct_funds() {
         let mut funds: Vec<Coin> = vec![coin(100, "uluna")];
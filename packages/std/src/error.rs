@@ -311,6 +311,48 @@ pub enum ContractError {
     #[error("InvalidADOVersion: {msg:?}")]
     InvalidADOVersion { msg: Option<String> },
 
+    #[error("YankedADOVersion: {ado_version}")]
+    YankedADOVersion { ado_version: String },
+
+    #[error("ChecksumMismatch: expected {expected}, code has {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
+    #[error("NoMigrationPath: {from} cannot migrate to {to}")]
+    NoMigrationPath { from: String, to: String },
+
+    #[error("KernelNotAdmin: the kernel is not the wasm admin of {address}")]
+    KernelNotAdmin { address: String },
+
+    #[error("InvalidActionFee: {msg}")]
+    InvalidActionFee { msg: String },
+
+    #[error("PublisherNotRegistered: {publisher}")]
+    PublisherNotRegistered { publisher: String },
+
+    #[error("EmptyPublisherName")]
+    EmptyPublisherName {},
+
+    #[error("NoPendingOwner")]
+    NoPendingOwner {},
+
+    #[error("ADO has no owner: {address}")]
+    NoOwner { address: String },
+
+    #[error("PassOfferingNotFound")]
+    PassOfferingNotFound {},
+
+    #[error("PassNotFound")]
+    PassNotFound {},
+
+    #[error("InvalidRole")]
+    InvalidRole {},
+
+    #[error("ActionPaused: {action}")]
+    ActionPaused { action: String },
+
+    #[error("InvalidBatch: {msg}")]
+    InvalidBatch { msg: String },
+
     #[error("AuctionRewardAlreadyClaimed")]
     AuctionAlreadyClaimed {},
 
@@ -344,6 +386,9 @@ pub enum ContractError {
     #[error("Cannot migrate from different contract type: {previous_contract}")]
     CannotMigrate { previous_contract: String },
 
+    #[error("InvalidMigrationStep: {version}")]
+    InvalidMigrationStep { version: String },
+
     #[error("NestedAndromedaMsg")]
     NestedAndromedaMsg {},
 
@@ -487,6 +532,16 @@ pub enum ContractError {
     #[error("Invalid Query")]
     InvalidQuery {},
 
+    #[error("InvalidAdoMetadata: {msg}")]
+    InvalidAdoMetadata { msg: String },
+
+    #[error("ADOQueryFailed: query {query} to {path} failed: {msg}")]
+    ADOQueryFailed {
+        path: String,
+        query: String,
+        msg: String,
+    },
+
     #[error("Invalid Withdrawal: {msg:?}")]
     InvalidWithdrawal { msg: Option<String> },
 
@@ -559,6 +614,9 @@ pub enum ContractError {
     #[error("Invalid expiration")]
     InvalidExpiration {},
 
+    #[error("Rate limited permissions require at least one use per window of at least one second")]
+    InvalidRateLimit {},
+
     #[error("Invalid start time")]
     InvalidStartTime {},
 
@@ -606,6 +664,15 @@ pub enum ContractError {
 
     #[error("Invalid Transfer Port: {port}")]
     InvalidTransferPort { port: String },
+
+    #[error("Symlink cycle detected while resolving {path}")]
+    SymlinkCycle { path: String },
+
+    #[error("Maximum symlink resolution depth of {max_depth} exceeded")]
+    SymlinkDepthExceeded { max_depth: u32 },
+
+    #[error("Maximum symlink resolution depth must be greater than zero")]
+    InvalidSymlinkDepth {},
 }
 
 impl From<Cw20ContractError> for ContractError {
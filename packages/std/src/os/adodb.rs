@@ -1,8 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{ensure, Addr, Binary, Decimal, HexBinary, Uint128};
 use schemars::JsonSchema;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 
+use crate::error::ContractError;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub kernel_address: String,
@@ -17,6 +20,9 @@ pub enum ExecuteMsg {
         action_fees: Option<Vec<ActionFee>>,
         version: String,
         publisher: Option<String>,
+        /// If provided, publishing fails unless the stored code has this checksum
+        checksum: Option<HexBinary>,
+        release: Option<ReleaseMetadata>,
     },
     UpdateActionFees {
         ado_type: String,
@@ -30,6 +36,93 @@ pub enum ExecuteMsg {
         ado_type: String,
         publisher: String,
     },
+    /// Marks a published version as deprecated, it can still be instantiated
+    Deprecate {
+        ado_version: String,
+        reason: Option<String>,
+    },
+    /// Prevents a published version from being instantiated
+    Yank {
+        ado_version: String,
+    },
+    /// Allows ADOs of the given type with a version matching the `from` semver range to migrate to the `to` version
+    AddMigrationPath {
+        ado_type: String,
+        from: String,
+        to: String,
+        /// The migrate message sent to the ADO, defaults to `{}`
+        migrate_msg: Option<Binary>,
+    },
+    /// Removes the migration path from the given version range, or every path into `to` if no range is provided
+    RemoveMigrationPath {
+        ado_type: String,
+        from: Option<String>,
+        to: String,
+    },
+    /// Registers or updates the sender's publisher profile
+    RegisterPublisher {
+        name: String,
+        url: Option<String>,
+        /// Address that receives the sender's action fees, defaults to the sender
+        payout_address: Option<String>,
+    },
+    /// Hands every published version of an ADO type over to another registered publisher
+    TransferPublisher {
+        ado_type: String,
+        new_publisher: String,
+    },
+}
+
+#[cw_serde]
+pub enum FeeRate {
+    /// A fixed amount of the fee asset
+    Flat(Uint128),
+    /// A percentage of the value moved by the action
+    Percent(Decimal),
+}
+
+impl FeeRate {
+    fn validate(&self) -> Result<(), ContractError> {
+        if let FeeRate::Percent(percent) = self {
+            ensure!(
+                *percent <= Decimal::one(),
+                ContractError::InvalidActionFee {
+                    msg: "Fee percentage cannot exceed 100%".to_string()
+                }
+            );
+        }
+        Ok(())
+    }
+
+    fn calculate(&self, value: Uint128) -> Uint128 {
+        match self {
+            FeeRate::Flat(amount) => *amount,
+            FeeRate::Percent(percent) => value * *percent,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct FeeTier {
+    /// The minimum value moved by the action for this tier to apply
+    pub min_value: Uint128,
+    pub rate: FeeRate,
+}
+
+#[cw_serde]
+pub enum FeeType {
+    /// A percentage of the value moved by the action.
+    ///
+    /// The fee's `amount` is charged as a minimum and `max` caps the fee if provided.
+    Percentage {
+        percent: Decimal,
+        max: Option<Uint128>,
+    },
+    /// Fees tiered by the value moved by the action.
+    ///
+    /// The tier with the highest `min_value` not exceeding the value applies, the fee's `amount` is
+    /// charged if the value is below every tier.
+    Tiered { tiers: Vec<FeeTier> },
 }
 
 #[cw_serde]
@@ -38,6 +131,10 @@ pub struct ActionFee {
     pub asset: String,
     pub amount: Uint128,
     pub receiver: Option<Addr>,
+    /// How the fee is calculated, defaults to a flat fee of `amount`
+    pub fee_type: Option<FeeType>,
+    /// Additional assets the fee can be paid in, in order of preference after `asset`
+    pub accepted_assets: Option<Vec<String>>,
 }
 
 impl ActionFee {
@@ -47,17 +144,192 @@ impl ActionFee {
             asset,
             amount,
             receiver: None,
+            fee_type: None,
+            accepted_assets: None,
         }
     }
 
     pub fn with_receive(&self, receiver: Addr) -> Self {
         Self {
-            action: self.action.clone(),
-            asset: self.asset.clone(),
-            amount: self.amount,
             receiver: Some(receiver),
+            ..self.clone()
         }
     }
+
+    pub fn with_fee_type(self, fee_type: FeeType) -> Self {
+        Self {
+            fee_type: Some(fee_type),
+            ..self
+        }
+    }
+
+    pub fn with_accepted_assets(self, accepted_assets: Vec<String>) -> Self {
+        Self {
+            accepted_assets: Some(accepted_assets),
+            ..self
+        }
+    }
+
+    /// All assets the fee can be paid in, in order of preference
+    pub fn assets(&self) -> Vec<String> {
+        let mut assets = vec![self.asset.clone()];
+        if let Some(accepted_assets) = &self.accepted_assets {
+            assets.extend(accepted_assets.iter().cloned());
+        }
+        assets
+    }
+
+    /// Whether the fee is calculated from the value moved by the action
+    pub fn is_value_based(&self) -> bool {
+        self.fee_type.is_some()
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        match &self.fee_type {
+            None => Ok(()),
+            Some(FeeType::Percentage { percent, max }) => {
+                FeeRate::Percent(*percent).validate()?;
+                if let Some(max) = max {
+                    ensure!(
+                        *max >= self.amount,
+                        ContractError::InvalidActionFee {
+                            msg: "Maximum fee cannot be less than the minimum fee".to_string()
+                        }
+                    );
+                }
+                Ok(())
+            }
+            Some(FeeType::Tiered { tiers }) => {
+                ensure!(
+                    !tiers.is_empty(),
+                    ContractError::InvalidActionFee {
+                        msg: "At least one fee tier must be provided".to_string()
+                    }
+                );
+                ensure!(
+                    tiers.windows(2).all(|w| w[0].min_value < w[1].min_value),
+                    ContractError::InvalidActionFee {
+                        msg: "Fee tiers must be in ascending order of minimum value".to_string()
+                    }
+                );
+                for tier in tiers {
+                    tier.rate.validate()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Calculates the fee owed for an action moving the given value
+    pub fn calculate(&self, value: Option<Uint128>) -> Result<Uint128, ContractError> {
+        let fee_type = match &self.fee_type {
+            Some(fee_type) => fee_type,
+            None => return Ok(self.amount),
+        };
+        let value = value.ok_or(ContractError::InvalidFunds {
+            msg: format!(
+                "A value is required to calculate the fee for {}",
+                self.action
+            ),
+        })?;
+        match fee_type {
+            FeeType::Percentage { percent, max } => {
+                let fee = FeeRate::Percent(*percent).calculate(value).max(self.amount);
+                Ok(max.map_or(fee, |max| fee.min(max)))
+            }
+            FeeType::Tiered { tiers } => Ok(tiers
+                .iter()
+                .rev()
+                .find(|tier| tier.min_value <= value)
+                .map_or(self.amount, |tier| tier.rate.calculate(value))),
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub enum VersionStatus {
+    #[default]
+    Active,
+    Deprecated,
+    Yanked,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct VersionStatusInfo {
+    pub status: VersionStatus,
+    pub reason: Option<String>,
+}
+
+impl VersionStatusInfo {
+    pub fn is_yanked(&self) -> bool {
+        self.status == VersionStatus::Yanked
+    }
+}
+
+/// Optional information describing a published release
+#[cw_serde]
+#[derive(Default)]
+pub struct ReleaseMetadata {
+    pub changelog_url: Option<String>,
+    /// Hash of the JSON schema the release was built with
+    pub schema_hash: Option<String>,
+    pub audit_reference: Option<String>,
+    /// The minimum kernel version the release is compatible with
+    pub min_kernel_version: Option<String>,
+}
+
+/// The code details recorded from the chain when a version is published
+#[cw_serde]
+pub struct PublishedCodeInfo {
+    pub checksum: HexBinary,
+    pub creator: String,
+}
+
+#[cw_serde]
+pub struct VersionInfo {
+    pub ado_version: String,
+    pub code_id: u64,
+    pub publisher: String,
+    pub status: VersionStatusInfo,
+    /// Versions published before checksums were recorded have no code info
+    pub code_info: Option<PublishedCodeInfo>,
+    pub release: Option<ReleaseMetadata>,
+}
+
+#[cw_serde]
+pub struct MigrationPath {
+    /// Semver range of the versions that can migrate, e.g. `>=1.0.0, <1.2.0`
+    pub from: String,
+    pub to: String,
+    pub migrate_msg: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct ResolvedVersion {
+    /// The exact ADO version in the form `ado_type@version`
+    pub ado_version: String,
+    pub code_id: u64,
+}
+
+#[cw_serde]
+pub struct PublisherProfile {
+    pub name: String,
+    pub url: Option<String>,
+    pub payout_address: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct CatalogueEntry {
+    pub ado_type: String,
+    pub latest_version: String,
+    pub code_id: u64,
+    pub publisher: String,
+    /// The action fees charged by the latest version
+    pub action_fees: Vec<ActionFee>,
+    /// The deprecation status of the latest version
+    pub status: VersionStatusInfo,
 }
 
 #[cw_serde]
@@ -97,6 +369,38 @@ pub enum QueryMsg {
     ActionFee { ado_type: String, action: String },
     #[returns(Option<ActionFee>)]
     ActionFeeByCodeId { code_id: u64, action: String },
+    #[returns(VersionStatusInfo)]
+    VersionStatus { ado_version: String },
+    /// Resolves an ADO version, which may be `latest` or a semver range such as `^1.2`, to an exact version and its code ID
+    #[returns(ResolvedVersion)]
+    ResolveVersion { ado_version: String },
+    #[returns(VersionInfo)]
+    VersionInfo { ado_version: String },
+    #[returns(Vec<MigrationPath>)]
+    MigrationPaths { ado_type: String },
+    /// Returns the migration path allowing `from_version` to migrate to `to_version`, if one exists
+    #[returns(Option<MigrationPath>)]
+    MigrationPath {
+        ado_type: String,
+        from_version: String,
+        to_version: String,
+    },
+    #[returns(Option<PublisherProfile>)]
+    Publisher { address: String },
+    /// Lists the ADO types with at least one version published by the given publisher
+    #[returns(Vec<String>)]
+    #[serde(rename = "publisher_ados")]
+    PublisherADOs {
+        publisher: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists every ADO type along with a summary of its latest version
+    #[returns(Vec<CatalogueEntry>)]
+    Catalogue {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(
@@ -170,6 +474,22 @@ impl ADOVersion {
         }
     }
 
+    /// Gets the semver requirement for the given ADOVersion
+    ///
+    /// Returns `None` if the version is `latest`, an exact version or cannot be parsed as a range
+    ///
+    /// ### Examples
+    /// - `ado_type@^1.2`
+    /// - `ado_type@~0.3`
+    /// - `ado_type@>=1.0.0, <2.0.0`
+    pub fn get_version_req(&self) -> Option<VersionReq> {
+        let version = self.get_version();
+        if version == "latest" || Version::parse(&version).is_ok() {
+            return None;
+        }
+        VersionReq::parse(&version).ok()
+    }
+
     /// Gets the type for the given ADOVersion
     pub fn get_type(&self) -> String {
         self.clone().into_string().split('@').collect::<Vec<&str>>()[0].to_string()
@@ -185,6 +505,90 @@ impl ADOVersion {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_action_fee_calculate() {
+        let fee = ActionFee::new("buy".to_string(), "uusd".to_string(), Uint128::new(10));
+        assert_eq!(fee.calculate(None).unwrap(), Uint128::new(10));
+
+        let fee = fee.with_fee_type(FeeType::Percentage {
+            percent: Decimal::percent(5),
+            max: Some(Uint128::new(100)),
+        });
+        fee.validate().unwrap();
+        assert!(fee.calculate(None).is_err());
+        // Minimum fee
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(100))).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(1000))).unwrap(),
+            Uint128::new(50)
+        );
+        // Maximum fee
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(10000))).unwrap(),
+            Uint128::new(100)
+        );
+
+        let fee = fee.with_fee_type(FeeType::Tiered {
+            tiers: vec![
+                FeeTier {
+                    min_value: Uint128::new(100),
+                    rate: FeeRate::Percent(Decimal::percent(5)),
+                },
+                FeeTier {
+                    min_value: Uint128::new(1000),
+                    rate: FeeRate::Flat(Uint128::new(20)),
+                },
+            ],
+        });
+        fee.validate().unwrap();
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(50))).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(200))).unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(
+            fee.calculate(Some(Uint128::new(5000))).unwrap(),
+            Uint128::new(20)
+        );
+    }
+
+    #[test]
+    fn test_action_fee_validate() {
+        let fee = ActionFee::new("buy".to_string(), "uusd".to_string(), Uint128::new(10));
+        let invalid = [
+            FeeType::Percentage {
+                percent: Decimal::percent(101),
+                max: None,
+            },
+            FeeType::Percentage {
+                percent: Decimal::percent(1),
+                max: Some(Uint128::new(5)),
+            },
+            FeeType::Tiered { tiers: vec![] },
+            FeeType::Tiered {
+                tiers: vec![
+                    FeeTier {
+                        min_value: Uint128::new(100),
+                        rate: FeeRate::Flat(Uint128::new(1)),
+                    },
+                    FeeTier {
+                        min_value: Uint128::new(100),
+                        rate: FeeRate::Flat(Uint128::new(2)),
+                    },
+                ],
+            },
+        ];
+        for fee_type in invalid {
+            assert!(fee.clone().with_fee_type(fee_type).validate().is_err());
+        }
+    }
+
     #[test]
     fn test_validate() {
         let ado_version = ADOVersion::from_string("valid_version");
@@ -212,6 +616,28 @@ mod tests {
         assert_eq!(ado_version.get_version(), "latest");
     }
 
+    #[test]
+    fn test_get_version_req() {
+        let ado_version = ADOVersion::from_string("ado_type");
+        assert!(ado_version.get_version_req().is_none());
+
+        let ado_version = ADOVersion::from_string("ado_type@0.1.0");
+        assert!(ado_version.get_version_req().is_none());
+
+        let ado_version = ADOVersion::from_string("ado_type@^1.2");
+        let req = ado_version.get_version_req().unwrap();
+        assert!(req.matches(&Version::parse("1.4.0").unwrap()));
+        assert!(!req.matches(&Version::parse("2.0.0").unwrap()));
+
+        let ado_version = ADOVersion::from_string("ado_type@~0.3");
+        let req = ado_version.get_version_req().unwrap();
+        assert!(req.matches(&Version::parse("0.3.7").unwrap()));
+        assert!(!req.matches(&Version::parse("0.4.0").unwrap()));
+
+        let ado_version = ADOVersion::from_string("ado_type@not_a_version");
+        assert!(ado_version.get_version_req().is_none());
+    }
+
     #[test]
     fn test_get_type() {
         let ado_version = ADOVersion::from_string("ado_type");
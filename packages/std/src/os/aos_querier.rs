@@ -1,19 +1,79 @@
-use crate::amp::{ADO_DB_KEY, VFS_KEY};
+use crate::ado_contract::ADOContract;
+use crate::amp::{AndrAddr, ADO_DB_KEY, VFS_KEY};
 use crate::error::ContractError;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{from_json, Addr, QuerierWrapper};
+use cosmwasm_std::{ensure, from_json, to_json_string, Addr, Deps, QuerierWrapper};
 use cw_storage_plus::Path;
 use lazy_static::__Deref;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
 use std::str::from_utf8;
 
-use super::adodb::{ActionFee, QueryMsg as ADODBQueryMsg};
+use super::adodb::{ActionFee, PublisherProfile, QueryMsg as ADODBQueryMsg, VersionStatusInfo};
 use super::kernel::ChannelInfo;
 
 #[cw_serde]
 pub struct AOSQuerier();
 
+/// A cache of the raw addresses that `AndrAddr`s resolve to.
+///
+/// VFS paths can be re-registered between transactions, so a cache should not outlive the
+/// execution it was created in.
+#[derive(Default, Clone)]
+pub struct ResolvedAddresses {
+    vfs_address: Option<Addr>,
+    addresses: HashMap<AndrAddr, Addr>,
+}
+
+impl ResolvedAddresses {
+    /// Resolves the given local, VFS or username path or human readable address, reusing any
+    /// earlier resolution of the same address
+    pub fn resolve(&mut self, deps: &Deps, addr: &AndrAddr) -> Result<Addr, ContractError> {
+        if let Some(resolved) = self.addresses.get(addr) {
+            return Ok(resolved.clone());
+        }
+
+        let resolved = if addr.is_vfs_path() {
+            let vfs_address = match &self.vfs_address {
+                Some(vfs_address) => vfs_address.clone(),
+                None => {
+                    let vfs_address =
+                        ADOContract::default().get_vfs_address(deps.storage, &deps.querier)?;
+                    self.vfs_address = Some(vfs_address.clone());
+                    vfs_address
+                }
+            };
+            addr.get_raw_address_from_vfs(deps, vfs_address)?
+        } else {
+            deps.api.addr_validate(addr.as_str())?
+        };
+        self.addresses.insert(addr.clone(), resolved.clone());
+        Ok(resolved)
+    }
+}
+
 impl AOSQuerier {
+    /// Queries the ADO at the given address, which can be a local, VFS or username path, reusing any
+    /// resolution of the address cached in `resolved`.
+    ///
+    /// Errors are wrapped with the path and the failing query.
+    pub fn query_ado<R: DeserializeOwned>(
+        deps: &Deps,
+        resolved: &mut ResolvedAddresses,
+        addr: &AndrAddr,
+        msg: &impl Serialize,
+    ) -> Result<R, ContractError> {
+        let query_failed = |err: ContractError| ContractError::ADOQueryFailed {
+            path: addr.to_string(),
+            query: to_json_string(msg).unwrap_or_default(),
+            msg: err.to_string(),
+        };
+        let address = resolved.resolve(deps, addr).map_err(query_failed)?;
+        deps.querier
+            .query_wasm_smart(address, msg)
+            .map_err(|err| query_failed(err.into()))
+    }
+
     // namespace -> storage key
     // key_name -> item key
     // Taken from: https://github.com/KompleTeam/komple-framework/blob/387d333af03e794927b8ef8ac536d2a42ae7a1ff/packages/utils/src/storage.rs#L25
@@ -53,16 +113,6 @@ impl AOSQuerier {
         querier: &QuerierWrapper,
         adodb_addr: &Addr,
         code_id: u64,
-    ) -> Result<Option<String>, ContractError> {
-        let key = AOSQuerier::get_map_storage_key("ado_type", &[code_id.to_string().as_bytes()])?;
-        let ado_type: Option<String> = AOSQuerier::query_storage(querier, adodb_addr, &key)?;
-        Ok(ado_type)
-    }
-
-    pub fn ado_type_getter_smart(
-        querier: &QuerierWrapper,
-        adodb_addr: &Addr,
-        code_id: u64,
     ) -> Result<Option<String>, ContractError> {
         let query = ADODBQueryMsg::ADOType { code_id };
         let ado_type: Option<String> = querier.query_wasm_smart(adodb_addr, &query)?;
@@ -83,6 +133,18 @@ impl AOSQuerier {
         }
     }
 
+    pub fn publisher_profile_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        publisher: &str,
+    ) -> Result<Option<PublisherProfile>, ContractError> {
+        let key = AOSQuerier::get_map_storage_key("publisher_profiles", &[publisher.as_bytes()])?;
+        let profile: Option<PublisherProfile> =
+            AOSQuerier::query_storage(querier, adodb_addr, &key)?;
+
+        Ok(profile)
+    }
+
     /// Checks if the code id exists in the ADODB by querying its raw storage for the code id's ado type
     pub fn verify_code_id(
         querier: &QuerierWrapper,
@@ -99,6 +161,34 @@ impl AOSQuerier {
         }
     }
 
+    /// Queries the ADODB's raw storage for the status of the given ADO version
+    pub fn ado_version_status_getter(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        ado_version: &str,
+    ) -> Result<VersionStatusInfo, ContractError> {
+        let key = AOSQuerier::get_map_storage_key("version_status", &[ado_version.as_bytes()])?;
+        let status: Option<VersionStatusInfo> =
+            AOSQuerier::query_storage(querier, adodb_addr, &key)?;
+        Ok(status.unwrap_or_default())
+    }
+
+    /// Errors if the ADO version published under the given code id has been yanked
+    pub fn verify_code_id_not_yanked(
+        querier: &QuerierWrapper,
+        adodb_addr: &Addr,
+        code_id: u64,
+    ) -> Result<(), ContractError> {
+        if let Some(ado_version) = AOSQuerier::ado_type_getter(querier, adodb_addr, code_id)? {
+            let status = AOSQuerier::ado_version_status_getter(querier, adodb_addr, &ado_version)?;
+            ensure!(
+                !status.is_yanked(),
+                ContractError::YankedADOVersion { ado_version }
+            );
+        }
+        Ok(())
+    }
+
     pub fn code_id_getter_raw(
         querier: &QuerierWrapper,
         adodb_addr: &Addr,
@@ -170,16 +260,20 @@ impl AOSQuerier {
         Ok(fee)
     }
 
-    /// Queries the kernel's raw storage for the VFS's address
+    /// Queries an ADO's raw storage for its owner, `None` if ownership has been renounced
     pub fn ado_owner_getter(
         querier: &QuerierWrapper,
         ado_addr: &Addr,
-    ) -> Result<Addr, ContractError> {
-        let verify: Option<Addr> = AOSQuerier::query_storage(querier, ado_addr, "owner")?;
-        match verify {
-            Some(address) => Ok(address),
-            None => Err(ContractError::InvalidAddress {}),
-        }
+    ) -> Result<Option<Addr>, ContractError> {
+        AOSQuerier::query_storage(querier, ado_addr, "owner")
+    }
+
+    /// Queries an ADO's raw storage for the App it belongs to, if any
+    pub fn ado_app_getter(
+        querier: &QuerierWrapper,
+        ado_addr: &Addr,
+    ) -> Result<Option<Addr>, ContractError> {
+        AOSQuerier::query_storage(querier, ado_addr, "app_contract")
     }
 
     /// Queries the current chain name from the kernel
@@ -210,3 +304,85 @@ impl AOSQuerier {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ado_contract::permissioning::AddressListQueryMsg;
+    use crate::testing::mock_querier::{
+        mock_dependencies_custom, FAKE_VFS_PATH, MOCK_ADDRESS_LIST_CONTRACT, MOCK_KERNEL_CONTRACT,
+        MOCK_VFS_CONTRACT,
+    };
+
+    #[test]
+    fn test_query_ado() {
+        let mut deps = mock_dependencies_custom(&[]);
+        ADOContract::default()
+            .kernel_address
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(MOCK_KERNEL_CONTRACT),
+            )
+            .unwrap();
+
+        let mut resolved = ResolvedAddresses::default();
+        let is_inclusive: bool = AOSQuerier::query_ado(
+            &deps.as_ref(),
+            &mut resolved,
+            &AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT),
+            &AddressListQueryMsg::IsInclusive {},
+        )
+        .unwrap();
+        assert!(is_inclusive);
+
+        let err = AOSQuerier::query_ado::<bool>(
+            &deps.as_ref(),
+            &mut resolved,
+            &AndrAddr::from_string(FAKE_VFS_PATH),
+            &AddressListQueryMsg::IsInclusive {},
+        )
+        .unwrap_err();
+        match err {
+            ContractError::ADOQueryFailed { path, query, .. } => {
+                assert_eq!(path, FAKE_VFS_PATH);
+                assert_eq!(query, r#"{"is_inclusive":{}}"#);
+            }
+            err => panic!("Unexpected error: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolved_addresses() {
+        let mut deps = mock_dependencies_custom(&[]);
+        ADOContract::default()
+            .kernel_address
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(MOCK_KERNEL_CONTRACT),
+            )
+            .unwrap();
+
+        let mut resolved = ResolvedAddresses::default();
+        let addr = AndrAddr::from_string(MOCK_ADDRESS_LIST_CONTRACT);
+        resolved.resolve(&deps.as_ref(), &addr).unwrap();
+        // Human readable addresses do not require the VFS
+        assert!(resolved.vfs_address.is_none());
+
+        let path = AndrAddr::from_string("/home/user/app");
+        let resolved_path = resolved.resolve(&deps.as_ref(), &path).unwrap();
+        assert_eq!(
+            resolved.vfs_address,
+            Some(Addr::unchecked(MOCK_VFS_CONTRACT))
+        );
+        assert_eq!(resolved.addresses.get(&path), Some(&resolved_path));
+
+        // Cached resolutions are reused without querying the kernel again
+        ADOContract::default()
+            .kernel_address
+            .remove(deps.as_mut().storage);
+        assert_eq!(
+            resolved.resolve(&deps.as_ref(), &path).unwrap(),
+            resolved_path
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::amp::AndrAddr;
+use crate::{
+    amp::{AndrAddr, Recipient},
+    common::Funds,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -24,27 +27,204 @@ pub enum ExecuteMsg {
     /// Fees are paid in the following fallthrough priority:
     /// 1. The balance of the ADO contract itself
     /// 2. The balance of the App contract for the ADO
-    /// 3. The provided payee address
+    /// 3. Allowances granted to the payee by sponsors
+    /// 4. Allowances granted to the users of the App by sponsors
+    /// 5. The provided payee address
+    ///
+    /// The value moved by the action must be provided for percentage or tiered fees and
+    /// determines the asset the fee is paid in. Any discount for the payee, App or ADO type is
+    /// applied before the fee is charged.
+    ///
+    /// Funds attached to the message are used before any balances and any excess is returned to the payee.
     PayFee {
         payee: Addr,
         action: String,
+        value: Option<Funds>,
     },
     /// Withdraw native funds from the Andromeda economics module.
     ///
     /// If no amount is provided all funds are withdrawn for the given asset.
+    ///
+    /// Funds are sent to the sender unless a recipient is provided, in which case they are routed via the
+    /// Kernel and may be sent to a VFS path or to another chain using an `ibc://` address.
     Withdraw {
         amount: Option<Uint128>,
         asset: String,
+        recipient: Option<Recipient>,
     },
     #[serde(rename = "withdraw_cw20")]
     /// Withdraw CW20 funds from the Andromeda economics module.
     ///
     /// If no amount is provided all funds are withdrawn for the given asset.
+    ///
+    /// Funds are sent to the sender unless a recipient is provided, CW20 funds cannot be sent to another chain.
     WithdrawCW20 {
         amount: Option<Uint128>,
         asset: String,
+        recipient: Option<Recipient>,
+    },
+    /// Withdraw several assets from the Andromeda economics module at once.
+    ///
+    /// All native funds are sent to the recipient in a single message.
+    BatchWithdraw {
+        withdrawals: Vec<AssetWithdrawal>,
+        recipient: Option<Recipient>,
     },
     Receive(Cw20ReceiveMsg),
+    /// Sets a fee discount for the given target, replacing any existing discount.
+    ///
+    /// The contract owner can set discounts for any target, App owners can set discounts for their own App.
+    SetDiscount {
+        target: FeeTarget,
+        discount: FeeDiscount,
+    },
+    RemoveDiscount {
+        target: FeeTarget,
+    },
+    /// Allows the payee's fees to be paid from the sender's balance, up to `limit` of `asset` every
+    /// `period` seconds. Without a period the limit applies for the lifetime of the allowance.
+    ///
+    /// Replaces any existing allowance from the sender to the payee for the asset.
+    GrantAllowance {
+        payee: AndrAddr,
+        asset: String,
+        limit: Uint128,
+        period: Option<u64>,
+    },
+    RevokeAllowance {
+        payee: AndrAddr,
+        asset: String,
+    },
+    /// Allows the fees of any payee using the App's ADOs to be paid from the sender's balance, up to `limit`
+    /// of `asset` every `period` seconds. App allowances are drawn after the payee's own allowances.
+    ///
+    /// Replaces any existing allowance from the sender to the App for the asset.
+    GrantAppAllowance {
+        app: AndrAddr,
+        asset: String,
+        limit: Uint128,
+        period: Option<u64>,
+    },
+    RevokeAppAllowance {
+        app: AndrAddr,
+        asset: String,
+    },
+    /// Offers passes granting unlimited use of an ADO type, or a single action of it, for `duration` seconds.
+    ///
+    /// The sender must be the publisher of the given ADO version or the contract owner.
+    SetPassOffering {
+        ado_type: String,
+        action: Option<String>,
+        asset: String,
+        price: Uint128,
+        duration: u64,
+    },
+    RemovePassOffering {
+        ado_type: String,
+        action: Option<String>,
+    },
+    /// Buys a pass for the holder, defaulting to the sender. The holder can be a payee or an App.
+    ///
+    /// The price is paid from attached funds first and then from the sender's balance. Buying a pass
+    /// that is still valid extends it.
+    BuyPass {
+        ado_type: String,
+        action: Option<String>,
+        holder: Option<AndrAddr>,
+        auto_renew: bool,
+    },
+    /// Enables or disables renewal of an expired pass from the buyer's balance when a fee is next paid.
+    ///
+    /// Only the buyer of the pass can change this.
+    SetPassAutoRenew {
+        ado_type: String,
+        action: Option<String>,
+        holder: AndrAddr,
+        auto_renew: bool,
+    },
+}
+
+/// An asset held in an economics balance
+#[cw_serde]
+pub enum WithdrawAsset {
+    Native(String),
+    Cw20(String),
+}
+
+#[cw_serde]
+pub struct AssetWithdrawal {
+    pub asset: WithdrawAsset,
+    /// The amount to withdraw, the full balance is withdrawn if not provided
+    pub amount: Option<Uint128>,
+}
+
+/// The payee, App or ADO type a fee discount applies to
+#[cw_serde]
+pub enum FeeTarget {
+    Payee(AndrAddr),
+    /// Fees charged by any ADO within the App
+    App(AndrAddr),
+    /// Fees charged by any version of the ADO type
+    #[serde(rename = "ado_type")]
+    ADOType(String),
+}
+
+#[cw_serde]
+pub enum FeeDiscount {
+    /// No fee is charged
+    Exempt,
+    /// The fee is reduced by the given percentage
+    Percent(Decimal),
+}
+
+#[cw_serde]
+pub struct Allowance {
+    pub sponsor: Addr,
+    /// The payee, or the App for allowances shared by the App's users
+    pub payee: Addr,
+    pub asset: String,
+    pub limit: Uint128,
+    /// The amount spent in the current period
+    pub spent: Uint128,
+    pub period: Option<u64>,
+    pub period_start: Timestamp,
+}
+
+#[cw_serde]
+pub struct PassOffering {
+    pub ado_type: String,
+    /// The publisher paid for passes
+    pub publisher: Addr,
+    /// The action covered by the pass, all actions of the ADO type are covered if not provided
+    pub action: Option<String>,
+    pub asset: String,
+    pub price: Uint128,
+    /// The duration of the pass in seconds
+    pub duration: u64,
+}
+
+#[cw_serde]
+pub struct AccessPass {
+    /// The payee or App the pass was bought for
+    pub holder: Addr,
+    pub buyer: Addr,
+    pub ado_type: String,
+    pub action: Option<String>,
+    pub expires: Timestamp,
+    pub auto_renew: bool,
+}
+
+#[cw_serde]
+pub struct EffectiveFeeResponse {
+    /// The asset the fee is charged in, `None` if the action has no fee
+    pub asset: Option<String>,
+    /// The fee before any discount
+    pub base_amount: Uint128,
+    pub discount: Option<FeeDiscount>,
+    /// The fee charged once the discount is applied
+    pub amount: Uint128,
+    /// The part of the fee that can currently be covered by sponsor allowances
+    pub sponsored: Uint128,
 }
 
 #[cw_serde]
@@ -66,6 +246,99 @@ pub enum QueryMsg {
     /// Returns a `Uint128` representing the current balance
     #[returns(Uint128)]
     Balance { asset: String, address: AndrAddr },
+    /// Queries every non-zero balance held by the given address
+    #[returns(Vec<AssetBalance>)]
+    Balances { address: AndrAddr },
+    /// Queries the receipt recorded when a fee was paid
+    #[returns(Option<FeeReceipt>)]
+    Receipt { receipt_id: u64 },
+    #[returns(Option<FeeDiscount>)]
+    Discount { target: FeeTarget },
+    /// Queries the allowances sponsors have granted the payee
+    #[returns(Vec<Allowance>)]
+    Allowances { payee: AndrAddr },
+    /// Queries the allowances sponsors have granted the users of the App
+    #[returns(Vec<Allowance>)]
+    AppAllowances { app: AndrAddr },
+    /// Queries the fee the payee would be charged for the given ADO action
+    #[returns(EffectiveFeeResponse)]
+    EffectiveFee {
+        ado: AndrAddr,
+        action: String,
+        payee: AndrAddr,
+        value: Option<Funds>,
+    },
+    /// Queries the receipts of paid fees in ascending order, optionally filtered by ADO, payee or receiver
+    #[returns(Vec<FeeReceipt>)]
+    FeeHistory {
+        filter: Option<FeeHistoryFilter>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Queries the total fees collected for an ADO type, optionally for a single action
+    #[returns(Vec<FeeTotal>)]
+    FeeTotals {
+        ado_type: String,
+        action: Option<String>,
+    },
+    /// Queries the fees a publisher has collected that are waiting to be withdrawn
+    #[returns(RevenueResponse)]
+    Revenue { publisher: AndrAddr },
+    #[returns(Option<PassOffering>)]
+    PassOffering {
+        ado_type: String,
+        action: Option<String>,
+    },
+    /// Queries the unexpired passes held by the given payee or App
+    #[returns(Vec<AccessPass>)]
+    ActivePasses { holder: AndrAddr },
+}
+
+#[cw_serde]
+pub enum FeeHistoryFilter {
+    Ado(AndrAddr),
+    Payee(AndrAddr),
+    Receiver(AndrAddr),
+}
+
+#[cw_serde]
+pub struct AssetBalance {
+    pub asset: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct FeeTotal {
+    pub ado_type: String,
+    pub action: String,
+    pub asset: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct RevenueResponse {
+    pub publisher: Addr,
+    /// The address fees are paid to, the publisher's payout address if one is registered
+    pub receiver: Addr,
+    pub balances: Vec<AssetBalance>,
+}
+
+#[cw_serde]
+pub struct FeeReceipt {
+    pub receipt_id: u64,
+    /// The ADO the fee was charged for
+    pub ado: Addr,
+    pub ado_type: String,
+    pub action: String,
+    pub payee: Addr,
+    pub receiver: Addr,
+    pub asset: String,
+    pub amount: Uint128,
+    /// The part of the fee paid from funds attached to the `PayFee` message
+    pub paid_from_funds: Uint128,
+    /// Attached funds returned to the payee
+    pub refunded: Vec<Coin>,
+    pub timestamp: Timestamp,
 }
 
 #[cfg(test)]
@@ -49,6 +49,14 @@ pub enum ExecuteMsg {
     },
     /// Recovers funds from failed IBC messages
     Recover {},
+    /// Migrates an ADO to the given version along a migration path registered in the ADODB
+    ///
+    /// Restricted to the owner of the ADO, the kernel must be the ADO's wasm admin
+    #[serde(rename = "migrate_ado")]
+    MigrateADO {
+        address: AndrAddr,
+        target_version: String,
+    },
     // Only accessible to key contracts
     Internal(InternalMsg),
 }
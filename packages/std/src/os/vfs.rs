@@ -77,6 +77,24 @@ impl PathDetails {
     }
 }
 
+/// A single path component visited while resolving a path
+#[cw_serde]
+pub struct PathHop {
+    /// Number of symlinks being followed when this component was visited
+    pub depth: u32,
+    pub parent_address: Addr,
+    pub name: String,
+    /// The address the component resolved to, after following any symlink
+    pub address: Addr,
+    pub symlink: Option<AndrAddr>,
+}
+
+#[cw_serde]
+pub struct ResolvePathTraceResponse {
+    pub address: Addr,
+    pub hops: Vec<PathHop>,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     AddPath {
@@ -106,6 +124,10 @@ pub enum ExecuteMsg {
         chain: String,
         address: String,
     },
+    // Restricted to VFS owner
+    UpdateMaxSymlinkDepth {
+        depth: u32,
+    },
 }
 
 #[cw_serde]
@@ -126,6 +148,10 @@ pub enum QueryMsg {
     GetLibrary { address: Addr },
     #[returns(AndrAddr)]
     ResolveSymlink { path: AndrAddr },
+    #[returns(ResolvePathTraceResponse)]
+    ResolvePathTrace { path: AndrAddr },
+    #[returns(u32)]
+    MaxSymlinkDepth {},
 }
 
 /// Queries the provided VFS contract address to resolve the given path
@@ -2,7 +2,7 @@ use crate::{
     ado_base::AndromedaQuery,
     ado_contract::ADOContract,
     amp::{ADO_DB_KEY, ECONOMICS_KEY, OSMOSIS_ROUTER_KEY, VFS_KEY},
-    os::adodb::{ActionFee, QueryMsg as ADODBQueryMsg},
+    os::adodb::{ActionFee, FeeType, QueryMsg as ADODBQueryMsg},
     os::kernel::QueryMsg as KernelQueryMsg,
     os::vfs::QueryMsg as VFSQueryMsg,
 };
@@ -11,17 +11,19 @@ use cosmwasm_std::SubMsg;
 use cosmwasm_std::{
     from_json,
     testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
-    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, ContractResult, OwnedDeps, Querier,
-    QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    to_json_binary, Addr, Binary, Coin, ContractInfoResponse, ContractResult, Decimal, OwnedDeps,
+    Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
-#[cfg(feature = "primitive")]
-use cosmwasm_std::{Decimal, Uint128};
+#[cfg(feature = "cosmwasm_1_2")]
+use cosmwasm_std::{CodeInfoResponse, HexBinary};
 use cw20::{BalanceResponse, Cw20QueryMsg};
 
 /// Mock CW20 Contract Address
 pub const MOCK_CW20_CONTRACT: &str = "cw20_contract";
 /// Mock App Contract Address
 pub const MOCK_APP_CONTRACT: &str = "app_contract";
+/// Mock App Contract Owner
+pub const MOCK_APP_OWNER: &str = "app_owner";
 /// Mock Primitive Contract Address
 pub const MOCK_PRIMITIVE_CONTRACT: &str = "primitive_contract";
 /// Mock Kernel Contract Address
@@ -44,6 +46,14 @@ pub const MOCK_RATES_CONTRACT: &str = "rates_contract";
 /// Mock Address List Contract Address
 pub const MOCK_ADDRESS_LIST_CONTRACT: &str = "address_list_contract";
 
+/// Creator returned for every `CodeInfo` query
+#[cfg(feature = "cosmwasm_1_2")]
+pub const MOCK_CODE_CREATOR: &str = "code_creator";
+/// Checksum returned for every `CodeInfo` query
+#[cfg(feature = "cosmwasm_1_2")]
+pub const MOCK_CODE_CHECKSUM: &str =
+    "84cf20810fd429caf58898c3210fcb71759a27becddae08dbde8668ea2f4725d";
+
 /// An invalid contract address
 pub const INVALID_CONTRACT: &str = "invalid_contract";
 /// An invalid VFS Path
@@ -52,6 +62,10 @@ pub const FAKE_VFS_PATH: &str = "/f";
 pub const FAKE_ADODB_KEY: &str = "fake_adodb_key";
 /// A valid action
 pub const MOCK_ACTION: &str = "action";
+/// A valid action with a percentage fee
+pub const MOCK_PERCENTAGE_ACTION: &str = "percentage_action";
+/// A valid action with a flat fee payable in multiple assets
+pub const MOCK_MULTI_ASSET_ACTION: &str = "multi_asset_action";
 pub const UNWHITELISTED_ADDRESS: &str = "unwhitelisted_address";
 pub const RATES_EXCLUDED_ADDRESS: &str = "rates_excluded_address";
 
@@ -136,7 +150,6 @@ impl MockAndromedaQuerier {
                     MOCK_KERNEL_CONTRACT => self.handle_kernel_query(msg),
                     MOCK_VFS_CONTRACT => self.handle_vfs_query(msg),
                     MOCK_ADODB_CONTRACT => self.handle_adodb_query(msg),
-                    #[cfg(feature = "modules")]
                     MOCK_ADDRESS_LIST_CONTRACT => self.handle_address_list_query(msg),
                     _ => match from_json::<AndromedaQuery>(msg) {
                         Ok(msg) => self.handle_ado_query(msg),
@@ -146,13 +159,22 @@ impl MockAndromedaQuerier {
             }
             QueryRequest::Wasm(WasmQuery::Raw { contract_addr, key }) => {
                 match contract_addr.as_str() {
-                    // MOCK_APP_CONTRACT => self.handle_app_raw_query(key),
+                    MOCK_APP_CONTRACT => self.handle_app_raw_query(key),
                     MOCK_KERNEL_CONTRACT => self.handle_kernel_raw_query(key, false),
                     MOCK_FAKE_KERNEL_CONTRACT => self.handle_kernel_raw_query(key, true),
                     MOCK_ADODB_CONTRACT => self.handle_adodb_raw_query(key),
-                    _ => panic!("Unsupported query for contract: {contract_addr}"),
+                    _ => self.handle_ado_raw_query(contract_addr, key),
                 }
             }
+            #[cfg(feature = "cosmwasm_1_2")]
+            QueryRequest::Wasm(WasmQuery::CodeInfo { code_id }) => {
+                let resp = CodeInfoResponse::new(
+                    *code_id,
+                    MOCK_CODE_CREATOR.to_string(),
+                    HexBinary::from_hex(MOCK_CODE_CHECKSUM).unwrap(),
+                );
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&resp).unwrap()))
+            }
             // Defaults to code ID 1, returns 2 for `INVALID_CONTRACT` which is considered an invalid ADODB code id
             QueryRequest::Wasm(WasmQuery::ContractInfo { contract_addr }) => {
                 let mut resp = ContractInfoResponse::default();
@@ -218,6 +240,7 @@ impl MockAndromedaQuerier {
             VFSQueryMsg::GetLibrary { address } => {
                 SystemResult::Ok(ContractResult::Ok(to_json_binary(&address).unwrap()))
             }
+            _ => SystemResult::Ok(ContractResult::Err("Not implemented".to_string())),
         }
     }
 
@@ -231,6 +254,19 @@ impl MockAndromedaQuerier {
         todo!()
     }
 
+    /// Handles all App raw queries.
+    ///
+    /// Returns `MOCK_APP_OWNER` for the `owner` key.
+    fn handle_app_raw_query(&self, key: &Binary) -> QuerierResult {
+        let key_str = String::from_utf8(key.to_vec()).unwrap();
+        match key_str.as_str() {
+            "owner" => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&MOCK_APP_OWNER.to_string()).unwrap(),
+            )),
+            _ => panic!("Invalid App Raw Query"),
+        }
+    }
+
     /// Handles all ADODB queries.
     ///
     /// Returns `"ADOType"` for `ADOType` queries with code ID 1 and an error otherwise.
@@ -304,31 +340,49 @@ impl MockAndromedaQuerier {
         }
     }
 
-    #[cfg(feature = "modules")]
     /// Handles all address list queries
     ///
-    /// Returns `true` for `OnExecute` queries for any address excluding `UNWHITELISTED_ADDRESS`.
+    /// Returns `true` for `OnExecute` and `IncludesAddress` queries for any address excluding `UNWHITELISTED_ADDRESS`.
+    /// The list is always inclusive.
     fn handle_address_list_query(&self, msg: &Binary) -> QuerierResult {
-        use cosmwasm_std::Response;
+        use crate::ado_contract::permissioning::{AddressListQueryMsg, IncludesAddressResponse};
 
-        use crate::ado_base::hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse};
-        match from_json(msg).unwrap() {
-            HookMsg::AndrHook(hook) => match hook {
-                AndromedaHook::OnExecute { sender, .. } => match sender.as_str() {
-                    UNWHITELISTED_ADDRESS => {
-                        SystemResult::Ok(ContractResult::Err("Unwhitelisted Address".to_string()))
-                    }
+        #[cfg(feature = "modules")]
+        {
+            use cosmwasm_std::Response;
+
+            use crate::ado_base::hooks::{AndromedaHook, HookMsg, OnFundsTransferResponse};
+            if let Ok(HookMsg::AndrHook(hook)) = from_json(msg) {
+                return match hook {
+                    AndromedaHook::OnExecute { sender, .. } => match sender.as_str() {
+                        UNWHITELISTED_ADDRESS => SystemResult::Ok(ContractResult::Err(
+                            "Unwhitelisted Address".to_string(),
+                        )),
+                        _ => SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary::<Response>(&Response::default()).unwrap(),
+                        )),
+                    },
+                    AndromedaHook::OnFundsTransfer { .. } => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&OnFundsTransferResponse::default()).unwrap(),
+                    )),
                     _ => SystemResult::Ok(ContractResult::Ok(
                         to_json_binary::<Response>(&Response::default()).unwrap(),
                     )),
-                },
-                AndromedaHook::OnFundsTransfer { .. } => SystemResult::Ok(ContractResult::Ok(
-                    to_json_binary(&OnFundsTransferResponse::default()).unwrap(),
-                )),
-                AndromedaHook::OnTokenTransfer { .. } => SystemResult::Ok(ContractResult::Ok(
-                    to_json_binary::<Response>(&Response::default()).unwrap(),
-                )),
-            },
+                };
+            }
+        }
+
+        match from_json(msg) {
+            Ok(AddressListQueryMsg::IncludesAddress { address }) => {
+                let res = IncludesAddressResponse {
+                    included: address != UNWHITELISTED_ADDRESS,
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&res).unwrap()))
+            }
+            Ok(AddressListQueryMsg::IsInclusive {}) => {
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap()))
+            }
+            Err(_) => self.handle_ado_query(from_json(msg).unwrap()),
         }
     }
 
@@ -355,7 +409,7 @@ impl MockAndromedaQuerier {
                     // let msgs = calculate_mock_rates_response(sender, payload, amount);
                     todo!("Implement Rates Query")
                 }
-                AndromedaHook::OnTokenTransfer { .. } => SystemResult::Ok(ContractResult::Ok(
+                _ => SystemResult::Ok(ContractResult::Ok(
                     to_json_binary::<Response>(&Response::default()).unwrap(),
                 )),
             },
@@ -421,7 +475,10 @@ impl MockAndromedaQuerier {
         let key_vec = key.as_slice();
         let key_str = String::from_utf8(key_vec.to_vec()).unwrap();
 
-        if key_str.contains("code_id") {
+        if key_str.contains("version_status") {
+            // No versions are deprecated or yanked
+            SystemResult::Ok(ContractResult::Ok(Binary::default()))
+        } else if key_str.contains("code_id") {
             let split = key_str.split("code_id");
             let key = split.last();
             if let Some(key) = key {
@@ -439,7 +496,35 @@ impl MockAndromedaQuerier {
             let key = split.last();
             match key {
                 Some(key) => {
-                    if key.contains("ADOTypeaction") {
+                    if key.contains(&format!("ADOType{MOCK_PERCENTAGE_ACTION}")) {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(
+                                &ActionFee::new(
+                                    MOCK_PERCENTAGE_ACTION.to_string(),
+                                    "uusd".to_string(),
+                                    Uint128::from(1u128),
+                                )
+                                .with_fee_type(FeeType::Percentage {
+                                    percent: Decimal::percent(5),
+                                    max: None,
+                                })
+                                .with_accepted_assets(vec!["uandr".to_string()]),
+                            )
+                            .unwrap(),
+                        ))
+                    } else if key.contains(&format!("ADOType{MOCK_MULTI_ASSET_ACTION}")) {
+                        SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(
+                                &ActionFee::new(
+                                    MOCK_MULTI_ASSET_ACTION.to_string(),
+                                    "uusd".to_string(),
+                                    Uint128::from(10u128),
+                                )
+                                .with_accepted_assets(vec!["uandr".to_string()]),
+                            )
+                            .unwrap(),
+                        ))
+                    } else if key.contains("ADOTypeaction") {
                         SystemResult::Ok(ContractResult::Ok(
                             to_json_binary(&ActionFee::new(
                                 MOCK_ACTION.to_string(),
@@ -464,6 +549,9 @@ impl MockAndromedaQuerier {
                 Some(_) => SystemResult::Ok(ContractResult::Err("Invalid Key".to_string())),
                 None => SystemResult::Ok(ContractResult::Err("Invalid Key".to_string())),
             }
+        } else if key_str.contains("publisher_profiles") {
+            // No publishers have registered profiles
+            SystemResult::Ok(ContractResult::Ok(Binary::default()))
         } else if key_str.contains("publisher") {
             let split = key_str.split("ado_type");
             let key = split.last();
@@ -491,6 +579,15 @@ impl MockAndromedaQuerier {
             _ => panic!("Unsupported ADO query"),
         }
     }
+
+    pub fn handle_ado_raw_query(&self, contract_addr: &str, key: &Binary) -> QuerierResult {
+        match key.as_slice() {
+            b"app_contract" => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&MOCK_APP_CONTRACT.to_string()).unwrap(),
+            )),
+            _ => panic!("Unsupported query for contract: {contract_addr}"),
+        }
+    }
 }
 
 #[cfg(feature = "modules")]
@@ -210,7 +210,7 @@ fn test_crowdfund_app() {
 
     let _vault_one_addr = app.query_component_addr(&router, vault_one_app_component.name);
     let _vault_two_addr = app.query_component_addr(&router, vault_two_app_component.name);
-    app.execute_claim_ownership(&mut router, owner.clone(), None)
+    app.execute_claim_and_accept_ownership(&mut router, owner.clone())
         .unwrap();
 
     let cw721_contract =
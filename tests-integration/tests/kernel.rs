@@ -1,17 +1,25 @@
+use andromeda_adodb::mock::mock_andromeda_adodb;
 use andromeda_app_contract::mock::mock_andromeda_app;
 use andromeda_finance::splitter::AddressPercent;
 use andromeda_splitter::mock::{
     mock_andromeda_splitter, mock_splitter_instantiate_msg, mock_splitter_send_msg, MockSplitter,
 };
+use andromeda_std::ado_base::AndromedaMsg;
 use andromeda_std::amp::{AndrAddr, Recipient};
+use andromeda_std::error::ContractError;
+use andromeda_std::os::adodb::{
+    ExecuteMsg as ADODBExecuteMsg, MigrateMsg as ADODBMigrateMsg, QueryMsg as ADODBQueryMsg,
+};
+use andromeda_std::os::kernel::ExecuteMsg as KernelExecuteMsg;
 use andromeda_testing::{
     mock::MockAndromeda,
     mock_contract::{MockADO, MockContract},
 };
 
-use cosmwasm_std::{coin, Addr, Decimal};
+use cosmwasm_std::{coin, Addr, Decimal, Event, WasmMsg};
+use cw_storage_plus::Map;
 
-use cw_multi_test::App;
+use cw_multi_test::{App, Executor};
 
 fn mock_app() -> App {
     App::new(|router, _api, storage| {
@@ -95,3 +103,314 @@ fn kernel() {
 
     assert!(res.data.is_none());
 }
+
+#[test]
+fn kernel_create_yanked_ado() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+
+    andr.store_ado(&mut router, mock_andromeda_splitter(), "splitter");
+    router
+        .execute_contract(
+            owner.clone(),
+            andr.adodb.addr().clone(),
+            &ADODBExecuteMsg::Yank {
+                ado_version: "splitter@0.1.0".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let splitter_msg = mock_splitter_instantiate_msg(
+        vec![AddressPercent::new(
+            Recipient::from_string(owner.to_string()),
+            Decimal::one(),
+        )],
+        andr.kernel.addr().clone(),
+        None,
+        None,
+    );
+
+    // Yanked versions can still be looked up exactly but cannot be instantiated
+    let err: ContractError = andr
+        .kernel
+        .execute_create(
+            &mut router,
+            owner,
+            "splitter@0.1.0",
+            splitter_msg,
+            None,
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::YankedADOVersion {
+            ado_version: "splitter@0.1.0".to_string()
+        }
+    );
+}
+
+#[test]
+fn kernel_migrate_ado() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+
+    let code_id = andr.store_ado(&mut router, mock_andromeda_splitter(), "splitter");
+    let new_code_id = router.store_code(mock_andromeda_splitter());
+    andr.adodb
+        .execute_publish(
+            &mut router,
+            owner.clone(),
+            new_code_id,
+            "splitter",
+            "0.2.0",
+            Some(owner.to_string()),
+            None,
+        )
+        .unwrap();
+
+    let splitter_msg = mock_splitter_instantiate_msg(
+        vec![AddressPercent::new(
+            Recipient::from_string(owner.to_string()),
+            Decimal::one(),
+        )],
+        andr.kernel.addr().clone(),
+        None,
+        None,
+    );
+    let splitter_addr = router
+        .instantiate_contract(
+            code_id,
+            owner.clone(),
+            &splitter_msg,
+            &[],
+            "splitter",
+            Some(owner.to_string()),
+        )
+        .unwrap();
+
+    let migrate_msg = KernelExecuteMsg::MigrateADO {
+        address: AndrAddr::from_string(splitter_addr.to_string()),
+        target_version: "0.2.0".to_string(),
+    };
+
+    // Only the ADO owner can request a migration
+    let err: ContractError = router
+        .execute_contract(
+            Addr::unchecked("not_owner"),
+            andr.kernel.addr().clone(),
+            &migrate_msg,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // The kernel must be the wasm admin of the ADO
+    let err: ContractError = router
+        .execute_contract(owner.clone(), andr.kernel.addr().clone(), &migrate_msg, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::KernelNotAdmin {
+            address: splitter_addr.to_string()
+        }
+    );
+
+    router
+        .execute(
+            owner.clone(),
+            WasmMsg::UpdateAdmin {
+                contract_addr: splitter_addr.to_string(),
+                admin: andr.kernel.addr().to_string(),
+            }
+            .into(),
+        )
+        .unwrap();
+
+    // No migration path has been registered between the two versions
+    let err: ContractError = router
+        .execute_contract(owner, andr.kernel.addr().clone(), &migrate_msg, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NoMigrationPath {
+            from: "splitter@0.1.0".to_string(),
+            to: "splitter@0.2.0".to_string(),
+        }
+    );
+}
+
+#[test]
+fn kernel_migrate_created_ado() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+
+    andr.store_ado(&mut router, mock_andromeda_splitter(), "splitter");
+    let new_code_id = router.store_code(mock_andromeda_splitter());
+    andr.adodb
+        .execute_publish(
+            &mut router,
+            owner.clone(),
+            new_code_id,
+            "splitter",
+            "0.2.0",
+            Some(owner.to_string()),
+            None,
+        )
+        .unwrap();
+
+    let splitter_msg = mock_splitter_instantiate_msg(
+        vec![AddressPercent::new(
+            Recipient::from_string(owner.to_string()),
+            Decimal::one(),
+        )],
+        andr.kernel.addr().clone(),
+        None,
+        None,
+    );
+    let res = andr
+        .kernel
+        .execute_create(
+            &mut router,
+            owner.clone(),
+            "splitter@0.1.0",
+            splitter_msg,
+            Some(AndrAddr::from_string(owner.to_string())),
+            None,
+        )
+        .unwrap();
+    let splitter_addr = res
+        .events
+        .iter()
+        .find(|ev| ev.ty == "instantiate")
+        .and_then(|ev| {
+            ev.attributes
+                .iter()
+                .find(|attr| attr.key == "_contract_address")
+        })
+        .map(|attr| Addr::unchecked(attr.value.clone()))
+        .unwrap();
+    let splitter = MockSplitter::from(splitter_addr.clone());
+
+    // ADOs created through the kernel are administered by the kernel
+    let contract_info = router
+        .wrap()
+        .query_wasm_contract_info(splitter_addr.to_string())
+        .unwrap();
+    assert_eq!(contract_info.admin, Some(andr.kernel.addr().to_string()));
+
+    // Register two source ranges into the same target version
+    for from in ["<0.1.0", "0.1.x"] {
+        router
+            .execute_contract(
+                owner.clone(),
+                andr.adodb.addr().clone(),
+                &ADODBExecuteMsg::AddMigrationPath {
+                    ado_type: "splitter".to_string(),
+                    from: from.to_string(),
+                    to: "0.2.0".to_string(),
+                    migrate_msg: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    splitter.seed_contract_version(&mut router, "0.1.0");
+    let migrate_msg = KernelExecuteMsg::MigrateADO {
+        address: AndrAddr::from_string(splitter_addr.to_string()),
+        target_version: "0.2.0".to_string(),
+    };
+    router
+        .execute_contract(owner.clone(), andr.kernel.addr().clone(), &migrate_msg, &[])
+        .unwrap();
+
+    let contract_info = router
+        .wrap()
+        .query_wasm_contract_info(splitter_addr.to_string())
+        .unwrap();
+    assert_eq!(contract_info.code_id, new_code_id);
+    assert_ne!(splitter.query_contract_version(&router), "0.1.0");
+
+    // ADOs whose ownership has been renounced can no longer be migrated
+    router
+        .execute_contract(
+            owner.clone(),
+            splitter_addr.clone(),
+            &AndromedaMsg::RenounceOwnership {},
+            &[],
+        )
+        .unwrap();
+    let err: ContractError = router
+        .execute_contract(owner, andr.kernel.addr().clone(), &migrate_msg, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NoOwner {
+            address: splitter_addr.to_string()
+        }
+    );
+}
+
+#[test]
+fn adodb_migrate_backfills_publisher_ados() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+    andr.store_ado(&mut router, mock_andromeda_splitter(), "splitter");
+    let adodb_code_id = router.store_code(mock_andromeda_adodb());
+
+    let publisher_ados_query = ADODBQueryMsg::PublisherADOs {
+        publisher: owner.to_string(),
+        start_after: None,
+        limit: None,
+    };
+    let published: Vec<String> = router
+        .wrap()
+        .query_wasm_smart(andr.adodb.addr(), &publisher_ados_query)
+        .unwrap();
+    assert!(published.contains(&"splitter".to_string()));
+
+    // Drop the publisher index to match an ADODB deployed before the index existed
+    let publisher_ados: Map<(&str, &str), bool> = Map::new("publisher_ados");
+    andr.adodb.seed_state(&mut router, |storage| {
+        for ado_type in published.iter() {
+            publisher_ados.remove(storage, (owner.as_str(), ado_type.as_str()))
+        }
+    });
+    andr.adodb.seed_contract_version(&mut router, "0.2.1");
+    let ado_types: Vec<String> = router
+        .wrap()
+        .query_wasm_smart(andr.adodb.addr(), &publisher_ados_query)
+        .unwrap();
+    assert!(ado_types.is_empty());
+
+    let res = andr
+        .adodb
+        .migrate(&mut router, &ADODBMigrateMsg {}, owner, adodb_code_id)
+        .unwrap();
+    assert!(res.has_event(&Event::new("wasm").add_attribute("migrations", "0.2.2")));
+    let ado_types: Vec<String> = router
+        .wrap()
+        .query_wasm_smart(andr.adodb.addr(), &publisher_ados_query)
+        .unwrap();
+    assert_eq!(ado_types, published);
+}
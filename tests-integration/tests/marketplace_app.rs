@@ -130,7 +130,7 @@ fn test_marketplace_app() {
     assert_eq!(components, app_components);
 
     // Claim Ownership
-    app.execute_claim_ownership(&mut router, owner.clone(), None)
+    app.execute_claim_and_accept_ownership(&mut router, owner.clone())
         .unwrap();
 
     let cw721: MockCW721 = app.query_ado_by_component_name(&router, cw721_component.name);
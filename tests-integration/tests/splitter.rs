@@ -1,12 +1,15 @@
 use andromeda_app::app::{AppComponent, ComponentType};
 use andromeda_app_contract::mock::{mock_andromeda_app, MockApp};
 
-use andromeda_testing::{MockAndromeda, MockContract};
+use andromeda_testing::{MockADO, MockAndromeda, MockContract};
 
-use andromeda_std::amp::Recipient;
-use cosmwasm_std::{coin, Addr, Decimal, Uint128};
+use andromeda_std::{
+    ado_base::metadata::{AdoMetadata, MetadataLink},
+    amp::Recipient,
+};
+use cosmwasm_std::{coin, Addr, Decimal, Event, Uint128};
 
-use andromeda_finance::splitter::AddressPercent;
+use andromeda_finance::splitter::{AddressPercent, MigrateMsg};
 use andromeda_splitter::mock::{
     mock_andromeda_splitter, mock_splitter_instantiate_msg, MockSplitter,
 };
@@ -88,3 +91,119 @@ fn test_splitter() {
     assert_eq!(balance_1.amount, Uint128::from(200u128));
     assert_eq!(balance_2.amount, Uint128::from(800u128));
 }
+
+#[test]
+fn test_splitter_migration() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+    let splitter_code_id = router.store_code(mock_andromeda_splitter());
+
+    let recipients = vec![AddressPercent {
+        recipient: Recipient::from_string("recipient_1"),
+        percent: Decimal::one(),
+    }];
+    let splitter = MockSplitter::instantiate(
+        &mut router,
+        splitter_code_id,
+        owner.clone(),
+        recipients,
+        andr.kernel.addr(),
+        None,
+        None,
+    );
+    let version = splitter.query_contract_version(&router);
+
+    // Migrating to the current version is rejected
+    let res = splitter.migrate(&mut router, &MigrateMsg {}, owner.clone(), splitter_code_id);
+    assert!(res.is_err());
+
+    splitter.seed_contract_version(&mut router, "0.1.0");
+    let res = splitter
+        .migrate(&mut router, &MigrateMsg {}, owner, splitter_code_id)
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm")
+            .add_attribute("previous_version", "0.1.0")
+            .add_attribute("version", version.clone())
+    ));
+    assert_eq!(splitter.query_contract_version(&router), version);
+    let migrations: Vec<String> = splitter
+        .query_migrations(&router)
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+    assert_eq!(migrations, vec!["0.2.4".to_string()]);
+}
+
+#[test]
+fn test_splitter_metadata() {
+    let owner = Addr::unchecked("owner");
+
+    let mut router = mock_app();
+    let andr = mock_andromeda(&mut router, owner.clone());
+
+    let app_code_id = router.store_code(mock_andromeda_app());
+    andr.store_code_id(&mut router, "app", app_code_id);
+    let splitter_code_id = router.store_code(mock_andromeda_splitter());
+    andr.store_code_id(&mut router, "splitter", splitter_code_id);
+
+    let metadata = AdoMetadata {
+        description: Some("Splits sales between the artists".to_string()),
+        tags: vec!["splitter".to_string()],
+        links: vec![MetadataLink {
+            name: "website".to_string(),
+            url: "https://example.com".to_string(),
+        }],
+        image: None,
+    };
+    let recipients = vec![AddressPercent {
+        recipient: Recipient::from_string("recipient_1"),
+        percent: Decimal::one(),
+    }];
+    let mut splitter_init_msg =
+        mock_splitter_instantiate_msg(recipients, andr.kernel.addr().clone(), None, None);
+    splitter_init_msg.metadata = Some(metadata.clone());
+    let splitter_app_component = AppComponent {
+        name: "1".to_string(),
+        component_type: ComponentType::new(splitter_init_msg),
+        ado_type: "splitter".to_string(),
+    };
+
+    let app = MockApp::instantiate(
+        app_code_id,
+        owner.clone(),
+        &mut router,
+        "Splitter App",
+        vec![splitter_app_component.clone()],
+        andr.kernel.addr(),
+        None,
+    );
+
+    let components = app.query_addresses_with_names(&router);
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].metadata, Some(metadata));
+
+    // The app must hand over ownership before the metadata can be updated
+    let splitter: MockSplitter =
+        app.query_ado_by_component_name(&router, splitter_app_component.name);
+    let updated_metadata = AdoMetadata {
+        description: Some("Pays the artist".to_string()),
+        ..AdoMetadata::default()
+    };
+    let res =
+        splitter.execute_update_metadata(&mut router, owner.clone(), updated_metadata.clone());
+    assert!(res.is_err());
+
+    app.execute_claim_and_accept_ownership(&mut router, owner.clone())
+        .unwrap();
+    splitter
+        .execute_update_metadata(&mut router, owner, updated_metadata.clone())
+        .unwrap();
+    assert_eq!(splitter.query_metadata(&router), updated_metadata);
+    assert_eq!(
+        app.query_addresses_with_names(&router)[0].metadata,
+        Some(updated_metadata)
+    );
+}